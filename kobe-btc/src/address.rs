@@ -1,11 +1,194 @@
-//! Bitcoin address creation utilities.
+//! Bitcoin address type and creation utilities.
 //!
-//! This module provides shared address creation functionality used by both
-//! HD derivation and standard wallet implementations.
+//! This module provides the [`Address`] wrapper along with shared address
+//! creation functionality used by both HD derivation and standard wallet
+//! implementations.
 
-use bitcoin::{Address, PublicKey, key::CompressedPublicKey, secp256k1::Secp256k1};
+use alloc::vec::Vec;
+use core::fmt;
 
-use crate::{AddressType, Network};
+use bitcoin::hashes::{Hash, sha256};
+use bitcoin::{Address as BtcAddress, key::CompressedPublicKey, secp256k1::Secp256k1};
+use bitcoin::{PubkeyHash, ScriptHash, WitnessProgram, WitnessVersion};
+
+use crate::{AddressType, Error, Network, PublicKey};
+
+/// A Bitcoin address.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Address {
+    /// Underlying `bitcoin` crate address.
+    inner: BtcAddress,
+}
+
+impl Address {
+    /// Create an address of the given type for a public key.
+    ///
+    /// P2PKH commits to the key in the encoding it was parsed from, so an
+    /// uncompressed key gets its uncompressed-key address. SegWit and
+    /// Taproot outputs only allow compressed keys and always use that form.
+    #[must_use]
+    pub fn from_public_key(
+        public_key: &PublicKey,
+        network: Network,
+        address_type: AddressType,
+    ) -> Self {
+        match address_type {
+            AddressType::P2pkh => Self::p2pkh_from_hash(public_key.hash160(), network),
+            _ => Self {
+                inner: create_address(public_key.inner(), network, address_type),
+            },
+        }
+    }
+
+    /// Create a nested SegWit (P2SH-P2WPKH, `3...`) address for a public key.
+    ///
+    /// The redeem script is `OP_0 <hash160(pubkey)>` and the address is the
+    /// P2SH of that script's `hash160`.
+    #[inline]
+    #[must_use]
+    pub fn p2sh_p2wpkh(public_key: &PublicKey, network: Network) -> Self {
+        Self::from_public_key(public_key, network, AddressType::P2shP2wpkh)
+    }
+
+    /// Create a legacy P2PKH (`1...`) address from a 20-byte `hash160(pubkey)`.
+    #[must_use]
+    pub fn p2pkh_from_hash(hash: [u8; 20], network: Network) -> Self {
+        Self {
+            inner: BtcAddress::p2pkh(
+                PubkeyHash::from_byte_array(hash),
+                network.to_bitcoin_network(),
+            ),
+        }
+    }
+
+    /// Create a native SegWit P2WPKH (`bc1q...`) address from a 20-byte
+    /// `hash160(pubkey)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAddress`] if the witness program is rejected.
+    pub fn p2wpkh_from_hash(hash: [u8; 20], network: Network) -> Result<Self, Error> {
+        let program =
+            WitnessProgram::new(WitnessVersion::V0, &hash).map_err(|_| Error::InvalidAddress)?;
+        Ok(Self {
+            inner: BtcAddress::from_witness_program(program, network.to_bitcoin_network()),
+        })
+    }
+
+    /// Create a P2SH (`3...`) address paying to an arbitrary redeem script.
+    ///
+    /// The script is committed to by `hash160(script)`; it is not checked
+    /// for validity or for the 520-byte push limit that spending enforces.
+    #[must_use]
+    pub fn p2sh_from_redeem_script(script: &[u8], network: Network) -> Self {
+        Self {
+            inner: BtcAddress::p2sh_from_hash(
+                ScriptHash::hash(script),
+                network.to_bitcoin_network(),
+            ),
+        }
+    }
+
+    /// Parse an address string, requiring it to belong to `network`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NetworkMismatch`] if the address belongs to another
+    /// network, or [`Error::InvalidAddress`] if it is not a valid address
+    /// at all. A bech32 human-readable part (`bc`, `tb` or `bcrt`) is
+    /// checked against `network` before the address is decoded.
+    pub fn parse(s: &str, network: Network) -> Result<Self, Error> {
+        if let Some(actual) = bech32_network_name(s)
+            && actual != network.name()
+        {
+            return Err(Error::NetworkMismatch {
+                expected: network.name(),
+                actual,
+            });
+        }
+        let unchecked = s
+            .parse::<BtcAddress<_>>()
+            .map_err(|_| Error::InvalidAddress)?;
+        if !unchecked.is_valid_for_network(network.to_bitcoin_network()) {
+            let actual = [Network::Mainnet, Network::Testnet]
+                .into_iter()
+                .find(|other| unchecked.is_valid_for_network(other.to_bitcoin_network()))
+                .ok_or(Error::InvalidAddress)?;
+            return Err(Error::NetworkMismatch {
+                expected: network.name(),
+                actual: actual.name(),
+            });
+        }
+        Ok(Self {
+            inner: unchecked.assume_checked(),
+        })
+    }
+
+    /// Check whether `s` is a valid address on `network`.
+    ///
+    /// This is [`Self::parse`] without the error details, for validating a
+    /// "send to" field.
+    #[inline]
+    #[must_use]
+    pub fn is_valid_for(s: &str, network: Network) -> bool {
+        Self::parse(s, network).is_ok()
+    }
+
+    /// Get the raw scriptPubKey bytes locking funds to this address.
+    #[inline]
+    #[must_use]
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        self.inner.script_pubkey().into_bytes()
+    }
+
+    /// Get the Electrum protocol script hash for this address.
+    ///
+    /// This is `sha256(scriptPubKey)` in reversed byte order, as used by the
+    /// `blockchain.scripthash.*` methods of Electrum servers.
+    #[must_use]
+    pub fn electrum_scripthash(&self) -> [u8; 32] {
+        let mut hash = sha256::Hash::hash(self.inner.script_pubkey().as_bytes()).to_byte_array();
+        hash.reverse();
+        hash
+    }
+
+    /// Get the underlying `bitcoin` crate address.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &BtcAddress {
+        &self.inner
+    }
+}
+
+impl From<Address> for BtcAddress {
+    fn from(address: Address) -> Self {
+        address.inner
+    }
+}
+
+impl TryFrom<BtcAddress> for Address {
+    type Error = Error;
+
+    /// Convert a `bitcoin` address, rejecting networks other than mainnet
+    /// and testnet (e.g. regtest `bcrt1...`).
+    fn try_from(inner: BtcAddress) -> Result<Self, Self::Error> {
+        [Network::Mainnet, Network::Testnet]
+            .into_iter()
+            .any(|network| {
+                inner
+                    .as_unchecked()
+                    .is_valid_for_network(network.to_bitcoin_network())
+            })
+            .then_some(Self { inner })
+            .ok_or(Error::InvalidAddress)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
 
 /// Create a Bitcoin address from a compressed public key.
 ///
@@ -26,22 +209,41 @@ pub fn create_address(
     public_key: &CompressedPublicKey,
     network: Network,
     address_type: AddressType,
-) -> Address {
+) -> BtcAddress {
     let btc_network = network.to_bitcoin_network();
 
     match address_type {
-        AddressType::P2pkh => Address::p2pkh(PublicKey::from(*public_key), btc_network),
-        AddressType::P2shP2wpkh => Address::p2shwpkh(public_key, btc_network),
-        AddressType::P2wpkh => Address::p2wpkh(public_key, btc_network),
+        AddressType::P2pkh => BtcAddress::p2pkh(bitcoin::PublicKey::from(*public_key), btc_network),
+        AddressType::P2shP2wpkh => BtcAddress::p2shwpkh(public_key, btc_network),
+        AddressType::P2wpkh => BtcAddress::p2wpkh(public_key, btc_network),
         AddressType::P2tr => {
             let secp = Secp256k1::verification_only();
             let internal_key = public_key.0.x_only_public_key().0;
-            Address::p2tr(&secp, internal_key, None, btc_network)
+            BtcAddress::p2tr(&secp, internal_key, None, btc_network)
         }
     }
 }
 
+/// Name the network of a segwit address from its bech32 human-readable
+/// part: `bc` for mainnet, `tb` for testnet and signet, `bcrt` for regtest.
+fn bech32_network_name(s: &str) -> Option<&'static str> {
+    let has_prefix = |prefix: &str| {
+        s.get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    };
+    if has_prefix("bcrt1") {
+        Some("regtest")
+    } else if has_prefix("bc1") {
+        Some(Network::Mainnet.name())
+    } else if has_prefix("tb1") {
+        Some(Network::Testnet.name())
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
     use bitcoin::secp256k1::Secp256k1;
@@ -69,4 +271,171 @@ fn test_create_address_p2pkh() {
         let address = create_address(&public_key, Network::Mainnet, AddressType::P2pkh);
         assert!(address.to_string().starts_with('1'));
     }
+
+    #[test]
+    fn test_p2sh_p2wpkh_bip49_vector() {
+        use crate::{DerivationPath, ExtendedPrivateKey};
+        use kobe::Wallet;
+        use kobe::testing::BIP49_BTC_VECTOR;
+
+        let wallet = Wallet::from_mnemonic(BIP49_BTC_VECTOR.mnemonic, None).unwrap();
+        let master = ExtendedPrivateKey::new_master(wallet.seed(), Network::Mainnet).unwrap();
+        let path = DerivationPath::from_path_str(BIP49_BTC_VECTOR.path).unwrap();
+        let secp = Secp256k1::new();
+        let key = master.derive(&path).unwrap().inner().private_key;
+        let public_key = PublicKey::from_slice(&key.public_key(&secp).serialize()).unwrap();
+
+        let address = Address::p2sh_p2wpkh(&public_key, Network::Mainnet);
+        assert_eq!(address.to_string(), BIP49_BTC_VECTOR.address);
+
+        // P2SH of the `OP_0 <hash160(pubkey)>` redeem script.
+        let mut redeem_script = vec![0x00, 0x14];
+        redeem_script.extend_from_slice(&public_key.inner().wpubkey_hash().to_byte_array());
+        let script_hash = bitcoin::hashes::hash160::Hash::hash(&redeem_script);
+        let mut expected = vec![0xa9, 0x14];
+        expected.extend_from_slice(script_hash.as_byte_array());
+        expected.push(0x87);
+        assert_eq!(address.script_pubkey(), expected);
+    }
+
+    // Genesis block address, from the Electrum protocol documentation.
+    #[test]
+    fn test_electrum_scripthash() {
+        let address =
+            Address::parse("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", Network::Mainnet).unwrap();
+        assert_eq!(
+            hex::encode(address.script_pubkey()),
+            "76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac"
+        );
+        assert_eq!(
+            hex::encode(address.electrum_scripthash()),
+            "8b01df4e368ea28f8dc0423bcf7a4923e3a12d307c875e47a0cfbf90b5c39161"
+        );
+    }
+
+    #[test]
+    fn test_from_hash_matches_public_key() {
+        let public_key = PublicKey::from_slice(
+            &hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap(),
+        )
+        .unwrap();
+        let hash = public_key.inner().pubkey_hash().to_byte_array();
+
+        for network in [Network::Mainnet, Network::Testnet] {
+            assert_eq!(
+                Address::p2pkh_from_hash(hash, network),
+                Address::from_public_key(&public_key, network, AddressType::P2pkh)
+            );
+            assert_eq!(
+                Address::p2wpkh_from_hash(hash, network).unwrap(),
+                Address::from_public_key(&public_key, network, AddressType::P2wpkh)
+            );
+        }
+        assert_eq!(
+            Address::p2wpkh_from_hash(hash, Network::Mainnet)
+                .unwrap()
+                .to_string(),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+    }
+
+    #[test]
+    fn test_bitcoin_address_round_trip() {
+        let address = Address::parse(
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            Network::Mainnet,
+        )
+        .unwrap();
+        let btc = BtcAddress::from(address.clone());
+        assert_eq!(btc.to_string(), address.to_string());
+        assert_eq!(Address::try_from(btc).unwrap(), address);
+
+        let regtest = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"
+            .parse::<BtcAddress<_>>()
+            .unwrap()
+            .require_network(bitcoin::Network::Regtest)
+            .unwrap();
+        assert!(Address::try_from(regtest).is_err());
+    }
+
+    #[test]
+    fn test_p2sh_from_redeem_script_op_true() {
+        let script = [crate::script::OP_1];
+        let mainnet = Address::p2sh_from_redeem_script(&script, Network::Mainnet);
+        assert_eq!(mainnet.to_string(), "3MaB7QVq3k4pQx3BhsvEADgzQonLSBwMdj");
+        assert_eq!(
+            hex::encode(mainnet.script_pubkey()),
+            "a914da1745e9b549bd0bfa1a569971c77eba30cd5a4b87"
+        );
+        assert_eq!(
+            Address::p2sh_from_redeem_script(&script, Network::Testnet).to_string(),
+            "2ND8PB9RrfCaAcjfjP1Y6nAgFd9zWHYX4DN"
+        );
+    }
+
+    #[test]
+    fn test_parse_wrong_network() {
+        assert!(matches!(
+            Address::parse("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", Network::Testnet),
+            Err(Error::NetworkMismatch {
+                expected: "testnet",
+                actual: "mainnet"
+            })
+        ));
+        assert!(matches!(
+            Address::parse(
+                "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080",
+                Network::Mainnet
+            ),
+            Err(Error::NetworkMismatch {
+                expected: "mainnet",
+                actual: "regtest"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_is_valid_for() {
+        let mainnet = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        assert!(Address::is_valid_for(mainnet, Network::Mainnet));
+        assert!(!Address::is_valid_for(mainnet, Network::Testnet));
+        assert!(Address::is_valid_for(
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            Network::Mainnet
+        ));
+        assert!(!Address::is_valid_for(
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3",
+            Network::Mainnet
+        ));
+    }
+
+    #[test]
+    fn test_parse_bech32_hrp_mismatch() {
+        assert!(matches!(
+            Address::parse(
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                Network::Testnet
+            ),
+            Err(Error::NetworkMismatch {
+                expected: "testnet",
+                actual: "mainnet"
+            })
+        ));
+        // Checked before decoding, and case-insensitively.
+        assert!(matches!(
+            Address::parse("TB1QINVALID", Network::Mainnet),
+            Err(Error::NetworkMismatch {
+                expected: "mainnet",
+                actual: "testnet"
+            })
+        ));
+        assert!(
+            Address::parse(
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+                Network::Testnet
+            )
+            .is_ok()
+        );
+    }
 }
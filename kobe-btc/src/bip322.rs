@@ -0,0 +1,328 @@
+//! BIP-322 generic signed messages ("simple" variant).
+//!
+//! Unlike BIP-137, BIP-322 proves control of a scriptPubKey by signing a
+//! virtual transaction. `to_spend` commits to the message hash and pays the
+//! address; `to_sign` spends it to an `OP_RETURN` output. The "simple"
+//! signature is the consensus-encoded witness stack of `to_sign`'s input.
+//!
+//! Native SegWit P2WPKH and Taproot key-path (P2TR) addresses are supported.
+//! Encode the signature with base64 for the usual text form.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use bitcoin::absolute::LockTime;
+use bitcoin::hashes::{Hash, HashEngine, sha256};
+use bitcoin::key::{Keypair, TapTweak};
+use bitcoin::secp256k1::{Message, Secp256k1, SecretKey, XOnlyPublicKey};
+use bitcoin::sighash::{EcdsaSighashType, Prevouts, SighashCache, TapSighashType};
+use bitcoin::transaction::Version;
+use bitcoin::{
+    Amount, CompressedPublicKey, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid,
+    Witness, WitnessVersion, consensus, opcodes, script,
+};
+
+use crate::{Address, Error, StandardWallet};
+
+/// Tag of the BIP-340 tagged hash over the message.
+const MESSAGE_TAG: &[u8] = b"BIP0322-signed-message";
+
+/// Compute the BIP-322 message hash, `tagged_hash("BIP0322-signed-message", message)`.
+#[must_use]
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let tag = sha256::Hash::hash(MESSAGE_TAG).to_byte_array();
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag);
+    engine.input(&tag);
+    engine.input(message);
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+/// Build the virtual `to_spend` transaction committing to `message` and
+/// paying `script_pubkey`.
+#[must_use]
+pub fn to_spend(script_pubkey: &ScriptBuf, message: &[u8]) -> Transaction {
+    let script_sig = script::Builder::new()
+        .push_opcode(opcodes::OP_0)
+        .push_slice(message_hash(message))
+        .into_script();
+    Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::new(Txid::all_zeros(), 0xffff_ffff),
+            script_sig,
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: script_pubkey.clone(),
+        }],
+    }
+}
+
+/// Build the unsigned virtual `to_sign` transaction spending `to_spend`.
+#[must_use]
+pub fn to_sign(to_spend: &Transaction) -> Transaction {
+    Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::new(to_spend.compute_txid(), 0),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .into_script(),
+        }],
+    }
+}
+
+/// Sign `message` for `address` with the "simple" variant, returning the
+/// consensus-encoded witness stack.
+///
+/// The result is deterministic: ECDSA signatures are ground for a low `r`
+/// as Bitcoin Core does, and Taproot signatures use BIP-340 without
+/// auxiliary randomness.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAddress`] if `address` is neither P2WPKH nor
+/// P2TR, or is not controlled by `wallet`'s key.
+pub fn sign_simple(
+    wallet: &StandardWallet,
+    address: &Address,
+    message: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let secp = Secp256k1::new();
+    let secret = SecretKey::from_slice(wallet.secret_bytes().as_slice())?;
+    let script_pubkey = address.inner().script_pubkey();
+    let spend = to_spend(&script_pubkey, message);
+    let sign = to_sign(&spend);
+    let mut cache = SighashCache::new(&sign);
+
+    let witness = match witness_version(&script_pubkey)? {
+        WitnessVersion::V0 => {
+            let public_key = CompressedPublicKey(secret.public_key(&secp));
+            if ScriptBuf::new_p2wpkh(&public_key.wpubkey_hash()) != script_pubkey {
+                return Err(Error::InvalidAddress);
+            }
+            let sighash = cache
+                .p2wpkh_signature_hash(0, &script_pubkey, Amount::ZERO, EcdsaSighashType::All)
+                .map_err(|_| Error::InvalidAddress)?;
+            let signature = bitcoin::ecdsa::Signature::sighash_all(
+                secp.sign_ecdsa_low_r(&Message::from_digest(sighash.to_byte_array()), &secret),
+            );
+            Witness::p2wpkh(&signature, &public_key.0)
+        }
+        WitnessVersion::V1 => {
+            let keypair = Keypair::from_secret_key(&secp, &secret)
+                .tap_tweak(&secp, None)
+                .to_keypair();
+            if ScriptBuf::new_p2tr_tweaked(keypair.x_only_public_key().0.dangerous_assume_tweaked())
+                != script_pubkey
+            {
+                return Err(Error::InvalidAddress);
+            }
+            let sighash = cache
+                .taproot_key_spend_signature_hash(
+                    0,
+                    &Prevouts::All(&spend.output),
+                    TapSighashType::Default,
+                )
+                .map_err(|_| Error::InvalidAddress)?;
+            let signature = secp
+                .sign_schnorr_no_aux_rand(&Message::from_digest(sighash.to_byte_array()), &keypair);
+            Witness::p2tr_key_spend(&bitcoin::taproot::Signature {
+                signature,
+                sighash_type: TapSighashType::Default,
+            })
+        }
+        _ => return Err(Error::InvalidAddress),
+    };
+    Ok(consensus::serialize(&witness))
+}
+
+/// Verify a "simple" BIP-322 `signature` (consensus-encoded witness stack)
+/// of `message` by `address`.
+#[must_use]
+pub fn verify_simple(address: &Address, message: &[u8], signature: &[u8]) -> bool {
+    let Ok(witness) = consensus::deserialize::<Witness>(signature) else {
+        return false;
+    };
+    let script_pubkey = address.inner().script_pubkey();
+    let spend = to_spend(&script_pubkey, message);
+    let sign = to_sign(&spend);
+    let mut cache = SighashCache::new(&sign);
+    let secp = Secp256k1::verification_only();
+
+    match (witness_version(&script_pubkey), witness.len()) {
+        (Ok(WitnessVersion::V0), 2) => {
+            let (Some(sig_bytes), Some(key_bytes)) = (witness.nth(0), witness.nth(1)) else {
+                return false;
+            };
+            let (Ok(ecdsa_sig), Ok(public_key)) = (
+                bitcoin::ecdsa::Signature::from_slice(sig_bytes),
+                CompressedPublicKey::from_slice(key_bytes),
+            ) else {
+                return false;
+            };
+            if ScriptBuf::new_p2wpkh(&public_key.wpubkey_hash()) != script_pubkey {
+                return false;
+            }
+            cache
+                .p2wpkh_signature_hash(0, &script_pubkey, Amount::ZERO, ecdsa_sig.sighash_type)
+                .is_ok_and(|sighash| {
+                    secp.verify_ecdsa(
+                        &Message::from_digest(sighash.to_byte_array()),
+                        &ecdsa_sig.signature,
+                        &public_key.0,
+                    )
+                    .is_ok()
+                })
+        }
+        (Ok(WitnessVersion::V1), 1) => {
+            let Some(Ok(schnorr_sig)) = witness.nth(0).map(bitcoin::taproot::Signature::from_slice)
+            else {
+                return false;
+            };
+            let Ok(output_key) = XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..]) else {
+                return false;
+            };
+            cache
+                .taproot_key_spend_signature_hash(
+                    0,
+                    &Prevouts::All(&spend.output),
+                    schnorr_sig.sighash_type,
+                )
+                .is_ok_and(|sighash| {
+                    secp.verify_schnorr(
+                        &schnorr_sig.signature,
+                        &Message::from_digest(sighash.to_byte_array()),
+                        &output_key,
+                    )
+                    .is_ok()
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Get the witness version of a P2WPKH or P2TR output script.
+fn witness_version(script_pubkey: &ScriptBuf) -> Result<WitnessVersion, Error> {
+    if script_pubkey.is_p2wpkh() {
+        Ok(WitnessVersion::V0)
+    } else if script_pubkey.is_p2tr() {
+        Ok(WitnessVersion::V1)
+    } else {
+        Err(Error::InvalidAddress)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{AddressType, Network};
+    use alloc::string::String;
+
+    /// Private key of the BIP-322 test vectors.
+    const WIF: &str = "L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k";
+    const P2WPKH_ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+
+    fn reversed_hex(txid: Txid) -> String {
+        let mut bytes = txid.to_byte_array();
+        bytes.reverse();
+        hex::encode(bytes)
+    }
+
+    #[test]
+    fn test_message_hash_vectors() {
+        assert_eq!(
+            hex::encode(message_hash(b"")),
+            "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+        );
+        assert_eq!(
+            hex::encode(message_hash(b"Hello World")),
+            "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+        );
+    }
+
+    #[test]
+    fn test_virtual_transaction_vectors() {
+        let address = Address::parse(P2WPKH_ADDRESS, Network::Mainnet).unwrap();
+        let script_pubkey = address.inner().script_pubkey();
+
+        let spend = to_spend(&script_pubkey, b"");
+        assert_eq!(
+            reversed_hex(spend.compute_txid()),
+            "c5680aa69bb8d860bf82d4e9cd3504b55dde018de765a91bb566283c545a99a7"
+        );
+        assert_eq!(
+            reversed_hex(to_sign(&spend).compute_txid()),
+            "1e9654e951a5ba44c8604c4de6c67fd78a27e81dcadcfe1edf638ba3aaebaed6"
+        );
+
+        let hello = to_spend(&script_pubkey, b"Hello World");
+        assert_eq!(
+            reversed_hex(hello.compute_txid()),
+            "b79d196740ad5217771c1098fc4a4b51e0535c32236c71f1ea4d61a2d603352b"
+        );
+        assert_eq!(
+            reversed_hex(to_sign(&hello).compute_txid()),
+            "88737ae86f2077145f93cc4b153ae9a1cb8d56afa511988c149c5c8c9d93bddf"
+        );
+    }
+
+    #[test]
+    fn test_p2wpkh_reference_signature() {
+        let address = Address::parse(P2WPKH_ADDRESS, Network::Mainnet).unwrap();
+        // "Hello World" signature from the BIP-322 test vectors (base64-decoded).
+        let signature = hex::decode(
+            "0247304402206517c8637a7bfc3a154edcba6196d64bbd5b73955cb7da7d1626bcdde466c364022022bf10d19fc0bb69b4596e306b362acaa835293cf693bb176f7324b531f5afec012102c7f12003196442943d8588e01aee840423cc54fc1521526a3b85c2b0cbd58872",
+        )
+        .unwrap();
+        assert!(verify_simple(&address, b"Hello World", &signature));
+        assert!(!verify_simple(&address, b"Hello World!", &signature));
+
+        let wallet = StandardWallet::from_wif(WIF, AddressType::P2wpkh).unwrap();
+        assert_eq!(wallet.address(), P2WPKH_ADDRESS);
+        let signed = sign_simple(&wallet, &address, b"Hello World").unwrap();
+        assert_eq!(signed, signature);
+    }
+
+    #[test]
+    fn test_p2tr_round_trip() {
+        let wallet = StandardWallet::from_wif(WIF, AddressType::P2tr).unwrap();
+        let address = Address::parse(&wallet.address(), Network::Mainnet).unwrap();
+        let signature = sign_simple(&wallet, &address, b"Hello World").unwrap();
+        assert!(verify_simple(&address, b"Hello World", &signature));
+        assert!(!verify_simple(&address, b"", &signature));
+    }
+
+    #[test]
+    fn test_rejects_foreign_or_unsupported_address() {
+        let wallet = StandardWallet::from_wif(WIF, AddressType::P2wpkh).unwrap();
+        let other = Address::parse(
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            Network::Mainnet,
+        )
+        .unwrap();
+        assert!(matches!(
+            sign_simple(&wallet, &other, b"hi"),
+            Err(Error::InvalidAddress)
+        ));
+        let legacy =
+            Address::parse("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", Network::Mainnet).unwrap();
+        assert!(matches!(
+            sign_simple(&wallet, &legacy, b"hi"),
+            Err(Error::InvalidAddress)
+        ));
+        assert!(!verify_simple(&legacy, b"hi", &[0x00]));
+    }
+}
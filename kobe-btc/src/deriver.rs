@@ -45,6 +45,7 @@ pub struct Deriver<'a> {
 
 /// A derived Bitcoin address with associated keys.
 #[derive(Debug, Clone)]
+#[allow(clippy::exhaustive_structs)]
 pub struct DerivedAddress {
     /// Derivation path used (e.g., `m/84'/0'/0'/0/0`).
     pub path: DerivationPath,
@@ -169,11 +170,6 @@ pub fn derive_many_with(
     /// # Errors
     ///
     /// Returns an error if derivation fails.
-    ///
-    /// # Panics
-    ///
-    /// This function will not panic under normal circumstances.
-    /// The internal `expect` is guaranteed to succeed for valid private keys.
     pub fn derive_path(
         &self,
         path: &DerivationPath,
@@ -184,7 +180,7 @@ pub fn derive_path(
 
         let private_key = PrivateKey::new(derived.private_key, self.network.to_bitcoin_network());
         let public_key = CompressedPublicKey::from_private_key(&secp, &private_key)
-            .expect("valid private key always produces valid public key");
+            .map_err(|_| Error::InvalidPrivateKey)?;
 
         let address = create_address(&public_key, self.network, address_type);
 
@@ -209,8 +205,10 @@ pub const fn network(&self) -> Network {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use kobe::testing::BIP49_BTC_VECTOR;
 
     const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
 
@@ -255,8 +253,8 @@ fn test_derive_with_p2sh() {
         let deriver = Deriver::new(&wallet, Network::Mainnet).unwrap();
         let addr = deriver.derive_with(AddressType::P2shP2wpkh, 0).unwrap();
 
-        assert!(addr.address.starts_with('3'));
-        assert_eq!(addr.path.to_string(), "m/49'/0'/0'/0/0");
+        assert_eq!(addr.address, BIP49_BTC_VECTOR.address);
+        assert_eq!(addr.path.to_string(), BIP49_BTC_VECTOR.path);
     }
 
     #[test]
@@ -15,6 +15,7 @@ pub enum Error {
     /// Invalid mnemonic phrase.
     Mnemonic(bip39::Error),
     /// BIP32 derivation error.
+    #[cfg(feature = "bitcoin")]
     Bip32(bitcoin::bip32::Error),
     /// Invalid word count for mnemonic.
     InvalidWordCount(usize),
@@ -27,7 +28,41 @@ pub enum Error {
     InvalidHex,
     /// Invalid private key.
     InvalidPrivateKey,
+    /// Invalid public key.
+    InvalidPublicKey,
+    /// Invalid or unverifiable signature.
+    InvalidSignature,
+    /// Invalid address string.
+    InvalidAddress,
+    /// Extended key violates BIP-32 structural invariants.
+    InvalidExtendedKey,
+    /// Locktime or sequence value out of range.
+    InvalidTimelock(u32),
+    /// Key or address belongs to a different network than requested.
+    NetworkMismatch {
+        /// Name of the requested network.
+        expected: &'static str,
+        /// Name of the network the input was encoded for.
+        actual: &'static str,
+    },
+    /// Amount is not denominated in satoshi.
+    InvalidAmount,
+    /// Input value cannot cover the required fee plus a non-dust output.
+    InsufficientFunds {
+        /// Fee required, in satoshis.
+        required: u64,
+        /// Value available, in satoshis.
+        available: u64,
+    },
+    /// Transaction fee exceeds a sanity cap.
+    ExcessiveFee {
+        /// Total fee, in satoshis.
+        fee: u64,
+        /// Fee rate, in sat/vB.
+        fee_rate: u64,
+    },
     /// Secp256k1 error.
+    #[cfg(feature = "bitcoin")]
     Secp256k1(bitcoin::secp256k1::Error),
 }
 
@@ -35,6 +70,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Mnemonic(e) => write!(f, "mnemonic error: {e}"),
+            #[cfg(feature = "bitcoin")]
             Self::Bip32(e) => write!(f, "BIP32 derivation error: {e}"),
             Self::InvalidWordCount(n) => {
                 write!(f, "invalid word count {n}, must be 12, 15, 18, 21, or 24")
@@ -44,6 +80,27 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::InvalidWif => write!(f, "invalid WIF format"),
             Self::InvalidHex => write!(f, "invalid hex string"),
             Self::InvalidPrivateKey => write!(f, "invalid private key"),
+            Self::InvalidPublicKey => write!(f, "invalid public key"),
+            Self::InvalidSignature => write!(f, "invalid signature"),
+            Self::InvalidAddress => write!(f, "invalid address"),
+            Self::InvalidExtendedKey => write!(f, "invalid extended key"),
+            Self::InvalidTimelock(value) => write!(f, "invalid timelock value {value:#x}"),
+            Self::InvalidAmount => write!(f, "amount is not denominated in satoshi"),
+            Self::NetworkMismatch { expected, actual } => {
+                write!(f, "network mismatch: expected {expected}, got {actual}")
+            }
+            Self::InsufficientFunds {
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient funds: {available} sat available, {required} sat required"
+            ),
+            Self::ExcessiveFee { fee, fee_rate } => write!(
+                f,
+                "excessive fee: {fee} sat at {fee_rate} sat/vB exceeds the allowed maximum"
+            ),
+            #[cfg(feature = "bitcoin")]
             Self::Secp256k1(e) => write!(f, "secp256k1 error: {e}"),
         }
     }
@@ -54,12 +111,23 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Mnemonic(e) => Some(e),
+            #[cfg(feature = "bitcoin")]
             Self::Bip32(e) => Some(e),
+            #[cfg(feature = "bitcoin")]
             Self::Secp256k1(e) => Some(e),
             Self::InvalidWordCount(_)
             | Self::InvalidWif
             | Self::InvalidHex
-            | Self::InvalidPrivateKey => None,
+            | Self::InvalidPrivateKey
+            | Self::InvalidPublicKey
+            | Self::InvalidSignature
+            | Self::InvalidAddress
+            | Self::InvalidExtendedKey
+            | Self::InvalidTimelock(_)
+            | Self::NetworkMismatch { .. }
+            | Self::InvalidAmount
+            | Self::InsufficientFunds { .. }
+            | Self::ExcessiveFee { .. } => None,
             #[cfg(feature = "alloc")]
             Self::InvalidDerivationPath(_) => None,
         }
@@ -72,12 +140,14 @@ fn from(err: bip39::Error) -> Self {
     }
 }
 
+#[cfg(feature = "bitcoin")]
 impl From<bitcoin::bip32::Error> for Error {
     fn from(err: bitcoin::bip32::Error) -> Self {
         Self::Bip32(err)
     }
 }
 
+#[cfg(feature = "bitcoin")]
 impl From<bitcoin::secp256k1::Error> for Error {
     fn from(err: bitcoin::secp256k1::Error) -> Self {
         Self::Secp256k1(err)
@@ -0,0 +1,619 @@
+//! BIP-32 extended private and public keys.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use bitcoin::base58;
+use bitcoin::bip32::{ChildNumber, Xpriv, Xpub};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::{Address, DerivationPath, Error, Network, PublicKey};
+
+/// Length of a serialized BIP-32 extended key.
+pub const EXTENDED_KEY_LEN: usize = 78;
+
+/// A BIP-32 extended private key.
+///
+/// # Example
+///
+/// ```
+/// use kobe_btc::{ExtendedPrivateKey, Network};
+///
+/// let seed = [0x42u8; 64];
+/// let master = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+/// let bytes = master.to_bytes();
+/// assert_eq!(ExtendedPrivateKey::from_bytes(&bytes).unwrap(), master);
+/// ```
+///
+/// The secret key and chain code are wiped on drop, and the `Debug` output
+/// omits them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExtendedPrivateKey {
+    /// Underlying `bitcoin` extended private key.
+    inner: Xpriv,
+}
+
+impl ExtendedPrivateKey {
+    /// Create a master key from a wallet seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the seed produces an invalid key.
+    #[inline]
+    pub fn new_master(seed: &[u8], network: Network) -> Result<Self, Error> {
+        Ok(Self {
+            inner: Xpriv::new_master(network.to_bitcoin_network(), seed)?,
+        })
+    }
+
+    /// Derive a child key at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if derivation fails.
+    pub fn derive(&self, path: &DerivationPath) -> Result<Self, Error> {
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        Ok(Self {
+            inner: self.inner.derive_priv(&secp, path.inner())?,
+        })
+    }
+
+    /// Decode from the raw 78-byte BIP-32 serialization (no Base58Check layer).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid extended private key.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8; EXTENDED_KEY_LEN]) -> Result<Self, Error> {
+        Ok(Self {
+            inner: Xpriv::decode(bytes)?,
+        })
+    }
+
+    /// Encode as the raw 78-byte BIP-32 serialization (zeroized on drop).
+    #[inline]
+    #[must_use]
+    pub fn to_bytes(&self) -> Zeroizing<[u8; EXTENDED_KEY_LEN]> {
+        Zeroizing::new(self.inner.encode())
+    }
+
+    /// Encode as a Base58Check `xprv`/`tprv` string (zeroized on drop).
+    #[inline]
+    #[must_use]
+    pub fn to_base58(&self) -> Zeroizing<String> {
+        Zeroizing::new(base58::encode_check(self.to_bytes().as_slice()))
+    }
+
+    /// Get the corresponding extended public key.
+    #[must_use]
+    pub fn to_extended_public_key(&self) -> ExtendedPublicKey {
+        let secp = bitcoin::secp256k1::Secp256k1::signing_only();
+        ExtendedPublicKey {
+            inner: Xpub::from_priv(&secp, &self.inner),
+        }
+    }
+
+    /// Derive the hardened account node `m/purpose'/coin_type'/account'`
+    /// from this (master) key and export its public key for watch-only use.
+    ///
+    /// The version bytes follow SLIP-132 for the purpose: `ypub`/`upub` for
+    /// BIP-49, `zpub`/`vpub` for BIP-84, and `xpub`/`tpub` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an index is not below `2^31` or derivation fails.
+    pub fn account_xpub(
+        &self,
+        purpose: u32,
+        coin_type: u32,
+        account: u32,
+    ) -> Result<String, Error> {
+        let path = [
+            ChildNumber::from_hardened_idx(purpose)?,
+            ChildNumber::from_hardened_idx(coin_type)?,
+            ChildNumber::from_hardened_idx(account)?,
+        ];
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        let account_key = self.inner.derive_priv(&secp, &path)?;
+        let mut bytes = Xpub::from_priv(&secp, &account_key).encode();
+        bytes[..4].copy_from_slice(&slip132_public_version(purpose, self.network()));
+        Ok(base58::encode_check(&bytes))
+    }
+
+    /// Derive the first `count` BIP-44 account nodes
+    /// `m/44'/coin_type'/0'`, `m/44'/coin_type'/1'`, ... from this (master)
+    /// key.
+    ///
+    /// Account discovery scans these in order until it finds an account
+    /// with no transaction history.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an index is not below `2^31` or derivation fails.
+    pub fn accounts(&self, coin_type: u32, count: u32) -> Result<Vec<Self>, Error> {
+        let path = [
+            ChildNumber::from_hardened_idx(44)?,
+            ChildNumber::from_hardened_idx(coin_type)?,
+        ];
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        let coin_key = self.inner.derive_priv(&secp, &path)?;
+        (0..count)
+            .map(|account| {
+                let child = [ChildNumber::from_hardened_idx(account)?];
+                Ok(Self {
+                    inner: coin_key.derive_priv(&secp, &child)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Check that the key at `path` below this key owns `expected`.
+    ///
+    /// Every [`crate::AddressType`] is tried on this key's network, so the
+    /// caller does not need to know which format the address uses. This is
+    /// meant for confirming a restored wallet before trusting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` is malformed or derivation fails.
+    pub fn verify_derives_to(&self, path: &str, expected: &Address) -> Result<bool, Error> {
+        let public_key = self
+            .derive(&DerivationPath::from_path_str(path)?)?
+            .to_extended_public_key()
+            .public_key();
+        Ok(public_key
+            .all_addresses(self.network())
+            .iter()
+            .any(|(_, address)| address == expected))
+    }
+
+    /// Derive the external (receive) chain key `.../0/index` from this
+    /// account node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is not below `2^31` or derivation fails.
+    pub fn receive_address_key(&self, index: u32) -> Result<Self, Error> {
+        self.derive_chain(0, index)
+    }
+
+    /// Derive the internal (change) chain key `.../1/index` from this
+    /// account node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is not below `2^31` or derivation fails.
+    pub fn change_address_key(&self, index: u32) -> Result<Self, Error> {
+        self.derive_chain(1, index)
+    }
+
+    /// Derive the non-hardened `.../chain/index` key used for addresses.
+    fn derive_chain(&self, chain: u32, index: u32) -> Result<Self, Error> {
+        let path = [
+            ChildNumber::from_normal_idx(chain)?,
+            ChildNumber::from_normal_idx(index)?,
+        ];
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        Ok(Self {
+            inner: self.inner.derive_priv(&secp, &path)?,
+        })
+    }
+
+    /// Get the network of this key.
+    #[inline]
+    #[must_use]
+    pub const fn network(&self) -> Network {
+        network_from_kind(self.inner.network)
+    }
+
+    /// Get the underlying `bitcoin` extended private key.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &Xpriv {
+        &self.inner
+    }
+}
+
+impl FromStr for ExtendedPrivateKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            inner: Xpriv::from_str(s)?,
+        })
+    }
+}
+
+impl From<Xpriv> for ExtendedPrivateKey {
+    #[inline]
+    fn from(inner: Xpriv) -> Self {
+        Self { inner }
+    }
+}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("network", &self.network())
+            .field("depth", &self.inner.depth)
+            .field("parent_fingerprint", &self.inner.parent_fingerprint)
+            .field("child_number", &self.inner.child_number)
+            .field("private_key", &"[REDACTED]")
+            .field("chain_code", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl Zeroize for ExtendedPrivateKey {
+    /// Overwrite the secret key with a fixed dummy key and zero the chain code.
+    fn zeroize(&mut self) {
+        self.inner.private_key.non_secure_erase();
+        AsMut::<[u8; 32]>::as_mut(&mut self.inner.chain_code).zeroize();
+    }
+}
+
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ExtendedPrivateKey {}
+
+/// A BIP-32 extended public key.
+///
+/// Keys decoded from bytes or strings are only checked for a valid version
+/// and curve point; call [`ExtendedPublicKey::validate`] on untrusted input
+/// to also check the BIP-32 structural invariants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtendedPublicKey {
+    /// Underlying `bitcoin` extended public key.
+    inner: Xpub,
+}
+
+impl ExtendedPublicKey {
+    /// Decode from the raw 78-byte BIP-32 serialization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version or public key is invalid.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8; EXTENDED_KEY_LEN]) -> Result<Self, Error> {
+        Ok(Self {
+            inner: Xpub::decode(bytes)?,
+        })
+    }
+
+    /// Encode as the raw 78-byte BIP-32 serialization.
+    #[inline]
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; EXTENDED_KEY_LEN] {
+        self.inner.encode()
+    }
+
+    /// Check the BIP-32 structural invariants.
+    ///
+    /// A depth-0 (master) key must have child number `0` and an all-zero
+    /// parent fingerprint, and a key below the master must have a non-zero
+    /// parent fingerprint. The public key is re-checked as a valid curve point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidExtendedKey`] if an invariant is violated.
+    pub fn validate(&self) -> Result<(), Error> {
+        let is_master = self.inner.depth == 0;
+        let has_parent = self.parent_fingerprint() != [0u8; 4];
+        if is_master && (self.child_number() != 0 || has_parent) {
+            return Err(Error::InvalidExtendedKey);
+        }
+        if !is_master && !has_parent {
+            return Err(Error::InvalidExtendedKey);
+        }
+        k256::PublicKey::from_sec1_bytes(&self.inner.public_key.serialize())
+            .map_err(|_| Error::InvalidExtendedKey)?;
+        Ok(())
+    }
+
+    /// Get the depth (number of derivations from the master key).
+    #[inline]
+    #[must_use]
+    pub const fn depth(&self) -> u8 {
+        self.inner.depth
+    }
+
+    /// Get the raw child number (hardened indices include the `2^31` bit).
+    #[inline]
+    #[must_use]
+    pub fn child_number(&self) -> u32 {
+        self.inner.child_number.into()
+    }
+
+    /// Get the parent key fingerprint.
+    #[inline]
+    #[must_use]
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.inner.parent_fingerprint.to_bytes()
+    }
+
+    /// Get the public key.
+    #[inline]
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(bitcoin::CompressedPublicKey(self.inner.public_key))
+    }
+
+    /// Get the network of this key.
+    #[inline]
+    #[must_use]
+    pub const fn network(&self) -> Network {
+        network_from_kind(self.inner.network)
+    }
+
+    /// Get the underlying `bitcoin` extended public key.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &Xpub {
+        &self.inner
+    }
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            inner: Xpub::from_str(s)?,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<Xpub> for ExtendedPublicKey {
+    #[inline]
+    fn from(inner: Xpub) -> Self {
+        Self { inner }
+    }
+}
+
+/// Get the SLIP-132 extended public key version bytes for a BIP purpose.
+const fn slip132_public_version(purpose: u32, network: Network) -> [u8; 4] {
+    match (purpose, network) {
+        (49, Network::Mainnet) => [0x04, 0x9d, 0x7c, 0xb2],
+        (49, Network::Testnet) => [0x04, 0x4a, 0x52, 0x62],
+        (84, Network::Mainnet) => [0x04, 0xb2, 0x47, 0x46],
+        (84, Network::Testnet) => [0x04, 0x5f, 0x1c, 0xf6],
+        (_, Network::Mainnet) => [0x04, 0x88, 0xb2, 0x1e],
+        (_, Network::Testnet) => [0x04, 0x35, 0x87, 0xcf],
+    }
+}
+
+/// Map a `bitcoin` network kind to [`Network`].
+const fn network_from_kind(kind: bitcoin::NetworkKind) -> Network {
+    match kind {
+        bitcoin::NetworkKind::Main => Network::Mainnet,
+        bitcoin::NetworkKind::Test => Network::Testnet,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use kobe::testing::BIP32_VECTOR_1;
+
+    #[test]
+    fn test_bytes_base58_matches_xprv() {
+        for vector in BIP32_VECTOR_1 {
+            let seed = hex::decode(vector.seed_hex).unwrap();
+            let master = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+            let path = DerivationPath::from_path_str(vector.path).unwrap();
+            let key = master.derive(&path).unwrap();
+            let bytes = key.to_bytes();
+            assert_eq!(base58::encode_check(bytes.as_slice()), vector.xprv);
+            assert_eq!(key.to_base58().as_str(), vector.xprv);
+            assert_eq!(ExtendedPrivateKey::from_bytes(&bytes).unwrap(), key);
+            assert_eq!(vector.xprv.parse::<ExtendedPrivateKey>().unwrap(), key);
+        }
+    }
+
+    const MASTER_XPUB: &str = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+
+    #[test]
+    fn test_validate_master_xpub() {
+        let seed = hex::decode(BIP32_VECTOR_1[0].seed_hex).unwrap();
+        let master = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+        let xpub = master.to_extended_public_key();
+        assert_eq!(xpub.to_string(), MASTER_XPUB);
+        assert_eq!(MASTER_XPUB.parse::<ExtendedPublicKey>().unwrap(), xpub);
+        assert!(xpub.validate().is_ok());
+        assert_eq!(xpub.depth(), 0);
+
+        let path = DerivationPath::from_path_str("m/0'/1").unwrap();
+        let child = master.derive(&path).unwrap().to_extended_public_key();
+        assert!(child.validate().is_ok());
+        assert_eq!(child.depth(), 2);
+        assert_eq!(child.child_number(), 1);
+    }
+
+    #[test]
+    fn test_validate_rejects_tampered_master() {
+        let master = MASTER_XPUB.parse::<ExtendedPublicKey>().unwrap().to_bytes();
+
+        // Depth 0 with a non-zero child number.
+        let mut bytes = master;
+        bytes[12] = 1;
+        let tampered = ExtendedPublicKey::from_bytes(&bytes).unwrap();
+        assert!(matches!(
+            tampered.validate(),
+            Err(Error::InvalidExtendedKey)
+        ));
+
+        // Depth 1 with a zero parent fingerprint.
+        let mut orphan_bytes = master;
+        orphan_bytes[4] = 1;
+        let orphan = ExtendedPublicKey::from_bytes(&orphan_bytes).unwrap();
+        assert!(matches!(orphan.validate(), Err(Error::InvalidExtendedKey)));
+    }
+
+    #[test]
+    fn test_account_xpub_bip84() {
+        use crate::{Address, AddressType};
+        use kobe::Wallet;
+        use kobe::testing::BIP84_BTC_VECTOR;
+
+        let wallet = Wallet::from_mnemonic(BIP84_BTC_VECTOR.mnemonic, None).unwrap();
+        let master = ExtendedPrivateKey::new_master(wallet.seed(), Network::Mainnet).unwrap();
+        let zpub = master.account_xpub(84, 0, 0).unwrap();
+        // Account 0 extended public key from the BIP-84 test vectors.
+        assert_eq!(
+            zpub,
+            "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs"
+        );
+
+        // Watch-only derivation of `.../0/0` from the exported key.
+        let mut bytes: [u8; EXTENDED_KEY_LEN] =
+            base58::decode_check(&zpub).unwrap().try_into().unwrap();
+        bytes[..4].copy_from_slice(&[0x04, 0x88, 0xb2, 0x1e]);
+        let secp = bitcoin::secp256k1::Secp256k1::new();
+        let receive_0 = [
+            ChildNumber::Normal { index: 0 },
+            ChildNumber::Normal { index: 0 },
+        ];
+        let child = Xpub::decode(&bytes)
+            .unwrap()
+            .derive_pub(&secp, &receive_0)
+            .unwrap();
+        let watch_only = Address::from_public_key(
+            &ExtendedPublicKey::from(child).public_key(),
+            Network::Mainnet,
+            AddressType::P2wpkh,
+        );
+
+        let full_path = DerivationPath::from_path_str(BIP84_BTC_VECTOR.path).unwrap();
+        let full = Address::from_public_key(
+            &master
+                .derive(&full_path)
+                .unwrap()
+                .to_extended_public_key()
+                .public_key(),
+            Network::Mainnet,
+            AddressType::P2wpkh,
+        );
+        assert_eq!(watch_only, full);
+        assert_eq!(full.to_string(), BIP84_BTC_VECTOR.address);
+    }
+
+    #[test]
+    fn test_accounts() {
+        let seed = hex::decode(BIP32_VECTOR_1[0].seed_hex).unwrap();
+        let master = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+        let accounts = master.accounts(0, 3).unwrap();
+        assert_eq!(accounts.len(), 3);
+        for (index, account) in (0u32..).zip(&accounts) {
+            assert_eq!(account.inner().depth, 3);
+            assert_eq!(
+                account.inner().child_number,
+                ChildNumber::from_hardened_idx(index).unwrap()
+            );
+            let path = alloc::format!("m/44'/0'/{index}'");
+            let expected = master
+                .derive(&DerivationPath::from_path_str(&path).unwrap())
+                .unwrap();
+            assert_eq!(*account, expected);
+        }
+        assert!(master.accounts(0, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_verify_derives_to() {
+        use kobe::Wallet;
+        use kobe::testing::BIP84_BTC_VECTOR;
+
+        let wallet = Wallet::from_mnemonic(BIP84_BTC_VECTOR.mnemonic, None).unwrap();
+        let master = ExtendedPrivateKey::new_master(wallet.seed(), Network::Mainnet).unwrap();
+        let expected = Address::parse(BIP84_BTC_VECTOR.address, Network::Mainnet).unwrap();
+
+        assert!(
+            master
+                .verify_derives_to(BIP84_BTC_VECTOR.path, &expected)
+                .unwrap()
+        );
+        assert!(
+            !master
+                .verify_derives_to("m/84'/0'/0'/0/1", &expected)
+                .unwrap()
+        );
+        assert!(master.verify_derives_to("m/84'/x", &expected).is_err());
+    }
+
+    #[test]
+    fn test_receive_and_change_keys() {
+        use kobe::Wallet;
+        use kobe::testing::BIP84_BTC_VECTOR;
+
+        let wallet = Wallet::from_mnemonic(BIP84_BTC_VECTOR.mnemonic, None).unwrap();
+        let master = ExtendedPrivateKey::new_master(wallet.seed(), Network::Mainnet).unwrap();
+        let account = master
+            .derive(&DerivationPath::from_path_str("m/84'/0'/0'").unwrap())
+            .unwrap();
+
+        let receive = account.receive_address_key(0).unwrap();
+        let change = account.change_address_key(0).unwrap();
+        assert_ne!(receive, change);
+        assert_eq!(
+            receive,
+            master
+                .derive(&DerivationPath::from_path_str("m/84'/0'/0'/0/0").unwrap())
+                .unwrap()
+        );
+        assert_eq!(
+            change,
+            master
+                .derive(&DerivationPath::from_path_str("m/84'/0'/0'/1/0").unwrap())
+                .unwrap()
+        );
+        assert!(account.receive_address_key(1 << 31).is_err());
+    }
+
+    #[test]
+    fn test_account_xpub_prefixes() {
+        let master = ExtendedPrivateKey::new_master(&[0x42; 64], Network::Mainnet).unwrap();
+        assert!(master.account_xpub(44, 0, 0).unwrap().starts_with("xpub"));
+        assert!(master.account_xpub(49, 0, 0).unwrap().starts_with("ypub"));
+        assert!(master.account_xpub(86, 0, 0).unwrap().starts_with("xpub"));
+        assert!(master.account_xpub(84, 0, 1 << 31).is_err());
+
+        let testnet = ExtendedPrivateKey::new_master(&[0x42; 64], Network::Testnet).unwrap();
+        assert!(testnet.account_xpub(84, 1, 0).unwrap().starts_with("vpub"));
+        assert!(testnet.account_xpub(44, 1, 0).unwrap().starts_with("tpub"));
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        assert!(ExtendedPrivateKey::from_bytes(&[0u8; EXTENDED_KEY_LEN]).is_err());
+    }
+
+    #[test]
+    fn test_zeroize_and_redacted_debug() {
+        const fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<ExtendedPrivateKey>();
+
+        let mut key = ExtendedPrivateKey::new_master(&[0x42; 64], Network::Mainnet).unwrap();
+        let secret = key.inner.private_key.secret_bytes();
+        let debug = format!("{key:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(&hex::encode(secret)));
+        assert!(!debug.contains(&hex::encode(key.inner.chain_code.as_bytes())));
+
+        key.zeroize();
+        assert_ne!(key.inner.private_key.secret_bytes(), secret);
+        assert_eq!(key.inner.chain_code.as_bytes(), &[0u8; 32]);
+    }
+}
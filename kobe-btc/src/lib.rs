@@ -4,9 +4,12 @@
 //!
 //! # Features
 //!
-//! - `std` (default): Enable standard library support
+//! - `std` (default): Enable standard library support; implies `bitcoin`
 //! - `alloc`: Enable heap allocation without full std (for `no_std` environments)
+//! - `bitcoin`: Keys and addresses via the `bitcoin` crate (libsecp256k1)
+//! - `pure-rust`: Enable the [`pure`] module, a `k256`-based backend without C code
 //! - `rand`: Enable random key generation for `StandardWallet`
+//! - `wasm`: Enable `rand` with the browser RNG backend for `wasm32-unknown-unknown`
 //!
 //! # Usage
 //!
@@ -31,23 +34,55 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 mod address;
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+pub mod bip322;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 mod deriver;
 mod error;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+mod extended_key;
+#[cfg(feature = "bitcoin")]
+pub mod message;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+pub mod musig2;
 mod network;
+#[cfg(feature = "bitcoin")]
+mod public_key;
+#[cfg(feature = "pure-rust")]
+pub mod pure;
+#[cfg(feature = "bitcoin")]
+mod schnorr;
 #[cfg(feature = "alloc")]
+pub mod script;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 mod standard_wallet;
+#[cfg(feature = "alloc")]
+mod timelock;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+pub mod tx;
 mod types;
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+pub use address::Address;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 pub use deriver::{DerivedAddress, Deriver};
 pub use error::Error;
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+pub use extended_key::{EXTENDED_KEY_LEN, ExtendedPrivateKey, ExtendedPublicKey};
+#[cfg(feature = "bitcoin")]
+pub use message::{MessageSignature, MessageSignatureType};
 pub use network::{Network, ParseNetworkError};
-#[cfg(feature = "alloc")]
+#[cfg(feature = "bitcoin")]
+pub use public_key::PublicKey;
+#[cfg(feature = "bitcoin")]
+pub use schnorr::{SchnorrItem, batch_verify_schnorr, verify_schnorr, verify_schnorr_each};
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 pub use standard_wallet::StandardWallet;
 #[cfg(feature = "alloc")]
+pub use timelock::{LockTime, Sequence};
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 pub use types::DerivationPath;
 pub use types::{AddressType, ParseAddressTypeError};
 
@@ -0,0 +1,269 @@
+//! BIP-137 signed messages.
+//!
+//! Litecoin, Dogecoin and other forks use the same scheme with a different
+//! magic prefix, so the prefix is a parameter; [`BITCOIN_MESSAGE_PREFIX`]
+//! is the default.
+//!
+//! The header byte range tells the verifier which address type the
+//! signature attests to; see [`MessageSignatureType`].
+
+use bitcoin::hashes::{Hash, HashEngine, sha256d};
+#[cfg(feature = "alloc")]
+use k256::ecdsa::SigningKey;
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use kobe::bytes::{varint_bytes, varint_len};
+
+#[cfg(feature = "alloc")]
+use crate::{Address, Network};
+use crate::{AddressType, Error, PublicKey};
+
+/// Magic prefix for Bitcoin signed messages.
+pub const BITCOIN_MESSAGE_PREFIX: &str = "Bitcoin Signed Message:\n";
+
+/// The address type a BIP-137 signature attests to, encoded in the range of
+/// its header byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum MessageSignatureType {
+    /// Legacy P2PKH (`1...`), header `31..=34` (`27..=30` for uncompressed keys).
+    #[default]
+    P2pkh,
+    /// Nested SegWit P2SH-P2WPKH (`3...`), header `35..=38`.
+    P2shP2wpkh,
+    /// Native SegWit P2WPKH (`bc1q...`), header `39..=42`.
+    P2wpkh,
+}
+
+impl MessageSignatureType {
+    /// Get the header byte for recovery id 0 with a compressed key.
+    #[inline]
+    #[must_use]
+    pub const fn header_base(self) -> u8 {
+        match self {
+            Self::P2pkh => 31,
+            Self::P2shP2wpkh => 35,
+            Self::P2wpkh => 39,
+        }
+    }
+
+    /// Get the corresponding address type.
+    #[inline]
+    #[must_use]
+    pub const fn address_type(self) -> AddressType {
+        match self {
+            Self::P2pkh => AddressType::P2pkh,
+            Self::P2shP2wpkh => AddressType::P2shP2wpkh,
+            Self::P2wpkh => AddressType::P2wpkh,
+        }
+    }
+}
+
+/// A 65-byte compact recoverable message signature (`header || r || s`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageSignature {
+    /// BIP-137 header byte (encodes the recovery id and key type).
+    header: u8,
+    /// Compact `r || s`.
+    rs: [u8; 64],
+}
+
+impl MessageSignature {
+    /// Decode from the 65-byte compact form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header byte is outside BIP-137's `27..=42`.
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<Self, Error> {
+        if !(27..=42).contains(&bytes[0]) {
+            return Err(Error::InvalidSignature);
+        }
+        let mut rs = [0u8; 64];
+        rs.copy_from_slice(&bytes[1..]);
+        Ok(Self {
+            header: bytes[0],
+            rs,
+        })
+    }
+
+    /// Encode to the 65-byte compact form.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = self.header;
+        bytes[1..].copy_from_slice(&self.rs);
+        bytes
+    }
+
+    /// Get the BIP-137 header byte.
+    #[inline]
+    #[must_use]
+    pub const fn header(&self) -> u8 {
+        self.header
+    }
+
+    /// Get the address type the signature attests to, from the header range.
+    #[inline]
+    #[must_use]
+    pub const fn signature_type(&self) -> MessageSignatureType {
+        match self.header {
+            35..=38 => MessageSignatureType::P2shP2wpkh,
+            39..=42 => MessageSignatureType::P2wpkh,
+            _ => MessageSignatureType::P2pkh,
+        }
+    }
+
+    /// Check whether the header marks an uncompressed-key P2PKH signature.
+    #[inline]
+    #[must_use]
+    pub const fn is_uncompressed(&self) -> bool {
+        self.header < MessageSignatureType::P2pkh.header_base()
+    }
+
+    /// Get the recovery id (`0..=3`).
+    #[inline]
+    #[must_use]
+    pub const fn recovery_id(&self) -> u8 {
+        (self.header - 27) % 4
+    }
+
+    /// Recover the signer's public key for a message signed with `prefix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid or recovery fails.
+    pub fn recover(&self, prefix: &str, message: &[u8]) -> Result<PublicKey, Error> {
+        let signature = Signature::from_slice(&self.rs).map_err(|_| Error::InvalidSignature)?;
+        let recovery_id =
+            RecoveryId::from_byte(self.recovery_id()).ok_or(Error::InvalidSignature)?;
+        let key = VerifyingKey::recover_from_prehash(
+            &signed_message_hash(prefix, message),
+            &signature,
+            recovery_id,
+        )
+        .map_err(|_| Error::InvalidSignature)?;
+        PublicKey::from_slice(key.to_encoded_point(true).as_bytes())
+    }
+
+    /// Recover the address the signature attests to, using the address
+    /// type encoded in the header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid or recovery fails.
+    #[cfg(feature = "alloc")]
+    pub fn recover_address(
+        &self,
+        prefix: &str,
+        message: &[u8],
+        network: Network,
+    ) -> Result<Address, Error> {
+        let public_key = self.recover(prefix, message)?;
+        if self.is_uncompressed() {
+            let uncompressed = bitcoin::PublicKey::new_uncompressed(public_key.inner().0);
+            return Ok(Address::p2pkh_from_hash(
+                uncompressed.pubkey_hash().to_byte_array(),
+                network,
+            ));
+        }
+        Ok(Address::from_public_key(
+            &public_key,
+            network,
+            self.signature_type().address_type(),
+        ))
+    }
+
+    /// Check whether the signature over `message` was made by `address`.
+    ///
+    /// Output scripts are compared, so the address network is not considered.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn verify(&self, prefix: &str, message: &[u8], address: &Address) -> bool {
+        self.recover_address(prefix, message, Network::Mainnet)
+            .is_ok_and(|recovered| recovered.script_pubkey() == address.script_pubkey())
+    }
+
+    /// Sign the hash of a message with a raw secret key, attesting to an
+    /// address of `signature_type`.
+    ///
+    /// `k256` always produces low-`s` signatures, as Bitcoin Core requires.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn sign(
+        secret: &[u8; 32],
+        prefix: &str,
+        message: &[u8],
+        signature_type: MessageSignatureType,
+    ) -> Result<Self, Error> {
+        let key = SigningKey::from_slice(secret).map_err(|_| Error::InvalidPrivateKey)?;
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(&signed_message_hash(prefix, message))
+            .map_err(|_| Error::InvalidSignature)?;
+        Ok(Self {
+            header: signature_type.header_base() + recovery_id.to_byte(),
+            rs: signature.to_bytes().into(),
+        })
+    }
+}
+
+/// Compute the double-SHA256 hash of `varint(len(prefix)) || prefix ||
+/// varint(len(message)) || message`.
+#[must_use]
+pub fn signed_message_hash(prefix: &str, message: &[u8]) -> [u8; 32] {
+    let mut engine = sha256d::Hash::engine();
+    for part in [prefix.as_bytes(), message] {
+        let len = part.len() as u64;
+        engine.input(&varint_bytes(len)[..varint_len(len)]);
+        engine.input(part);
+    }
+    sha256d::Hash::from_engine(engine).to_byte_array()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitcoin_prefix_hash() {
+        let message = "Hello, World!";
+        assert_eq!(
+            signed_message_hash(BITCOIN_MESSAGE_PREFIX, message.as_bytes()),
+            bitcoin::sign_message::signed_msg_hash(message).to_byte_array()
+        );
+    }
+
+    #[test]
+    fn test_long_message_hash() {
+        let message = "x".repeat(300);
+        assert_eq!(
+            signed_message_hash(BITCOIN_MESSAGE_PREFIX, message.as_bytes()),
+            bitcoin::sign_message::signed_msg_hash(&message).to_byte_array()
+        );
+    }
+
+    #[test]
+    fn test_header_validation() {
+        let mut bytes = [0u8; 65];
+        assert!(MessageSignature::from_bytes(&bytes).is_err());
+        bytes[0] = 31;
+        assert_eq!(
+            MessageSignature::from_bytes(&bytes).unwrap().to_bytes(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_signature_type_from_header() {
+        let mut bytes = [0u8; 65];
+        for (header, expected, uncompressed) in [
+            (27, MessageSignatureType::P2pkh, true),
+            (34, MessageSignatureType::P2pkh, false),
+            (35, MessageSignatureType::P2shP2wpkh, false),
+            (42, MessageSignatureType::P2wpkh, false),
+        ] {
+            bytes[0] = header;
+            let signature = MessageSignature::from_bytes(&bytes).unwrap();
+            assert_eq!(signature.signature_type(), expected);
+            assert_eq!(signature.is_uncompressed(), uncompressed);
+        }
+    }
+}
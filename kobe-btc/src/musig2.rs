@@ -0,0 +1,332 @@
+//! MuSig2 key aggregation (BIP-327).
+//!
+//! Aggregates several public keys into a single Taproot-compatible key, so
+//! that cooperating signers can produce one BIP-340 Schnorr signature.
+//! This module implements the deterministic key-aggregation and tweaking
+//! steps; nonce generation and partial signing build on [`KeyAggContext`].
+
+use alloc::vec::Vec;
+
+use k256::elliptic_curve::PrimeField;
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{AffinePoint, ProjectivePoint, Scalar};
+
+use crate::schnorr::{tagged_hash, tagged_scalar};
+use crate::{Error, PublicKey};
+
+/// Aggregated key state for a fixed, ordered set of signer keys.
+///
+/// The key order matters: different orders give different aggregate keys.
+/// Use [`key_sort`] first if signers do not agree on an order out of band.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyAggContext {
+    /// Signer keys in aggregation order.
+    keys: Vec<PublicKey>,
+    /// Hash of the full key list (`L` in BIP-327).
+    list_hash: [u8; 32],
+    /// The first key that differs from `keys[0]`, whose coefficient is `1`.
+    second_key: Option<PublicKey>,
+    /// The aggregate point `Q`, including any applied tweaks.
+    aggregate: AffinePoint,
+    /// Accumulated sign flips from x-only tweaks (`gacc`).
+    parity_acc: Scalar,
+    /// Accumulated tweak (`tacc`).
+    tweak_acc: Scalar,
+}
+
+impl KeyAggContext {
+    /// Aggregate the given public keys, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPublicKey`] if no keys are given, a key is not
+    /// a valid point, or the aggregate is the point at infinity.
+    pub fn new(keys: &[PublicKey]) -> Result<Self, Error> {
+        if keys.is_empty() {
+            return Err(Error::InvalidPublicKey);
+        }
+        let serialized: Vec<[u8; 33]> = keys.iter().map(PublicKey::to_bytes).collect();
+        let list_hash = tagged_hash(b"KeyAgg list", &[serialized.concat().as_slice()]);
+        let second_key = keys.iter().copied().find(|key| *key != keys[0]);
+
+        let mut context = Self {
+            keys: keys.to_vec(),
+            list_hash,
+            second_key,
+            aggregate: AffinePoint::IDENTITY,
+            parity_acc: Scalar::ONE,
+            tweak_acc: Scalar::ZERO,
+        };
+        let mut q = ProjectivePoint::IDENTITY;
+        for key in keys {
+            q += to_point(key)? * context.coefficient_scalar(key);
+        }
+        if bool::from(q.to_affine().is_identity()) {
+            return Err(Error::InvalidPublicKey);
+        }
+        context.aggregate = q.to_affine();
+        Ok(context)
+    }
+
+    /// Tweak the aggregate key by `tweak` (`ApplyTweak`).
+    ///
+    /// A plain tweak adds `tweak·G` to `Q`, as in BIP-32 derivation. An
+    /// x-only tweak first negates `Q` if its y-coordinate is odd, as in a
+    /// BIP-341 Taproot commitment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrivateKey`] if `tweak` is not below the curve
+    /// order, or [`Error::InvalidPublicKey`] if the tweaked key is the point
+    /// at infinity. The context is left unchanged on error.
+    pub fn apply_tweak(&mut self, tweak: &[u8; 32], is_xonly: bool) -> Result<(), Error> {
+        let t = Option::<Scalar>::from(Scalar::from_repr((*tweak).into()))
+            .ok_or(Error::InvalidPrivateKey)?;
+        let g = if is_xonly && bool::from(self.aggregate.y_is_odd()) {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+        let q = (ProjectivePoint::from(self.aggregate) * g + ProjectivePoint::GENERATOR * t)
+            .to_affine();
+        if bool::from(q.is_identity()) {
+            return Err(Error::InvalidPublicKey);
+        }
+        self.aggregate = q;
+        self.parity_acc *= g;
+        self.tweak_acc = t + g * self.tweak_acc;
+        Ok(())
+    }
+
+    /// Get the accumulated sign `gacc` of applied x-only tweaks: `true` if
+    /// it is `-1`.
+    #[must_use]
+    pub fn is_parity_negated(&self) -> bool {
+        self.parity_acc != Scalar::ONE
+    }
+
+    /// Get the big-endian accumulated tweak `tacc`.
+    #[must_use]
+    pub fn tweak_accumulator(&self) -> [u8; 32] {
+        self.tweak_acc.to_repr().into()
+    }
+
+    /// Get the aggregate public key `Q` (compressed, with its real parity).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the aggregate point cannot be encoded.
+    pub fn aggregate_public_key(&self) -> Result<PublicKey, Error> {
+        PublicKey::from_slice(self.aggregate.to_encoded_point(true).as_bytes())
+    }
+
+    /// Get the 32-byte x-only aggregate key, as used in a Taproot output.
+    #[must_use]
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        self.aggregate.x().into()
+    }
+
+    /// Get the signer keys in aggregation order.
+    #[inline]
+    #[must_use]
+    pub fn keys(&self) -> &[PublicKey] {
+        &self.keys
+    }
+
+    /// Get the big-endian aggregation coefficient `a_i` of a signer key.
+    ///
+    /// Returns `None` if the key is not part of this aggregation.
+    #[must_use]
+    pub fn key_coefficient(&self, key: &PublicKey) -> Option<[u8; 32]> {
+        self.keys
+            .contains(key)
+            .then(|| self.coefficient_scalar(key).to_repr().into())
+    }
+
+    /// Compute `KeyAggCoeff(L, key)`.
+    fn coefficient_scalar(&self, key: &PublicKey) -> Scalar {
+        if self.second_key.as_ref() == Some(key) {
+            Scalar::ONE
+        } else {
+            tagged_scalar(b"KeyAgg coefficient", &[&self.list_hash, &key.to_bytes()])
+        }
+    }
+}
+
+/// Sort public keys lexicographically by their compressed encoding (`KeySort`).
+pub fn key_sort(keys: &mut [PublicKey]) {
+    keys.sort_by_key(PublicKey::to_bytes);
+}
+
+/// Convert a public key to a curve point.
+fn to_point(key: &PublicKey) -> Result<ProjectivePoint, Error> {
+    k256::PublicKey::from_sec1_bytes(&key.to_bytes())
+        .map(|point| point.to_projective())
+        .map_err(|_| Error::InvalidPublicKey)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::verify_schnorr;
+    use bitcoin::secp256k1::{Keypair, Message, Secp256k1};
+
+    fn key(hex_str: &str) -> PublicKey {
+        PublicKey::from_slice(&hex::decode(hex_str).unwrap()).unwrap()
+    }
+
+    fn vector_keys() -> [PublicKey; 3] {
+        [
+            key("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            key("03DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"),
+            key("023590A94E768F8E1815C2F24B4D80A8E3149316C3518CE7B7AD338368D038CA66"),
+        ]
+    }
+
+    // BIP-327 key_agg_vectors.json, valid cases.
+    #[test]
+    fn test_key_agg_vectors() {
+        let [x1, x2, x3] = vector_keys();
+        let cases = [
+            (
+                vec![x1, x2, x3],
+                "90539EEDE565F5D054F32CC0C220126889ED1E5D193BAF15AEF344FE59D4610C",
+            ),
+            (
+                vec![x3, x2, x1],
+                "6204DE8B083426DC6EAF9502D27024D53FC826BF7D2012148A0575435DF54B2B",
+            ),
+            (
+                vec![x1, x1, x1],
+                "B436E3BAD62B8CD409969A224731C193D051162D8C5AE8B109306127DA3AA935",
+            ),
+            (
+                vec![x1, x1, x2, x2],
+                "69BC22BFA5D106306E48A20679DE1D7389386124D07571D0D872686028C26A3E",
+            ),
+        ];
+        for (keys, expected) in cases {
+            let context = KeyAggContext::new(&keys).unwrap();
+            assert_eq!(hex::encode_upper(context.x_only_public_key()), expected);
+        }
+    }
+
+    #[test]
+    fn test_key_sort() {
+        let [x1, x2, x3] = vector_keys();
+        let mut keys = [x1, x2, x3];
+        key_sort(&mut keys);
+        assert_eq!(keys, [x3, x1, x2]);
+    }
+
+    /// Signing with the aggregate secret `Σ aᵢ·xᵢ` must verify under the
+    /// aggregate x-only key, which checks the coefficients end to end.
+    #[test]
+    fn test_aggregate_secret_signature_verifies() {
+        let secp = Secp256k1::new();
+        let secrets = [[0x11u8; 32], [0x22u8; 32]];
+        let keypairs = secrets.map(|s| Keypair::from_seckey_slice(&secp, &s).unwrap());
+        let keys =
+            keypairs.map(|kp| PublicKey::from(bitcoin::key::CompressedPublicKey(kp.public_key())));
+        let context = KeyAggContext::new(&keys).unwrap();
+
+        let mut aggregate_secret = Scalar::ZERO;
+        for (secret, key) in secrets.iter().zip(&keys) {
+            let x = Option::<Scalar>::from(Scalar::from_repr((*secret).into())).unwrap();
+            let a = Option::<Scalar>::from(Scalar::from_repr(
+                context.key_coefficient(key).unwrap().into(),
+            ))
+            .unwrap();
+            aggregate_secret += a * x;
+        }
+        let secret_bytes: [u8; 32] = aggregate_secret.to_repr().into();
+        let aggregate_keypair = Keypair::from_seckey_slice(&secp, &secret_bytes).unwrap();
+        assert_eq!(
+            aggregate_keypair.x_only_public_key().0.serialize(),
+            context.x_only_public_key()
+        );
+
+        let message = [0x42u8; 32];
+        let signature =
+            secp.sign_schnorr_no_aux_rand(&Message::from_digest(message), &aggregate_keypair);
+        let aggregate = context.aggregate_public_key().unwrap();
+        assert!(verify_schnorr(&aggregate, &message, &signature.serialize()).is_ok());
+        assert!(verify_schnorr(&keys[0], &message, &signature.serialize()).is_err());
+    }
+
+    #[test]
+    fn test_empty_keys_rejected() {
+        assert!(KeyAggContext::new(&[]).is_err());
+    }
+
+    // BIP-327 key_agg_vectors.json, error cases.
+    #[test]
+    fn test_key_agg_error_vectors() {
+        // Not on the curve, x above the field size, and a bad prefix.
+        for invalid in [
+            "020000000000000000000000000000000000000000000000000000000000000005",
+            "02FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+            "04F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        ] {
+            let bytes = hex::decode(invalid).unwrap();
+            assert!(matches!(
+                PublicKey::from_slice(&bytes),
+                Err(Error::InvalidPublicKey)
+            ));
+        }
+
+        let [x1, x2, _] = vector_keys();
+        let order = hex::decode("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")
+            .unwrap();
+        let mut context = KeyAggContext::new(&[x1, x2]).unwrap();
+        let before = context.clone();
+        assert!(matches!(
+            context.apply_tweak(&order.try_into().unwrap(), true),
+            Err(Error::InvalidPrivateKey)
+        ));
+        assert_eq!(context, before);
+
+        let mut single = KeyAggContext::new(&[key(
+            "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+        )])
+        .unwrap();
+        let tweak = hex::decode("252E4BD67410A76CDF933D30EAA1608214037F1B105A013ECCD3C5C184A6110B")
+            .unwrap();
+        assert!(matches!(
+            single.apply_tweak(&tweak.try_into().unwrap(), false),
+            Err(Error::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn test_apply_tweak() {
+        let [x1, x2, x3] = vector_keys();
+        let tweak: [u8; 32] =
+            hex::decode("E8F791FF9225A2AF0102AFFF4A9A723D9612A682A25EBE79802B263CDFCD83BB")
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        // `Q` has an odd y-coordinate here, so the two tweak kinds differ.
+        let context = KeyAggContext::new(&[x3, x2, x1]).unwrap();
+        let mut xonly = context.clone();
+        xonly.apply_tweak(&tweak, true).unwrap();
+        assert_eq!(
+            hex::encode_upper(xonly.x_only_public_key()),
+            "317D8A78CAFE6577AFD84DFD841A0C0C0B51F09DB4C592182B41AD8271587ACC"
+        );
+        assert!(xonly.is_parity_negated());
+        assert_eq!(xonly.tweak_accumulator(), tweak);
+
+        let mut plain = context;
+        plain.apply_tweak(&tweak, false).unwrap();
+        assert_eq!(
+            hex::encode_upper(plain.x_only_public_key()),
+            "7127B997978587213AEBEA116E69FAD619652D1E3E6079C8B5AD491CF606AF06"
+        );
+        assert!(!plain.is_parity_negated());
+    }
+}
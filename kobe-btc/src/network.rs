@@ -1,11 +1,14 @@
 //! Bitcoin network types.
 
+#[cfg(feature = "bitcoin")]
 use bitcoin::Network as BtcNetwork;
 use core::fmt;
 use core::str::FromStr;
+use kobe::slip44::CoinType;
 
 /// Supported Bitcoin networks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[allow(clippy::exhaustive_enums)]
 pub enum Network {
     /// Bitcoin mainnet.
     #[default]
@@ -16,6 +19,7 @@ pub enum Network {
 
 impl Network {
     /// Convert to bitcoin crate's Network type.
+    #[cfg(feature = "bitcoin")]
     #[inline]
     #[must_use]
     pub const fn to_bitcoin_network(self) -> BtcNetwork {
@@ -30,8 +34,8 @@ pub const fn to_bitcoin_network(self) -> BtcNetwork {
     #[must_use]
     pub const fn coin_type(self) -> u32 {
         match self {
-            Self::Mainnet => 0,
-            Self::Testnet => 1,
+            Self::Mainnet => CoinType::Bitcoin.index(),
+            Self::Testnet => CoinType::Testnet.index(),
         }
     }
 
@@ -54,6 +58,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 
 /// Error returned when parsing an invalid network string.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)]
 pub struct ParseNetworkError;
 
 impl fmt::Display for ParseNetworkError {
@@ -69,15 +74,26 @@ impl FromStr for Network {
     type Err = ParseNetworkError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "mainnet" | "main" | "bitcoin" => Ok(Self::Mainnet),
-            "testnet" | "test" | "testnet3" | "testnet4" => Ok(Self::Testnet),
-            _ => Err(ParseNetworkError),
-        }
+        /// Accepted names, matched case-insensitively.
+        const NAMES: [(&str, Network); 7] = [
+            ("mainnet", Network::Mainnet),
+            ("main", Network::Mainnet),
+            ("bitcoin", Network::Mainnet),
+            ("testnet", Network::Testnet),
+            ("test", Network::Testnet),
+            ("testnet3", Network::Testnet),
+            ("testnet4", Network::Testnet),
+        ];
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name))
+            .map(|&(_, network)| network)
+            .ok_or(ParseNetworkError)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,405 @@
+//! Bitcoin public key wrapper.
+//!
+//! Wraps a secp256k1 public key, remembering whether it was given in
+//! compressed form, and provides conversions to and from the 32-byte
+//! x-only form used by Taproot (BIP-340/341).
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use bitcoin::hashes::{Hash, hash160};
+use bitcoin::key::{CompressedPublicKey, Parity, XOnlyPublicKey};
+use bitcoin::secp256k1::{Message, Secp256k1, ecdsa};
+use kobe::secp256k1::is_low_s;
+
+use crate::Error;
+#[cfg(feature = "alloc")]
+use crate::{Address, AddressType, Network};
+
+/// A secp256k1 public key.
+///
+/// Keys are compressed unless parsed from a 65-byte uncompressed encoding
+/// with [`PublicKey::from_bytes`] or converted from an uncompressed
+/// `bitcoin::PublicKey`. The flag matters for [`PublicKey::hash160`] and
+/// thus for P2PKH addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublicKey {
+    /// Underlying public key point.
+    inner: CompressedPublicKey,
+    /// Whether the key is serialized in compressed form.
+    compressed: bool,
+}
+
+impl PublicKey {
+    /// Parse a public key from its 33-byte compressed SEC1 encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid compressed public key.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let inner = CompressedPublicKey::from_slice(bytes).map_err(|_| Error::InvalidPublicKey)?;
+        Ok(Self::from(inner))
+    }
+
+    /// Parse a public key in either SEC1 form, detected from its length and
+    /// prefix: 33 bytes starting `02`/`03`, or 65 bytes starting `04`.
+    ///
+    /// An uncompressed input is remembered as such (see
+    /// [`Self::is_compressed`]), so [`Self::hash160`] and P2PKH addresses
+    /// match the key as it was used on chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPublicKey`] for any other length or prefix,
+    /// including hybrid `06`/`07` keys, or if the point is not on the curve.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes {
+            [0x02 | 0x03, ..] if bytes.len() == 33 => Self::from_slice(bytes),
+            [0x04, ..] if bytes.len() == 65 => bitcoin::secp256k1::PublicKey::from_slice(bytes)
+                .map(|key| Self {
+                    inner: CompressedPublicKey(key),
+                    compressed: false,
+                })
+                .map_err(|_| Error::InvalidPublicKey),
+            _ => Err(Error::InvalidPublicKey),
+        }
+    }
+
+    /// Create a public key from a 32-byte x-only key.
+    ///
+    /// The y-coordinate is assumed to be even, following the BIP-340
+    /// convention for x-only keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not the x-coordinate of a point
+    /// on the curve.
+    pub fn from_x_only(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let x_only = XOnlyPublicKey::from_slice(bytes).map_err(|_| Error::InvalidPublicKey)?;
+        Ok(Self::from(x_only.public_key(Parity::Even)))
+    }
+
+    /// Get the 32-byte x-only form of this key, dropping the parity.
+    #[inline]
+    #[must_use]
+    pub fn to_x_only(&self) -> [u8; 32] {
+        self.inner.0.x_only_public_key().0.serialize()
+    }
+
+    /// Check whether the key is serialized in compressed form.
+    #[inline]
+    #[must_use]
+    pub const fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Get the 33-byte compressed SEC1 encoding, regardless of
+    /// [`Self::is_compressed`].
+    #[inline]
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 33] {
+        self.inner.to_bytes()
+    }
+
+    /// Get `hash160` (RIPEMD-160 of SHA-256) of the key's encoding, as
+    /// committed to by P2PKH outputs.
+    ///
+    /// Uncompressed keys hash their 65-byte encoding. P2WPKH always commits
+    /// to the compressed form, i.e. the hash of [`Self::to_bytes`].
+    #[must_use]
+    pub fn hash160(&self) -> [u8; 20] {
+        if self.compressed {
+            hash160::Hash::hash(&self.to_bytes()).to_byte_array()
+        } else {
+            hash160::Hash::hash(&self.inner.0.serialize_uncompressed()).to_byte_array()
+        }
+    }
+
+    /// Get the big-endian affine `(x, y)` coordinates of the point.
+    #[must_use]
+    pub fn coordinates(&self) -> ([u8; 32], [u8; 32]) {
+        let uncompressed = self.inner.0.serialize_uncompressed();
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x.copy_from_slice(&uncompressed[1..33]);
+        y.copy_from_slice(&uncompressed[33..]);
+        (x, y)
+    }
+
+    /// Get the compressed public key in hex format.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// Get the address of this key for every supported [`AddressType`].
+    ///
+    /// See [`Address::from_public_key`] for how uncompressed keys are
+    /// handled. Entries follow the declaration order of [`AddressType`], which lets
+    /// wallet UIs show all formats of a key side by side.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn all_addresses(&self, network: Network) -> Vec<(AddressType, Address)> {
+        [
+            AddressType::P2pkh,
+            AddressType::P2shP2wpkh,
+            AddressType::P2wpkh,
+            AddressType::P2tr,
+        ]
+        .into_iter()
+        .map(|address_type| {
+            (
+                address_type,
+                Address::from_public_key(self, network, address_type),
+            )
+        })
+        .collect()
+    }
+
+    /// Verify a 64-byte compact (`r || s`) ECDSA signature over a 32-byte
+    /// message hash.
+    ///
+    /// Both low-`s` and high-`s` forms of a signature are accepted; use
+    /// [`Self::verify_strict`] where malleable signatures must be rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSignature`] if the signature is malformed or
+    /// does not verify.
+    pub fn verify(&self, hash: &[u8; 32], signature: &[u8; 64]) -> Result<(), Error> {
+        let mut parsed =
+            ecdsa::Signature::from_compact(signature).map_err(|_| Error::InvalidSignature)?;
+        // libsecp256k1 only accepts low-s signatures.
+        parsed.normalize_s();
+        Secp256k1::verification_only()
+            .verify_ecdsa(&Message::from_digest(*hash), &parsed, &self.inner.0)
+            .map_err(|_| Error::InvalidSignature)
+    }
+
+    /// Verify a signature like [`Self::verify`], additionally requiring a
+    /// canonical low-`s` value (BIP-62/BIP-146).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSignature`] if `s` is above half the curve
+    /// order, even when the signature is otherwise valid.
+    pub fn verify_strict(&self, hash: &[u8; 32], signature: &[u8; 64]) -> Result<(), Error> {
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&signature[32..]);
+        if !is_low_s(&s) {
+            return Err(Error::InvalidSignature);
+        }
+        self.verify(hash, signature)
+    }
+
+    /// Get the underlying `bitcoin` crate public key.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &CompressedPublicKey {
+        &self.inner
+    }
+}
+
+impl From<CompressedPublicKey> for PublicKey {
+    fn from(inner: CompressedPublicKey) -> Self {
+        Self {
+            inner,
+            compressed: true,
+        }
+    }
+}
+
+impl From<PublicKey> for CompressedPublicKey {
+    fn from(key: PublicKey) -> Self {
+        key.inner
+    }
+}
+
+impl From<PublicKey> for bitcoin::PublicKey {
+    fn from(key: PublicKey) -> Self {
+        Self {
+            compressed: key.compressed,
+            inner: key.inner.0,
+        }
+    }
+}
+
+impl From<bitcoin::PublicKey> for PublicKey {
+    fn from(key: bitcoin::PublicKey) -> Self {
+        Self {
+            inner: CompressedPublicKey(key.inner),
+            compressed: key.compressed,
+        }
+    }
+}
+
+impl From<bitcoin::secp256k1::PublicKey> for PublicKey {
+    fn from(key: bitcoin::secp256k1::PublicKey) -> Self {
+        Self::from(CompressedPublicKey(key))
+    }
+}
+
+impl From<PublicKey> for bitcoin::secp256k1::PublicKey {
+    fn from(key: PublicKey) -> Self {
+        key.inner.0
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    /// Compressed public key with an odd y-coordinate (prefix `03`).
+    const ODD_PUBKEY: &str = "03a34b99f22c790c4e36b2b3c2c35a36db06226e41c692fc82b8b56ac1c540c5bd";
+
+    #[test]
+    fn test_x_only_roundtrip() {
+        let pk = PublicKey::from_slice(&hex::decode(ODD_PUBKEY).unwrap()).unwrap();
+        let x_only = pk.to_x_only();
+        let restored = PublicKey::from_x_only(&x_only).unwrap();
+
+        assert_eq!(restored.to_x_only(), x_only);
+        assert_eq!(&pk.to_bytes()[1..], &x_only);
+        // Parity is normalized to even.
+        assert_eq!(restored.to_bytes()[0], 0x02);
+    }
+
+    #[test]
+    fn test_bitcoin_conversions() {
+        let pk = PublicKey::from_slice(&hex::decode(ODD_PUBKEY).unwrap()).unwrap();
+
+        let btc = bitcoin::PublicKey::from(pk);
+        assert!(btc.compressed);
+        assert_eq!(PublicKey::from(btc), pk);
+
+        let secp = bitcoin::secp256k1::PublicKey::from(pk);
+        assert_eq!(PublicKey::from(secp), pk);
+        assert_eq!(CompressedPublicKey::from(pk), *pk.inner());
+
+        let uncompressed = bitcoin::PublicKey::new_uncompressed(secp);
+        assert!(!PublicKey::from(uncompressed).is_compressed());
+        assert_eq!(
+            bitcoin::PublicKey::from(PublicKey::from(uncompressed)),
+            uncompressed
+        );
+    }
+
+    #[test]
+    fn test_verify_strict_rejects_high_s() {
+        use k256::Scalar;
+        use k256::elliptic_curve::PrimeField;
+
+        let secp = Secp256k1::new();
+        let secret = bitcoin::secp256k1::SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let pk = PublicKey::from(secret.public_key(&secp));
+        let hash = [0x07; 32];
+        let low = secp
+            .sign_ecdsa(&Message::from_digest(hash), &secret)
+            .serialize_compact();
+
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&low[32..]);
+        let negated = -Option::<Scalar>::from(Scalar::from_repr(s.into())).unwrap();
+        let mut high = low;
+        high[32..].copy_from_slice(&negated.to_repr());
+
+        assert!(pk.verify(&hash, &low).is_ok());
+        assert!(pk.verify_strict(&hash, &low).is_ok());
+        assert!(pk.verify(&hash, &high).is_ok());
+        assert!(matches!(
+            pk.verify_strict(&hash, &high),
+            Err(Error::InvalidSignature)
+        ));
+        assert!(pk.verify(&[0x08; 32], &low).is_err());
+    }
+
+    #[test]
+    fn test_from_x_only_invalid() {
+        assert!(PublicKey::from_x_only(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_detects_format() {
+        let pk = PublicKey::from_slice(&hex::decode(ODD_PUBKEY).unwrap()).unwrap();
+        let uncompressed = pk.inner().0.serialize_uncompressed();
+
+        assert_eq!(PublicKey::from_bytes(&pk.to_bytes()).unwrap(), pk);
+        let parsed = PublicKey::from_bytes(&uncompressed).unwrap();
+        assert!(!parsed.is_compressed());
+        assert_eq!(parsed.to_bytes(), pk.to_bytes());
+
+        // Prefix-less coordinates, a hybrid key and mismatched prefixes.
+        assert!(PublicKey::from_bytes(&uncompressed[1..]).is_err());
+        let mut hybrid = uncompressed;
+        hybrid[0] = 0x07;
+        assert!(PublicKey::from_bytes(&hybrid).is_err());
+        let mut long_compressed = uncompressed;
+        long_compressed[0] = 0x03;
+        assert!(PublicKey::from_bytes(&long_compressed).is_err());
+        assert!(PublicKey::from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let pk = PublicKey::from_slice(&hex::decode(ODD_PUBKEY).unwrap()).unwrap();
+        let uncompressed = pk.inner().0.serialize_uncompressed();
+        let (x, y) = pk.coordinates();
+        assert_eq!(x, uncompressed[1..33]);
+        assert_eq!(y, uncompressed[33..]);
+        assert_eq!(x, pk.to_x_only());
+        // Odd prefix, odd y.
+        assert_eq!(y[31] & 1, 1);
+    }
+
+    #[test]
+    fn test_all_addresses() {
+        let pk = PublicKey::from_slice(&hex::decode(ODD_PUBKEY).unwrap()).unwrap();
+        let addresses = pk.all_addresses(Network::Mainnet);
+        assert_eq!(addresses.len(), 4);
+        for (address_type, address) in &addresses {
+            assert_eq!(
+                *address,
+                Address::from_public_key(&pk, Network::Mainnet, *address_type)
+            );
+        }
+        let mut strings: Vec<String> = addresses.iter().map(|(_, a)| a.to_string()).collect();
+        strings.sort();
+        strings.dedup();
+        assert_eq!(strings.len(), 4);
+    }
+
+    #[test]
+    fn test_uncompressed_p2pkh_address() {
+        // Public key of secret key 1 (the generator point).
+        let uncompressed = hex::decode(concat!(
+            "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        ))
+        .unwrap();
+        let pk = PublicKey::from_bytes(&uncompressed).unwrap();
+        let compressed = PublicKey::from_slice(&pk.to_bytes()).unwrap();
+
+        assert_eq!(
+            Address::from_public_key(&pk, Network::Mainnet, AddressType::P2pkh).to_string(),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+        assert_eq!(
+            Address::from_public_key(&compressed, Network::Mainnet, AddressType::P2pkh).to_string(),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        // SegWit only allows the compressed key.
+        assert_eq!(
+            Address::from_public_key(&pk, Network::Mainnet, AddressType::P2wpkh),
+            Address::from_public_key(&compressed, Network::Mainnet, AddressType::P2wpkh)
+        );
+        assert_eq!(
+            pk.all_addresses(Network::Mainnet)[0].1.to_string(),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! Pure-Rust Bitcoin key and address backend.
+//!
+//! Builds addresses directly on `k256`, `sha2`, `ripemd` and `bech32`,
+//! without the `bitcoin` crate and its libsecp256k1 C bindings. This gives
+//! embedded and WASM users an all-Rust build, and produces the same
+//! addresses as the default backend.
+//!
+//! # Example
+//!
+//! ```
+//! use kobe_btc::{AddressType, Network, pure};
+//!
+//! let mut secret = [0u8; 32];
+//! secret[31] = 1;
+//! let address = pure::address_from_secret_key(&secret, Network::Mainnet, AddressType::P2wpkh)?;
+//! assert_eq!(address, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+//! # Ok::<(), kobe_btc::Error>(())
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use bech32::{Fe32, Hrp, hrp, segwit};
+use bip32::{DerivationPath, XPrv};
+use k256::elliptic_curve::PrimeField;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{ProjectivePoint, PublicKey, Scalar, SecretKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::{AddressType, Error, Network};
+
+/// Create an address from a SEC1-encoded public key.
+///
+/// As in the default backend, a P2PKH address commits to the key in the
+/// encoding it was given, so a 65-byte uncompressed key gets its
+/// uncompressed-key address. SegWit and Taproot always use the compressed
+/// form.
+///
+/// # Errors
+///
+/// Returns an error if the public key is invalid.
+pub fn address_from_public_key(
+    public_key: &[u8],
+    network: Network,
+    address_type: AddressType,
+) -> Result<String, Error> {
+    let key = PublicKey::from_sec1_bytes(public_key).map_err(|_| Error::InvalidPublicKey)?;
+    let compressed = public_key.len() == 33;
+    address_for_key(&key, compressed, network, address_type)
+}
+
+/// Create an address from a raw 32-byte secret key.
+///
+/// # Errors
+///
+/// Returns an error if the secret key is invalid.
+pub fn address_from_secret_key(
+    secret_key: &[u8; 32],
+    network: Network,
+    address_type: AddressType,
+) -> Result<String, Error> {
+    let key = SecretKey::from_slice(secret_key).map_err(|_| Error::InvalidPrivateKey)?;
+    address_for_key(&key.public_key(), true, network, address_type)
+}
+
+/// Derive the address at a BIP-32 `path` from a wallet seed.
+///
+/// # Errors
+///
+/// Returns an error if the path is invalid or derivation fails.
+pub fn derive_address(
+    seed: &[u8],
+    path: &str,
+    network: Network,
+    address_type: AddressType,
+) -> Result<String, Error> {
+    let derivation_path: DerivationPath = path
+        .parse()
+        .map_err(|_| Error::InvalidDerivationPath(path.to_string()))?;
+    let xprv = XPrv::derive_from_path(seed, &derivation_path)
+        .map_err(|_| Error::InvalidDerivationPath(path.to_string()))?;
+    address_for_key(
+        &PublicKey::from(xprv.private_key().verifying_key()),
+        true,
+        network,
+        address_type,
+    )
+}
+
+/// Encode a secret key in WIF format for a compressed public key (zeroized on drop).
+#[must_use]
+pub fn secret_to_wif(secret_key: &[u8; 32], network: Network) -> Zeroizing<String> {
+    let version = match network {
+        Network::Mainnet => 0x80,
+        Network::Testnet => 0xef,
+    };
+    let mut payload = Zeroizing::new(Vec::with_capacity(34));
+    payload.push(version);
+    payload.extend_from_slice(secret_key);
+    payload.push(0x01);
+    Zeroizing::new(base58check(&payload))
+}
+
+/// Build the address of `address_type` for a public key.
+///
+/// `compressed` selects the encoding a P2PKH address commits to.
+fn address_for_key(
+    key: &PublicKey,
+    compressed: bool,
+    network: Network,
+    address_type: AddressType,
+) -> Result<String, Error> {
+    let pubkey_hash = hash160(key.to_encoded_point(true).as_bytes());
+
+    let (p2pkh_version, p2sh_version, segwit_hrp) = match network {
+        Network::Mainnet => (0x00, 0x05, hrp::BC),
+        Network::Testnet => (0x6f, 0xc4, hrp::TB),
+    };
+
+    match address_type {
+        AddressType::P2pkh => {
+            let hash = if compressed {
+                pubkey_hash
+            } else {
+                hash160(key.to_encoded_point(false).as_bytes())
+            };
+            Ok(base58check(&versioned(p2pkh_version, &hash)))
+        }
+        AddressType::P2shP2wpkh => {
+            let mut redeem_script = [0u8; 22];
+            redeem_script[0] = 0x00;
+            redeem_script[1] = 0x14;
+            redeem_script[2..].copy_from_slice(&pubkey_hash);
+            let script_hash = hash160(&redeem_script);
+            Ok(base58check(&versioned(p2sh_version, &script_hash)))
+        }
+        AddressType::P2wpkh => segwit_address(segwit_hrp, segwit::VERSION_0, &pubkey_hash),
+        AddressType::P2tr => {
+            let output_key = taproot_output_key(key)?;
+            segwit_address(segwit_hrp, segwit::VERSION_1, &output_key)
+        }
+    }
+}
+
+/// Compute the BIP-86 Taproot output key (key-path only, no script tree).
+fn taproot_output_key(key: &PublicKey) -> Result<[u8; 32], Error> {
+    let point = key.to_projective();
+    let is_odd = key.to_encoded_point(true).as_bytes()[0] == 0x03;
+    let internal = if is_odd { -point } else { point };
+    let internal_x: [u8; 32] = internal.to_affine().x().into();
+
+    let tag = Sha256::digest(b"TapTweak");
+    let tweak_hash: [u8; 32] = Sha256::new()
+        .chain_update(tag)
+        .chain_update(tag)
+        .chain_update(internal_x)
+        .finalize()
+        .into();
+    let tweak = Option::<Scalar>::from(Scalar::from_repr(tweak_hash.into()))
+        .ok_or(Error::InvalidPublicKey)?;
+
+    let output = internal + ProjectivePoint::GENERATOR * tweak;
+    Ok(output.to_affine().x().into())
+}
+
+/// Encode a segwit address.
+fn segwit_address(hrp: Hrp, version: Fe32, program: &[u8]) -> Result<String, Error> {
+    segwit::encode(hrp, version, program).map_err(|_| Error::InvalidPublicKey)
+}
+
+/// Prefix a 20-byte hash with a version byte.
+fn versioned(version: u8, hash: &[u8; 20]) -> [u8; 21] {
+    let mut out = [0u8; 21];
+    out[0] = version;
+    out[1..].copy_from_slice(hash);
+    out
+}
+
+/// Compute `RIPEMD160(SHA256(data))`.
+fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// Encode `payload` with a 4-byte double-SHA256 checksum in Base58.
+fn base58check(payload: &[u8]) -> String {
+    let checksum = Sha256::digest(Sha256::digest(payload));
+    let mut data = Zeroizing::new(Vec::with_capacity(payload.len() + 4));
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&checksum[..4]);
+    bs58::encode(data.as_slice()).into_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use kobe::Wallet;
+    use kobe::testing::{BIP44_BTC_VECTOR, BIP49_BTC_VECTOR, BIP84_BTC_VECTOR, TEST_MNEMONIC};
+
+    #[test]
+    fn test_bip44_bip49_bip84_vectors() {
+        for (vector, address_type) in [
+            (BIP44_BTC_VECTOR, AddressType::P2pkh),
+            (BIP49_BTC_VECTOR, AddressType::P2shP2wpkh),
+            (BIP84_BTC_VECTOR, AddressType::P2wpkh),
+        ] {
+            let wallet = Wallet::from_mnemonic(vector.mnemonic, None).unwrap();
+            let address =
+                derive_address(wallet.seed(), vector.path, Network::Mainnet, address_type).unwrap();
+            assert_eq!(address, vector.address);
+        }
+    }
+
+    // BIP-86 test vector.
+    #[test]
+    fn test_bip86_vector() {
+        let wallet = Wallet::from_mnemonic(TEST_MNEMONIC, None).unwrap();
+        let address = derive_address(
+            wallet.seed(),
+            "m/86'/0'/0'/0/0",
+            Network::Mainnet,
+            AddressType::P2tr,
+        )
+        .unwrap();
+        assert_eq!(
+            address,
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+    }
+
+    #[cfg(feature = "bitcoin")]
+    #[test]
+    fn test_matches_bitcoin_backend() {
+        use crate::StandardWallet;
+
+        for seed in 1u8..=8 {
+            let secret = [seed; 32];
+            for network in [Network::Mainnet, Network::Testnet] {
+                for address_type in [
+                    AddressType::P2pkh,
+                    AddressType::P2shP2wpkh,
+                    AddressType::P2wpkh,
+                    AddressType::P2tr,
+                ] {
+                    let wallet =
+                        StandardWallet::from_hex(&hex::encode(secret), network, address_type)
+                            .unwrap();
+                    let pure = address_from_secret_key(&secret, network, address_type).unwrap();
+                    assert_eq!(pure, wallet.address(), "{network} {address_type}");
+                    assert_eq!(*secret_to_wif(&secret, network), *wallet.to_wif());
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "bitcoin")]
+    #[test]
+    fn test_uncompressed_key_matches_bitcoin_backend() {
+        use crate::{Address, PublicKey as BtcPublicKey};
+
+        for seed in 1u8..=8 {
+            let secret = SecretKey::from_slice(&[seed; 32]).unwrap();
+            let uncompressed = secret.public_key().to_encoded_point(false);
+            let key = BtcPublicKey::from_bytes(uncompressed.as_bytes()).unwrap();
+            assert!(!key.is_compressed());
+            for network in [Network::Mainnet, Network::Testnet] {
+                for address_type in [
+                    AddressType::P2pkh,
+                    AddressType::P2shP2wpkh,
+                    AddressType::P2wpkh,
+                    AddressType::P2tr,
+                ] {
+                    let pure =
+                        address_from_public_key(uncompressed.as_bytes(), network, address_type)
+                            .unwrap();
+                    let expected = Address::from_public_key(&key, network, address_type);
+                    assert_eq!(pure, expected.to_string(), "{network} {address_type}");
+                }
+            }
+        }
+
+        // Generator point: the well-known uncompressed-key address.
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let generator = SecretKey::from_slice(&one).unwrap().public_key();
+        assert_eq!(
+            address_from_public_key(
+                generator.to_encoded_point(false).as_bytes(),
+                Network::Mainnet,
+                AddressType::P2pkh
+            )
+            .unwrap(),
+            "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"
+        );
+    }
+}
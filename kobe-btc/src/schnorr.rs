@@ -0,0 +1,205 @@
+//! BIP-340 Schnorr signature verification.
+//!
+//! Provides single and batch verification of Schnorr signatures over
+//! x-only public keys. Batch verification checks all signatures at once
+//! using a random linear combination, as described in BIP-340.
+
+use bitcoin::hashes::{Hash, HashEngine, sha256};
+use bitcoin::secp256k1::{Message, Secp256k1, XOnlyPublicKey, schnorr};
+use k256::elliptic_curve::PrimeField;
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::point::DecompressPoint;
+use k256::elliptic_curve::subtle::Choice;
+use k256::{AffinePoint, FieldBytes, ProjectivePoint, Scalar, U256};
+
+use crate::{Error, PublicKey};
+
+/// A Schnorr verification item: public key, 32-byte message and 64-byte signature.
+pub type SchnorrItem = (PublicKey, [u8; 32], [u8; 64]);
+
+/// Verify a single BIP-340 Schnorr signature.
+///
+/// The public key is used in its x-only form.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSignature`] if the signature does not verify.
+pub fn verify_schnorr(
+    public_key: &PublicKey,
+    message: &[u8; 32],
+    signature: &[u8; 64],
+) -> Result<(), Error> {
+    let x_only =
+        XOnlyPublicKey::from_slice(&public_key.to_x_only()).map_err(|_| Error::InvalidPublicKey)?;
+    let sig = schnorr::Signature::from_slice(signature).map_err(|_| Error::InvalidSignature)?;
+    let msg = Message::from_digest(*message);
+
+    Secp256k1::verification_only()
+        .verify_schnorr(&sig, &msg, &x_only)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+/// Verify each Schnorr signature individually.
+///
+/// This is the non-batch fallback for [`batch_verify_schnorr`]. It is slower
+/// for large inputs but stops at the first invalid signature.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSignature`] if any signature does not verify.
+pub fn verify_schnorr_each(items: &[SchnorrItem]) -> Result<(), Error> {
+    items
+        .iter()
+        .try_for_each(|(public_key, message, signature)| {
+            verify_schnorr(public_key, message, signature)
+        })
+}
+
+/// Verify a batch of BIP-340 Schnorr signatures at once.
+///
+/// Checks the BIP-340 batch equation
+/// `(Σ aᵢ·sᵢ)·G = Σ aᵢ·Rᵢ + Σ (aᵢ·eᵢ)·Pᵢ`, where `a₀ = 1` and the remaining
+/// coefficients are derived by hashing the whole batch. The batch passes only
+/// if every signature is valid; it does not identify which one failed.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSignature`] if any signature does not verify.
+pub fn batch_verify_schnorr(items: &[SchnorrItem]) -> Result<(), Error> {
+    let seed = batch_seed(items);
+
+    let mut s_sum = Scalar::ZERO;
+    let mut rhs = ProjectivePoint::IDENTITY;
+
+    for (i, (public_key, message, signature)) in items.iter().enumerate() {
+        let px = public_key.to_x_only();
+        let mut r_bytes = [0u8; 32];
+        let mut s_bytes = [0u8; 32];
+        r_bytes.copy_from_slice(&signature[..32]);
+        s_bytes.copy_from_slice(&signature[32..]);
+
+        let point_p = lift_x(&px).ok_or(Error::InvalidPublicKey)?;
+        let point_r = lift_x(&r_bytes).ok_or(Error::InvalidSignature)?;
+        let s = Option::<Scalar>::from(Scalar::from_repr(s_bytes.into()))
+            .ok_or(Error::InvalidSignature)?;
+
+        let e = tagged_scalar(b"BIP0340/challenge", &[&r_bytes, &px, message]);
+        let a = if i == 0 {
+            Scalar::ONE
+        } else {
+            let index = u32::try_from(i).map_err(|_| Error::InvalidSignature)?;
+            tagged_scalar(b"BIP0340/batch", &[&seed, &index.to_be_bytes()])
+        };
+
+        s_sum += a * s;
+        rhs += point_r * a + point_p * (a * e);
+    }
+
+    if ProjectivePoint::GENERATOR * s_sum == rhs {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Hash the full batch to seed the linear-combination coefficients.
+fn batch_seed(items: &[SchnorrItem]) -> [u8; 32] {
+    let mut engine = sha256::Hash::engine();
+    for (public_key, message, signature) in items {
+        engine.input(&public_key.to_x_only());
+        engine.input(message);
+        engine.input(signature);
+    }
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+/// Compute a BIP-340 tagged hash over `parts`.
+pub fn tagged_hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = sha256::Hash::hash(tag);
+    let mut engine = sha256::Hash::engine();
+    engine.input(tag_hash.as_byte_array());
+    engine.input(tag_hash.as_byte_array());
+    for part in parts {
+        engine.input(part);
+    }
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+/// Compute a BIP-340 tagged hash over `parts` and reduce it modulo the curve order.
+pub fn tagged_scalar(tag: &[u8], parts: &[&[u8]]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(&tagged_hash(tag, parts).into())
+}
+
+/// Lift an x-coordinate to the curve point with even y, per BIP-340.
+fn lift_x(x: &[u8; 32]) -> Option<ProjectivePoint> {
+    let point = AffinePoint::decompress(&FieldBytes::from(*x), Choice::from(0));
+    Option::<AffinePoint>::from(point)
+        .filter(|p| !bool::from(p.is_identity()))
+        .map(ProjectivePoint::from)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use bitcoin::secp256k1::Keypair;
+
+    /// Build `count` deterministic signed items.
+    fn signed_items(count: u8) -> Vec<SchnorrItem> {
+        let secp = Secp256k1::new();
+        (1..=count)
+            .map(|i| {
+                let keypair = Keypair::from_seckey_slice(&secp, &[i; 32]).unwrap();
+                let message = [i.wrapping_mul(7); 32];
+                let sig = secp.sign_schnorr_no_aux_rand(&Message::from_digest(message), &keypair);
+                let public_key =
+                    PublicKey::from(bitcoin::key::CompressedPublicKey(keypair.public_key()));
+                (public_key, message, sig.serialize())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_valid() {
+        let items = signed_items(10);
+        assert!(batch_verify_schnorr(&items).is_ok());
+        assert!(verify_schnorr_each(&items).is_ok());
+    }
+
+    #[test]
+    fn test_batch_flipped_bit() {
+        let mut items = signed_items(11);
+        items[10].2[40] ^= 0x01;
+        assert!(batch_verify_schnorr(&items).is_err());
+        assert!(verify_schnorr_each(&items).is_err());
+    }
+
+    #[test]
+    fn test_batch_empty() {
+        assert!(batch_verify_schnorr(&[]).is_ok());
+    }
+
+    #[test]
+    fn test_bip340_vector() {
+        // BIP-340 test vector 1.
+        let pk = hex::decode("02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659")
+            .unwrap();
+        let msg: [u8; 32] =
+            hex::decode("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let sig: [u8; 64] = hex::decode(
+            "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        let item = (PublicKey::from_slice(&pk).unwrap(), msg, sig);
+
+        assert!(verify_schnorr(&item.0, &item.1, &item.2).is_ok());
+        assert!(batch_verify_schnorr(&[item]).is_ok());
+    }
+}
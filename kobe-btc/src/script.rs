@@ -0,0 +1,403 @@
+//! Bitcoin script builders for time-locked spending paths.
+//!
+//! [`cltv`] and [`csv`] prefix a redeem script with an absolute
+//! (`OP_CHECKLOCKTIMEVERIFY`, BIP-65) or relative (`OP_CHECKSEQUENCEVERIFY`,
+//! BIP-112) timelock check. [`to_asm`] disassembles a script for debugging.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::Error;
+
+/// `OP_0` opcode.
+pub const OP_0: u8 = 0x00;
+/// `OP_1` opcode (`OP_1`..`OP_16` follow consecutively).
+pub const OP_1: u8 = 0x51;
+/// `OP_PUSHDATA1` opcode.
+pub const OP_PUSHDATA1: u8 = 0x4c;
+/// `OP_PUSHDATA2` opcode.
+pub const OP_PUSHDATA2: u8 = 0x4d;
+/// `OP_PUSHDATA4` opcode.
+pub const OP_PUSHDATA4: u8 = 0x4e;
+/// `OP_DROP` opcode.
+pub const OP_DROP: u8 = 0x75;
+/// `OP_CHECKLOCKTIMEVERIFY` opcode.
+pub const OP_CHECKLOCKTIMEVERIFY: u8 = 0xb1;
+/// `OP_CHECKSEQUENCEVERIFY` opcode.
+pub const OP_CHECKSEQUENCEVERIFY: u8 = 0xb2;
+
+/// BIP-68 flag selecting time-based (512-second units) relative locks.
+pub const SEQUENCE_LOCKTIME_TYPE_FLAG: u32 = 1 << 22;
+/// BIP-68 mask for the relative lock value.
+pub const SEQUENCE_LOCKTIME_MASK: u32 = 0x0000_ffff;
+
+/// Build `<locktime> OP_CHECKLOCKTIMEVERIFY OP_DROP <redeem_script>`.
+///
+/// `locktime` is a block height below 500 000 000 or a Unix timestamp at or
+/// above it, as in `nLockTime`.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidTimelock`] if `locktime` is zero, which any
+/// transaction would satisfy.
+pub fn cltv(locktime: u32, redeem_script: &[u8]) -> Result<Vec<u8>, Error> {
+    if locktime == 0 {
+        return Err(Error::InvalidTimelock(locktime));
+    }
+    Ok(timelocked(locktime, OP_CHECKLOCKTIMEVERIFY, redeem_script))
+}
+
+/// Build `<sequence> OP_CHECKSEQUENCEVERIFY OP_DROP <redeem_script>`.
+///
+/// `sequence` is a BIP-68 relative lock: a block count, or a count of
+/// 512-second units when [`SEQUENCE_LOCKTIME_TYPE_FLAG`] is set.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidTimelock`] if `sequence` is zero or sets bits
+/// outside [`SEQUENCE_LOCKTIME_TYPE_FLAG`] and [`SEQUENCE_LOCKTIME_MASK`]
+/// (including the disable flag, which would make the check a no-op).
+pub fn csv(sequence: u32, redeem_script: &[u8]) -> Result<Vec<u8>, Error> {
+    if sequence & SEQUENCE_LOCKTIME_MASK == 0
+        || sequence & !(SEQUENCE_LOCKTIME_TYPE_FLAG | SEQUENCE_LOCKTIME_MASK) != 0
+    {
+        return Err(Error::InvalidTimelock(sequence));
+    }
+    Ok(timelocked(sequence, OP_CHECKSEQUENCEVERIFY, redeem_script))
+}
+
+/// Append a minimally-encoded push of `value` to `script`.
+pub fn push_int(script: &mut Vec<u8>, value: u32) {
+    match value {
+        0 => script.push(OP_0),
+        // `value` is at most 16 here.
+        #[allow(clippy::cast_possible_truncation)]
+        1..=16 => script.push(OP_1 + (value - 1) as u8),
+        _ => {
+            let mut bytes = Vec::with_capacity(5);
+            bytes.extend(value.to_le_bytes().iter().rev().skip_while(|&&b| b == 0));
+            bytes.reverse();
+            // Script numbers are signed; add a zero byte if the sign bit is set.
+            if bytes.last().is_some_and(|&b| b & 0x80 != 0) {
+                bytes.push(0x00);
+            }
+            // At most 5 bytes, always a direct push.
+            #[allow(clippy::cast_possible_truncation)]
+            script.push(bytes.len() as u8);
+            script.extend_from_slice(&bytes);
+        }
+    }
+}
+
+/// Disassemble a script into ASM the way Bitcoin Core's `ScriptToAsmStr`
+/// does, e.g. `OP_DUP OP_HASH160 <hex> OP_EQUALVERIFY OP_CHECKSIG`.
+///
+/// Small-integer opcodes print as numbers (`0`, `-1`, `1` to `16`). Pushes
+/// of up to 4 bytes print as a decimal script number and longer pushes as
+/// lowercase hex; signatures are not decoded into `[ALL]`-style suffixes.
+/// A push that runs past the end of the script ends the output with
+/// `[error]`.
+#[must_use]
+pub fn to_asm(script: &[u8]) -> String {
+    let mut out = String::new();
+    let mut rest = script;
+    while let Some((&opcode, tail)) = rest.split_first() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        let (len_size, data_len): (usize, _) = match opcode {
+            0x00..=0x4b => (0, Some(usize::from(opcode))),
+            OP_PUSHDATA1 => (1, tail.first().map(|&b| usize::from(b))),
+            OP_PUSHDATA2 => (
+                2,
+                tail.first_chunk()
+                    .map(|b| usize::from(u16::from_le_bytes(*b))),
+            ),
+            OP_PUSHDATA4 => (
+                4,
+                tail.first_chunk()
+                    .and_then(|b| usize::try_from(u32::from_le_bytes(*b)).ok()),
+            ),
+            _ => {
+                out.push_str(opcode_name(opcode));
+                rest = tail;
+                continue;
+            }
+        };
+        let Some(data) = data_len.and_then(|len| tail.get(len_size..len_size.checked_add(len)?))
+        else {
+            out.push_str("[error]");
+            break;
+        };
+        if data.len() <= 4 {
+            let _ = write!(out, "{}", script_num(data));
+        } else {
+            out.push_str(&hex::encode(data));
+        }
+        rest = &tail[len_size + data.len()..];
+    }
+    out
+}
+
+/// Decode a push of at most 4 bytes as a script number, without requiring
+/// minimal encoding (little-endian magnitude, sign in the top bit).
+fn script_num(data: &[u8]) -> i64 {
+    let magnitude = data
+        .iter()
+        .rev()
+        .fold(0_i64, |acc, &byte| (acc << 8) | i64::from(byte));
+    match data.last() {
+        Some(&last) if last & 0x80 != 0 => {
+            let sign_bit = 0x80_i64 << (8 * (data.len() - 1));
+            -(magnitude & !sign_bit)
+        }
+        _ => magnitude,
+    }
+}
+
+/// Get the Bitcoin Core name of a non-push opcode.
+const fn opcode_name(opcode: u8) -> &'static str {
+    match opcode {
+        0x4f => "-1",
+        0x50 => "OP_RESERVED",
+        0x51 => "1",
+        0x52 => "2",
+        0x53 => "3",
+        0x54 => "4",
+        0x55 => "5",
+        0x56 => "6",
+        0x57 => "7",
+        0x58 => "8",
+        0x59 => "9",
+        0x5a => "10",
+        0x5b => "11",
+        0x5c => "12",
+        0x5d => "13",
+        0x5e => "14",
+        0x5f => "15",
+        0x60 => "16",
+        0x61 => "OP_NOP",
+        0x62 => "OP_VER",
+        0x63 => "OP_IF",
+        0x64 => "OP_NOTIF",
+        0x65 => "OP_VERIF",
+        0x66 => "OP_VERNOTIF",
+        0x67 => "OP_ELSE",
+        0x68 => "OP_ENDIF",
+        0x69 => "OP_VERIFY",
+        0x6a => "OP_RETURN",
+        0x6b => "OP_TOALTSTACK",
+        0x6c => "OP_FROMALTSTACK",
+        0x6d => "OP_2DROP",
+        0x6e => "OP_2DUP",
+        0x6f => "OP_3DUP",
+        0x70 => "OP_2OVER",
+        0x71 => "OP_2ROT",
+        0x72 => "OP_2SWAP",
+        0x73 => "OP_IFDUP",
+        0x74 => "OP_DEPTH",
+        0x75 => "OP_DROP",
+        0x76 => "OP_DUP",
+        0x77 => "OP_NIP",
+        0x78 => "OP_OVER",
+        0x79 => "OP_PICK",
+        0x7a => "OP_ROLL",
+        0x7b => "OP_ROT",
+        0x7c => "OP_SWAP",
+        0x7d => "OP_TUCK",
+        0x7e => "OP_CAT",
+        0x7f => "OP_SUBSTR",
+        0x80 => "OP_LEFT",
+        0x81 => "OP_RIGHT",
+        0x82 => "OP_SIZE",
+        0x83 => "OP_INVERT",
+        0x84 => "OP_AND",
+        0x85 => "OP_OR",
+        0x86 => "OP_XOR",
+        0x87 => "OP_EQUAL",
+        0x88 => "OP_EQUALVERIFY",
+        0x89 => "OP_RESERVED1",
+        0x8a => "OP_RESERVED2",
+        0x8b => "OP_1ADD",
+        0x8c => "OP_1SUB",
+        0x8d => "OP_2MUL",
+        0x8e => "OP_2DIV",
+        0x8f => "OP_NEGATE",
+        0x90 => "OP_ABS",
+        0x91 => "OP_NOT",
+        0x92 => "OP_0NOTEQUAL",
+        0x93 => "OP_ADD",
+        0x94 => "OP_SUB",
+        0x95 => "OP_MUL",
+        0x96 => "OP_DIV",
+        0x97 => "OP_MOD",
+        0x98 => "OP_LSHIFT",
+        0x99 => "OP_RSHIFT",
+        0x9a => "OP_BOOLAND",
+        0x9b => "OP_BOOLOR",
+        0x9c => "OP_NUMEQUAL",
+        0x9d => "OP_NUMEQUALVERIFY",
+        0x9e => "OP_NUMNOTEQUAL",
+        0x9f => "OP_LESSTHAN",
+        0xa0 => "OP_GREATERTHAN",
+        0xa1 => "OP_LESSTHANOREQUAL",
+        0xa2 => "OP_GREATERTHANOREQUAL",
+        0xa3 => "OP_MIN",
+        0xa4 => "OP_MAX",
+        0xa5 => "OP_WITHIN",
+        0xa6 => "OP_RIPEMD160",
+        0xa7 => "OP_SHA1",
+        0xa8 => "OP_SHA256",
+        0xa9 => "OP_HASH160",
+        0xaa => "OP_HASH256",
+        0xab => "OP_CODESEPARATOR",
+        0xac => "OP_CHECKSIG",
+        0xad => "OP_CHECKSIGVERIFY",
+        0xae => "OP_CHECKMULTISIG",
+        0xaf => "OP_CHECKMULTISIGVERIFY",
+        0xb0 => "OP_NOP1",
+        OP_CHECKLOCKTIMEVERIFY => "OP_CHECKLOCKTIMEVERIFY",
+        OP_CHECKSEQUENCEVERIFY => "OP_CHECKSEQUENCEVERIFY",
+        0xb3 => "OP_NOP4",
+        0xb4 => "OP_NOP5",
+        0xb5 => "OP_NOP6",
+        0xb6 => "OP_NOP7",
+        0xb7 => "OP_NOP8",
+        0xb8 => "OP_NOP9",
+        0xb9 => "OP_NOP10",
+        0xba => "OP_CHECKSIGADD",
+        0xff => "OP_INVALIDOPCODE",
+        _ => "OP_UNKNOWN",
+    }
+}
+
+/// Build `<value> <opcode> OP_DROP <redeem_script>`.
+fn timelocked(value: u32, opcode: u8, redeem_script: &[u8]) -> Vec<u8> {
+    let mut script = Vec::with_capacity(8 + redeem_script.len());
+    push_int(&mut script, value);
+    script.push(opcode);
+    script.push(OP_DROP);
+    script.extend_from_slice(redeem_script);
+    script
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    /// `OP_DUP OP_HASH160 <20 bytes> OP_EQUALVERIFY OP_CHECKSIG`, the
+    /// P2WPKH script code.
+    fn pubkey_hash_script() -> Vec<u8> {
+        let mut script = vec![0x76, 0xa9, 0x14];
+        script.extend_from_slice(&[0xab; 20]);
+        script.extend_from_slice(&[0x88, 0xac]);
+        script
+    }
+
+    #[test]
+    fn test_csv_one_year() {
+        // 365 days in 512-second units, rounded up.
+        let units = (365 * 24 * 60 * 60_u32).div_ceil(512);
+        assert_eq!(units, 61_594);
+        let redeem = pubkey_hash_script();
+        let script = csv(SEQUENCE_LOCKTIME_TYPE_FLAG | units, &redeem).unwrap();
+        assert_eq!(&script[..6], &[0x03, 0x9a, 0xf0, 0x40, 0xb2, 0x75]);
+        assert_eq!(&script[6..], redeem.as_slice());
+    }
+
+    #[test]
+    fn test_cltv_block_height() {
+        let script = cltv(800_000, &[0xac]).unwrap();
+        // 800000 = 0x0c3500, little-endian.
+        assert_eq!(script, [0x03, 0x00, 0x35, 0x0c, 0xb1, 0x75, 0xac]);
+    }
+
+    #[test]
+    fn test_to_asm_p2pkh() {
+        let script = hex::decode("76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac").unwrap();
+        assert_eq!(
+            to_asm(&script),
+            "OP_DUP OP_HASH160 62e907b15cbf27d5425399ebf6f0fb50ebb88f18 OP_EQUALVERIFY OP_CHECKSIG"
+        );
+    }
+
+    #[test]
+    fn test_to_asm_p2wpkh() {
+        let script = hex::decode("0014751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(
+            to_asm(&script),
+            "0 751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+    }
+
+    #[test]
+    fn test_to_asm_pushdata_and_errors() {
+        let cltv_script = cltv(800_000, &[0xac]).unwrap();
+        assert_eq!(
+            to_asm(&cltv_script),
+            "800000 OP_CHECKLOCKTIMEVERIFY OP_DROP OP_CHECKSIG"
+        );
+        assert_eq!(to_asm(&[OP_PUSHDATA1, 0x02, 0xab, 0xcd]), "-19883");
+        assert_eq!(
+            to_asm(&[OP_PUSHDATA1, 0x05, 0xab, 0xcd, 0xef, 0x01, 0x23]),
+            "abcdef0123"
+        );
+        assert_eq!(to_asm(&[0x6a, 0x05, 0x01]), "OP_RETURN [error]");
+        assert_eq!(
+            to_asm(&[OP_PUSHDATA4, 0xff, 0xff, 0xff, 0xff, 0x00]),
+            "[error]"
+        );
+        assert_eq!(to_asm(&[0xff]), "OP_INVALIDOPCODE");
+        assert_eq!(to_asm(&[0xfe]), "OP_UNKNOWN");
+        assert_eq!(to_asm(&[]), "");
+    }
+
+    #[test]
+    fn test_to_asm_small_ints() {
+        assert_eq!(to_asm(&[OP_0, 0x4f, OP_1, 0x60]), "0 -1 1 16");
+        assert_eq!(
+            to_asm(&[0x01, 0x81, 0x01, 0x80, 0x02, 0xff, 0x7f]),
+            "-1 0 32767"
+        );
+    }
+
+    #[test]
+    fn test_to_asm_matches_core_genesis_coinbase() {
+        // `scriptSig.asm` of the genesis coinbase as printed by Bitcoin Core.
+        let script = hex::decode(
+            "04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73",
+        )
+        .unwrap();
+        assert_eq!(
+            to_asm(&script),
+            "486604799 4 5468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73"
+        );
+    }
+
+    #[test]
+    fn test_push_int_minimal() {
+        let encode = |value| {
+            let mut script = Vec::new();
+            push_int(&mut script, value);
+            script
+        };
+        assert_eq!(encode(0), [OP_0]);
+        assert_eq!(encode(1), [OP_1]);
+        assert_eq!(encode(16), [0x60]);
+        assert_eq!(encode(17), [0x01, 0x11]);
+        assert_eq!(encode(0x80), [0x02, 0x80, 0x00]);
+        assert_eq!(encode(u32::MAX), [0x05, 0xff, 0xff, 0xff, 0xff, 0x00]);
+    }
+
+    #[test]
+    fn test_invalid_timelocks() {
+        assert!(cltv(0, &[]).is_err());
+        assert!(csv(0, &[]).is_err());
+        assert!(csv(SEQUENCE_LOCKTIME_TYPE_FLAG, &[]).is_err());
+        assert!(csv(0x8000_000a, &[]).is_err());
+        assert!(csv(0x0001_0000, &[]).is_err());
+        assert!(csv(10, &[]).is_ok());
+    }
+}
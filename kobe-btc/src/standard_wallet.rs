@@ -5,12 +5,16 @@
 
 #[cfg(feature = "alloc")]
 use alloc::string::{String, ToString};
+use core::fmt;
 
+use bitcoin::hashes::{Hash, hash160};
 use bitcoin::{Address, NetworkKind, PrivateKey, key::CompressedPublicKey};
+use k256::elliptic_curve::subtle::ConstantTimeEq;
 use zeroize::Zeroizing;
 
 use crate::address::create_address;
-use crate::{AddressType, Error, Network};
+use crate::message::{BITCOIN_MESSAGE_PREFIX, MessageSignatureType};
+use crate::{AddressType, Error, MessageSignature, Network, PublicKey};
 
 /// A standard Bitcoin wallet with a single private key.
 ///
@@ -26,7 +30,8 @@
 /// println!("Address: {}", wallet.address_string());
 /// println!("Private Key (WIF): {}", wallet.private_key_wif().as_str());
 /// ```
-#[derive(Debug)]
+///
+/// The `Debug` output omits the private key.
 pub struct StandardWallet {
     /// Bitcoin private key.
     private_key: PrivateKey,
@@ -47,11 +52,6 @@ impl StandardWallet {
     ///
     /// Returns an error if key generation fails.
     ///
-    /// # Panics
-    ///
-    /// This function will not panic under normal circumstances.
-    /// The internal `expect` is guaranteed to succeed for valid private keys.
-    ///
     /// # Note
     ///
     /// This function requires the `rand` feature to be enabled.
@@ -62,7 +62,7 @@ pub fn generate(network: Network, address_type: AddressType) -> Result<Self, Err
 
         let private_key = PrivateKey::new(secret_key, network.to_bitcoin_network());
         let public_key = CompressedPublicKey::from_private_key(&secp, &private_key)
-            .expect("valid private key always produces valid public key");
+            .map_err(|_| Error::InvalidPrivateKey)?;
 
         let address = create_address(&public_key, network, address_type);
 
@@ -79,12 +79,7 @@ pub fn generate(network: Network, address_type: AddressType) -> Result<Self, Err
     ///
     /// # Errors
     ///
-    /// Returns an error if the WIF is invalid.
-    ///
-    /// # Panics
-    ///
-    /// This function will not panic under normal circumstances.
-    /// The internal `expect` is guaranteed to succeed for valid private keys.
+    /// Returns an error if the WIF is invalid or encodes an uncompressed key.
     pub fn from_wif(wif: &str, address_type: AddressType) -> Result<Self, Error> {
         let private_key: PrivateKey = wif.parse().map_err(|_| Error::InvalidWif)?;
 
@@ -96,7 +91,7 @@ pub fn from_wif(wif: &str, address_type: AddressType) -> Result<Self, Error> {
 
         let secp = bitcoin::secp256k1::Secp256k1::new();
         let public_key = CompressedPublicKey::from_private_key(&secp, &private_key)
-            .expect("valid private key always produces valid public key");
+            .map_err(|_| Error::InvalidWif)?;
 
         let address = create_address(&public_key, network, address_type);
 
@@ -109,23 +104,39 @@ pub fn from_wif(wif: &str, address_type: AddressType) -> Result<Self, Error> {
         })
     }
 
-    /// Import a wallet from a hex-encoded secret key.
+    /// Import a WIF private key, requiring it to be encoded for `network`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the hex is invalid.
+    /// Returns [`Error::NetworkMismatch`] if the WIF belongs to the other
+    /// network, or an error if the WIF is invalid.
+    pub fn from_wif_for(
+        wif: &str,
+        network: Network,
+        address_type: AddressType,
+    ) -> Result<Self, Error> {
+        let wallet = Self::from_wif(wif, address_type)?;
+        if wallet.network != network {
+            return Err(Error::NetworkMismatch {
+                expected: network.name(),
+                actual: wallet.network.name(),
+            });
+        }
+        Ok(wallet)
+    }
+
+    /// Import a wallet from a hex-encoded secret key.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function will not panic under normal circumstances.
-    /// The internal `expect` is guaranteed to succeed for valid private keys.
+    /// Returns an error if the hex is invalid.
     pub fn from_hex(
         hex_str: &str,
         network: Network,
         address_type: AddressType,
     ) -> Result<Self, Error> {
-        let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-        let bytes = hex::decode(hex_str).map_err(|_| Error::InvalidHex)?;
+        let stripped = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+        let bytes = hex::decode(stripped).map_err(|_| Error::InvalidHex)?;
 
         if bytes.len() != 32 {
             return Err(Error::InvalidPrivateKey);
@@ -138,7 +149,7 @@ pub fn from_hex(
 
         let secp = bitcoin::secp256k1::Secp256k1::new();
         let public_key = CompressedPublicKey::from_private_key(&secp, &private_key)
-            .expect("valid private key always produces valid public key");
+            .map_err(|_| Error::InvalidPrivateKey)?;
 
         let address = create_address(&public_key, network, address_type);
 
@@ -172,6 +183,95 @@ pub fn to_wif(&self) -> Zeroizing<String> {
         Zeroizing::new(self.private_key.to_wif())
     }
 
+    /// Check whether `wif` encodes this wallet's private key and network.
+    ///
+    /// The comparison runs in constant time with respect to the contents
+    /// of the WIF strings.
+    #[must_use]
+    pub fn matches_wif(&self, wif: &str) -> bool {
+        self.to_wif().as_bytes().ct_eq(wif.as_bytes()).into()
+    }
+
+    /// Sign a message with the Bitcoin signed-message prefix (BIP-137).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    #[inline]
+    pub fn sign_message(&self, message: &[u8]) -> Result<MessageSignature, Error> {
+        self.sign_message_with_prefix(BITCOIN_MESSAGE_PREFIX, message)
+    }
+
+    /// Sign a message with a custom magic prefix, e.g.
+    /// `"Litecoin Signed Message:\n"` for alt-chains.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub fn sign_message_with_prefix(
+        &self,
+        prefix: &str,
+        message: &[u8],
+    ) -> Result<MessageSignature, Error> {
+        MessageSignature::sign(
+            &self.secret_bytes(),
+            prefix,
+            message,
+            MessageSignatureType::P2pkh,
+        )
+    }
+
+    /// Sign a message (BIP-137) attesting to an address of `signature_type`,
+    /// which selects the header byte range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub fn sign_message_typed(
+        &self,
+        message: &[u8],
+        signature_type: MessageSignatureType,
+    ) -> Result<MessageSignature, Error> {
+        MessageSignature::sign(
+            &self.secret_bytes(),
+            BITCOIN_MESSAGE_PREFIX,
+            message,
+            signature_type,
+        )
+    }
+
+    /// Check whether `address` is the BIP-86 key-path-only Taproot output
+    /// of this key, i.e. whether this wallet can key-spend it.
+    ///
+    /// Recomputes the output key tweaked with an empty script tree and
+    /// compares output scripts, so the address network is not considered.
+    /// Outputs committing to a script tree never match, even when this key
+    /// is their internal key.
+    #[must_use]
+    pub fn verify_p2tr_keypath(&self, address: &crate::Address) -> bool {
+        let expected = create_address(&self.public_key, self.network, AddressType::P2tr);
+        expected.script_pubkey() == address.inner().script_pubkey()
+    }
+
+    /// Get the public key.
+    #[inline]
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(self.public_key)
+    }
+
+    /// Get `hash160` of the public key in compressed or uncompressed form,
+    /// without building an intermediate [`PublicKey`].
+    #[must_use]
+    pub fn pubkey_hash160(&self, compressed: bool) -> [u8; 20] {
+        let hash = if compressed {
+            hash160::Hash::hash(&self.public_key.to_bytes())
+        } else {
+            hash160::Hash::hash(&self.public_key.0.serialize_uncompressed())
+        };
+        hash.to_byte_array()
+    }
+
     /// Get the public key in compressed hex format.
     #[inline]
     #[must_use]
@@ -199,10 +299,169 @@ pub const fn address_type(&self) -> AddressType {
     }
 }
 
+impl fmt::Debug for StandardWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StandardWallet")
+            .field("private_key", &"[REDACTED]")
+            .field("public_key", &self.public_key)
+            .field("address", &self.address)
+            .field("network", &self.network)
+            .field("address_type", &self.address_type)
+            .finish()
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
+    const TEST_KEY: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+
+    #[test]
+    fn test_pubkey_hash160() {
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2pkh).unwrap();
+        assert_eq!(wallet.pubkey_hash160(true), wallet.public_key().hash160());
+        assert_eq!(
+            hex::encode(wallet.pubkey_hash160(true)),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert_eq!(
+            hex::encode(wallet.pubkey_hash160(false)),
+            "91b24bf9f5288532960ac687abb035127b1d28a5"
+        );
+    }
+
+    #[test]
+    fn test_sign_message_recovers_key() {
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2pkh).unwrap();
+        let signature = wallet.sign_message(b"hello").unwrap();
+        let recovered = signature.recover(BITCOIN_MESSAGE_PREFIX, b"hello").unwrap();
+        assert_eq!(recovered, wallet.public_key());
+        assert!((31..=34).contains(&signature.header()));
+    }
+
+    #[test]
+    fn test_sign_message_typed_recovers_address() {
+        for (signature_type, address_type) in [
+            (MessageSignatureType::P2pkh, AddressType::P2pkh),
+            (MessageSignatureType::P2shP2wpkh, AddressType::P2shP2wpkh),
+            (MessageSignatureType::P2wpkh, AddressType::P2wpkh),
+        ] {
+            let wallet =
+                StandardWallet::from_hex(TEST_KEY, Network::Mainnet, address_type).unwrap();
+            let signature = wallet.sign_message_typed(b"hello", signature_type).unwrap();
+            assert_eq!(signature.signature_type(), signature_type);
+            let recovered = signature
+                .recover_address(BITCOIN_MESSAGE_PREFIX, b"hello", Network::Mainnet)
+                .unwrap();
+            assert_eq!(recovered.to_string(), wallet.address());
+            assert!(signature.verify(BITCOIN_MESSAGE_PREFIX, b"hello", &recovered));
+            assert!(!signature.verify(BITCOIN_MESSAGE_PREFIX, b"hell0", &recovered));
+        }
+    }
+
+    #[test]
+    fn test_sign_message_with_litecoin_prefix() {
+        const LITECOIN_PREFIX: &str = "Litecoin Signed Message:\n";
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2pkh).unwrap();
+        let signature = wallet
+            .sign_message_with_prefix(LITECOIN_PREFIX, b"hello")
+            .unwrap();
+        let recovered = signature.recover(LITECOIN_PREFIX, b"hello").unwrap();
+        assert_eq!(recovered, wallet.public_key());
+
+        // The same signature does not recover the key under Bitcoin's prefix.
+        let other = signature.recover(BITCOIN_MESSAGE_PREFIX, b"hello");
+        assert!(other.map_or(true, |key| key != wallet.public_key()));
+    }
+
+    #[test]
+    fn test_from_wif_for_network_mismatch() {
+        let testnet =
+            StandardWallet::from_hex(TEST_KEY, Network::Testnet, AddressType::P2wpkh).unwrap();
+        let wif = testnet.to_wif();
+
+        let err =
+            StandardWallet::from_wif_for(&wif, Network::Mainnet, AddressType::P2wpkh).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::NetworkMismatch {
+                expected: "mainnet",
+                actual: "testnet"
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "network mismatch: expected mainnet, got testnet"
+        );
+
+        let imported =
+            StandardWallet::from_wif_for(&wif, Network::Testnet, AddressType::P2wpkh).unwrap();
+        assert_eq!(imported.address(), testnet.address());
+    }
+
+    #[test]
+    fn test_debug_redacts_private_key() {
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2wpkh).unwrap();
+        let debug = format!("{wallet:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(wallet.to_wif().as_str()));
+        assert!(!debug.contains(wallet.secret_hex().as_str()));
+    }
+
+    #[test]
+    fn test_matches_wif() {
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2wpkh).unwrap();
+        let wif = wallet.to_wif();
+        assert!(wallet.matches_wif(&wif));
+        assert!(!wallet.matches_wif(&wif[..wif.len() - 1]));
+
+        let other =
+            StandardWallet::from_hex(TEST_KEY, Network::Testnet, AddressType::P2wpkh).unwrap();
+        assert!(!wallet.matches_wif(&other.to_wif()));
+    }
+
+    #[test]
+    fn test_secret_exports_zeroize_on_drop() {
+        // Reading the buffer after drop is not sound, so check the wipe is
+        // wired up through the `ZeroizeOnDrop` bound instead.
+        const fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>(_: &T) {}
+
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2wpkh).unwrap();
+        assert_zeroize_on_drop(&wallet.to_wif());
+        assert_zeroize_on_drop(&wallet.secret_bytes());
+        assert_zeroize_on_drop(&wallet.secret_hex());
+    }
+
+    #[test]
+    fn test_verify_p2tr_keypath() {
+        let wallet =
+            StandardWallet::from_hex(TEST_KEY, Network::Mainnet, AddressType::P2tr).unwrap();
+        let own = crate::Address::parse(&wallet.address(), Network::Mainnet).unwrap();
+        assert!(wallet.verify_p2tr_keypath(&own));
+
+        let other_key = "0000000000000000000000000000000000000000000000000000000000000002";
+        let other =
+            StandardWallet::from_hex(other_key, Network::Mainnet, AddressType::P2tr).unwrap();
+        let foreign = crate::Address::parse(&other.address(), Network::Mainnet).unwrap();
+        assert!(!wallet.verify_p2tr_keypath(&foreign));
+
+        // Same key, but not a Taproot output.
+        let segwit = crate::Address::from_public_key(
+            &wallet.public_key(),
+            Network::Mainnet,
+            AddressType::P2wpkh,
+        );
+        assert!(!wallet.verify_p2tr_keypath(&segwit));
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn test_generate_mainnet_p2wpkh() {
@@ -0,0 +1,250 @@
+//! Semantic `nLockTime` and `nSequence` values.
+//!
+//! A raw `u32` locktime is a block height or a Unix timestamp depending on
+//! which side of [`LockTime::THRESHOLD`] it falls, and a raw sequence mixes
+//! replace-by-fee signaling (BIP-125) with BIP-68 relative timelocks.
+//! [`LockTime`] and [`Sequence`] make the meaning explicit and encode the
+//! bits correctly.
+
+use crate::Error;
+use crate::script::{SEQUENCE_LOCKTIME_MASK, SEQUENCE_LOCKTIME_TYPE_FLAG};
+
+/// BIP-68 flag disabling the relative lock of an input.
+const SEQUENCE_LOCKTIME_DISABLE_FLAG: u32 = 1 << 31;
+
+/// Granularity of BIP-68 time-based relative locks, in seconds.
+const SEQUENCE_TIME_UNIT: u32 = 512;
+
+/// An absolute transaction locktime (`nLockTime`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LockTime {
+    /// Spendable once the chain reaches this block height.
+    Height(u32),
+    /// Spendable once the median time past reaches this Unix timestamp.
+    Time(u32),
+}
+
+impl LockTime {
+    /// Values below this are block heights, values at or above it are
+    /// Unix timestamps.
+    pub const THRESHOLD: u32 = 500_000_000;
+
+    /// No locktime: the transaction is final immediately.
+    pub const ZERO: Self = Self::Height(0);
+
+    /// Lock until block `height`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimelock`] if `height` is not below
+    /// [`Self::THRESHOLD`], where it would be read as a timestamp.
+    #[inline]
+    pub const fn from_height(height: u32) -> Result<Self, Error> {
+        if height >= Self::THRESHOLD {
+            return Err(Error::InvalidTimelock(height));
+        }
+        Ok(Self::Height(height))
+    }
+
+    /// Lock until the Unix timestamp `time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimelock`] if `time` is below
+    /// [`Self::THRESHOLD`], where it would be read as a block height.
+    #[inline]
+    pub const fn from_time(time: u32) -> Result<Self, Error> {
+        if time < Self::THRESHOLD {
+            return Err(Error::InvalidTimelock(time));
+        }
+        Ok(Self::Time(time))
+    }
+
+    /// Interpret a raw `nLockTime` value.
+    #[inline]
+    #[must_use]
+    pub const fn from_consensus(value: u32) -> Self {
+        if value < Self::THRESHOLD {
+            Self::Height(value)
+        } else {
+            Self::Time(value)
+        }
+    }
+
+    /// Get the raw `nLockTime` value.
+    #[inline]
+    #[must_use]
+    pub const fn to_consensus(self) -> u32 {
+        match self {
+            Self::Height(value) | Self::Time(value) => value,
+        }
+    }
+}
+
+impl Default for LockTime {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<LockTime> for bitcoin::absolute::LockTime {
+    #[inline]
+    fn from(lock_time: LockTime) -> Self {
+        Self::from_consensus(lock_time.to_consensus())
+    }
+}
+
+/// An input sequence number (`nSequence`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sequence(u32);
+
+impl Sequence {
+    /// Final sequence: no RBF signaling and no relative lock.
+    pub const MAX: Self = Self(0xffff_ffff);
+
+    /// Signal replace-by-fee (BIP-125) without a relative lock.
+    #[inline]
+    #[must_use]
+    pub const fn rbf() -> Self {
+        Self(0xffff_fffd)
+    }
+
+    /// Relative lock of `blocks` blocks after the spent output confirmed.
+    ///
+    /// Any relative lock also signals replace-by-fee.
+    #[inline]
+    #[must_use]
+    pub const fn from_height(blocks: u16) -> Self {
+        Self(blocks as u32)
+    }
+
+    /// Relative lock of at least `seconds` after the spent output
+    /// confirmed.
+    ///
+    /// BIP-68 counts time in 512-second units, so `seconds` is rounded up
+    /// to the next multiple of 512.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimelock`] if the lock needs more than
+    /// `0xffff` units (about 388 days).
+    #[inline]
+    pub const fn from_time(seconds: u32) -> Result<Self, Error> {
+        let units = seconds.div_ceil(SEQUENCE_TIME_UNIT);
+        if units > SEQUENCE_LOCKTIME_MASK {
+            return Err(Error::InvalidTimelock(seconds));
+        }
+        Ok(Self(SEQUENCE_LOCKTIME_TYPE_FLAG | units))
+    }
+
+    /// Wrap a raw `nSequence` value.
+    #[inline]
+    #[must_use]
+    pub const fn from_consensus(value: u32) -> Self {
+        Self(value)
+    }
+
+    /// Get the raw `nSequence` value.
+    #[inline]
+    #[must_use]
+    pub const fn to_consensus(self) -> u32 {
+        self.0
+    }
+
+    /// Check whether the input signals replace-by-fee (BIP-125).
+    #[inline]
+    #[must_use]
+    pub const fn is_rbf(self) -> bool {
+        self.0 < 0xffff_fffe
+    }
+
+    /// Check whether the input carries a BIP-68 relative lock.
+    ///
+    /// # Note
+    ///
+    /// Relative locks are only enforced in version 2 transactions.
+    #[inline]
+    #[must_use]
+    pub const fn is_relative_lock(self) -> bool {
+        self.0 & SEQUENCE_LOCKTIME_DISABLE_FLAG == 0
+    }
+}
+
+impl Default for Sequence {
+    #[inline]
+    fn default() -> Self {
+        Self::MAX
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<Sequence> for bitcoin::Sequence {
+    #[inline]
+    fn from(sequence: Sequence) -> Self {
+        Self(sequence.to_consensus())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_512_seconds() {
+        let sequence = Sequence::from_time(512).unwrap();
+        assert_eq!(sequence.to_consensus(), 0x0040_0001);
+        assert!(sequence.is_relative_lock());
+        assert!(sequence.is_rbf());
+
+        // Rounded up to whole 512-second units.
+        assert_eq!(
+            Sequence::from_time(513).unwrap().to_consensus(),
+            0x0040_0002
+        );
+        assert_eq!(
+            Sequence::from_time(0xffff * 512).unwrap().to_consensus(),
+            0x0040_ffff
+        );
+        assert!(Sequence::from_time(0xffff * 512 + 1).is_err());
+    }
+
+    #[test]
+    fn test_sequence_height_and_rbf() {
+        assert_eq!(Sequence::from_height(144).to_consensus(), 144);
+        assert!(Sequence::from_height(144).is_relative_lock());
+
+        let rbf = Sequence::rbf();
+        assert!(rbf.is_rbf());
+        assert!(!rbf.is_relative_lock());
+        assert!(!Sequence::MAX.is_rbf());
+    }
+
+    #[test]
+    fn test_lock_time_threshold() {
+        assert_eq!(LockTime::from_consensus(800_000), LockTime::Height(800_000));
+        assert_eq!(
+            LockTime::from_consensus(LockTime::THRESHOLD),
+            LockTime::Time(LockTime::THRESHOLD)
+        );
+        assert!(LockTime::from_height(LockTime::THRESHOLD).is_err());
+        assert!(LockTime::from_time(LockTime::THRESHOLD - 1).is_err());
+    }
+
+    #[cfg(feature = "bitcoin")]
+    #[test]
+    fn test_bitcoin_conversions() {
+        assert_eq!(
+            bitcoin::Sequence::from(Sequence::rbf()),
+            bitcoin::Sequence::ENABLE_RBF_NO_LOCKTIME
+        );
+        let time = LockTime::from_time(1_700_000_000).unwrap();
+        assert_eq!(
+            bitcoin::absolute::LockTime::from(time),
+            bitcoin::absolute::LockTime::from_consensus(1_700_000_000)
+        );
+    }
+}
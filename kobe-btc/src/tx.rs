@@ -0,0 +1,501 @@
+//! Transaction builders for fee management.
+//!
+//! [`build_cpfp`] creates a child-pays-for-parent transaction that spends an
+//! output of a stuck parent, paying enough fee to lift the combined
+//! package to a target fee rate. [`check_fee_sanity`] guards against
+//! absurd overpayment before broadcast. [`estimate_vsize`] sizes a
+//! transaction for coin selection before it is built. [`txid`] and
+//! [`wtxid`] identify a transaction without and with its witness data.
+
+use alloc::vec;
+
+use bitcoin::hashes::Hash;
+use bitcoin::transaction::Version;
+use bitcoin::{OutPoint, ScriptBuf, Transaction, TxIn, TxOut, Txid, Witness};
+use kobe::Amount;
+use kobe::bytes::varint_len;
+
+use crate::{Address, AddressType, Error, LockTime, Sequence};
+
+/// Witness weight of a P2WPKH spend: marker and flag, item count, a
+/// worst-case 72-byte DER signature and a 33-byte compressed key.
+const P2WPKH_WITNESS_WEIGHT: u64 = 2 + 1 + (1 + 72) + (1 + 33);
+
+/// Non-witness bytes of a one-input, one-output transaction excluding the
+/// output script: version, counts, outpoint, empty script sig, sequence,
+/// output value, script length prefix and locktime.
+const CHILD_BASE_SIZE: u64 = 4 + 1 + (32 + 4 + 1 + 4) + 1 + 8 + 1 + 4;
+
+/// Conservative default for the `max_fee_rate` of [`check_fee_sanity`],
+/// in sat/vB.
+pub const DEFAULT_MAX_FEE_RATE: u64 = 1_000;
+
+/// Conservative default for the `max_total_fee` of [`check_fee_sanity`]
+/// (0.01 BTC).
+pub const DEFAULT_MAX_TOTAL_FEE: Amount = Amount::from_satoshi(1_000_000);
+
+/// Build an unsigned child transaction that bumps an unconfirmed parent.
+///
+/// The child spends `parent_txid:parent_vout` (worth `parent_value`) into a
+/// single output paying `destination`. Its fee is chosen so that the parent
+/// (`parent_vsize` vbytes, paying `parent_fee`) and the child together reach
+/// `combined_fee_rate` sat/vB. The child always pays at least
+/// `combined_fee_rate` for its own vsize, so it relays on its own even when
+/// the parent already meets the target.
+///
+/// The returned transaction has an empty witness; sign it before broadcast.
+///
+/// # Note
+///
+/// The child's size is estimated assuming the spent output is P2WPKH, with
+/// a worst-case signature, so the package rate is never below the target.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAmount`] if an amount is not in satoshi, or
+/// [`Error::InsufficientFunds`] if `parent_value` cannot pay the child fee
+/// and still leave a non-dust output.
+pub fn build_cpfp(
+    parent_txid: [u8; 32],
+    parent_vout: u32,
+    parent_value: Amount,
+    parent_vsize: u64,
+    parent_fee: Amount,
+    combined_fee_rate: u64,
+    destination: &Address,
+) -> Result<Transaction, Error> {
+    let script_pubkey = destination.inner().script_pubkey();
+    let child_vsize = child_vsize(&script_pubkey);
+
+    let package_fee = combined_fee_rate.saturating_mul(parent_vsize.saturating_add(child_vsize));
+    let child_fee = package_fee
+        .saturating_sub(satoshi(parent_fee)?)
+        .max(combined_fee_rate.saturating_mul(child_vsize));
+    let dust = script_pubkey.minimal_non_dust().to_sat();
+    let available = satoshi(parent_value)?;
+    if child_fee.saturating_add(dust) > available {
+        return Err(Error::InsufficientFunds {
+            required: child_fee.saturating_add(dust),
+            available,
+        });
+    }
+
+    Ok(Transaction {
+        version: Version::TWO,
+        lock_time: LockTime::ZERO.into(),
+        input: vec![TxIn {
+            previous_output: OutPoint::new(Txid::from_byte_array(parent_txid), parent_vout),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::rbf().into(),
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: bitcoin::Amount::from_sat(available - child_fee),
+            script_pubkey,
+        }],
+    })
+}
+
+/// Check that `tx` does not pay an absurd fee.
+///
+/// The fee is `input_value` (the total value of the spent outputs) minus
+/// the outputs of `tx`. Callers that really mean to pay more must raise
+/// the caps explicitly; [`DEFAULT_MAX_FEE_RATE`] and
+/// [`DEFAULT_MAX_TOTAL_FEE`] are conservative starting points.
+///
+/// # Note
+///
+/// The fee rate uses the current vsize of `tx`, so check the signed
+/// transaction: without witnesses the rate is overstated.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAmount`] if an amount is not in satoshi,
+/// [`Error::InsufficientFunds`] if the outputs exceed `input_value`, or
+/// [`Error::ExcessiveFee`] if the fee rate is above `max_fee_rate` sat/vB
+/// or the fee is above `max_total_fee`.
+pub fn check_fee_sanity(
+    tx: &Transaction,
+    input_value: Amount,
+    max_fee_rate: u64,
+    max_total_fee: Amount,
+) -> Result<(), Error> {
+    let output_value = tx
+        .output
+        .iter()
+        .map(|output| output.value.to_sat())
+        .fold(0u64, u64::saturating_add);
+    let available = satoshi(input_value)?;
+    let fee = available
+        .checked_sub(output_value)
+        .ok_or(Error::InsufficientFunds {
+            required: output_value,
+            available,
+        })?;
+    let vsize = (tx.vsize() as u64).max(1);
+    // Compare against the cap in whole satoshi so a fractional excess over
+    // `max_fee_rate` is not rounded away; a cap that overflows cannot be hit.
+    let above_rate = max_fee_rate
+        .checked_mul(vsize)
+        .is_some_and(|max_fee| fee > max_fee);
+    if above_rate || fee > satoshi(max_total_fee)? {
+        return Err(Error::ExcessiveFee {
+            fee,
+            fee_rate: fee.div_ceil(vsize),
+        });
+    }
+    Ok(())
+}
+
+/// Get the value of a satoshi-denominated `amount`.
+fn satoshi(amount: Amount) -> Result<u64, Error> {
+    amount.as_satoshi().ok_or(Error::InvalidAmount)
+}
+
+/// Estimate the signed vsize of a transaction spending `inputs` to
+/// `outputs`, given the address type of each.
+///
+/// Inputs assume single-key spends with worst-case 72-byte ECDSA
+/// signatures and compressed keys (e.g. 68 vbytes for P2WPKH), and Taproot
+/// key-path spends with default sighash (57.5 vbytes), so the estimate
+/// errs on the high side. Fractional vbytes are rounded up for the whole
+/// transaction.
+#[must_use]
+pub fn estimate_vsize(inputs: &[AddressType], outputs: &[AddressType]) -> usize {
+    let has_witness = inputs.iter().any(|&input| input != AddressType::P2pkh);
+    // Version and locktime, then the input and output counts.
+    let mut weight =
+        4 * (4 + 4 + varint_len(inputs.len() as u64) + varint_len(outputs.len() as u64));
+    if has_witness {
+        // Segwit marker and flag.
+        weight += 2;
+    }
+    for &input in inputs {
+        let (script_sig_len, witness_len) = match input {
+            // <sig> <pubkey>
+            AddressType::P2pkh => (1 + 72 + 1 + 33, 0),
+            // <0 <20-byte hash>>, then the P2WPKH witness.
+            AddressType::P2shP2wpkh => (1 + 22, 1 + (1 + 72) + (1 + 33)),
+            AddressType::P2wpkh => (0, 1 + (1 + 72) + (1 + 33)),
+            AddressType::P2tr => (0, 1 + (1 + 64)),
+        };
+        // Outpoint, script sig with its length and sequence.
+        weight += 4 * (36 + 1 + script_sig_len + 4);
+        // Inputs without witness data still need an empty witness stack.
+        weight += if has_witness { witness_len.max(1) } else { 0 };
+    }
+    for &output in outputs {
+        let script_len = match output {
+            AddressType::P2pkh => 25,
+            AddressType::P2shP2wpkh => 23,
+            AddressType::P2wpkh => 22,
+            AddressType::P2tr => 34,
+        };
+        weight += 4 * (8 + 1 + script_len);
+    }
+    weight.div_ceil(4)
+}
+
+/// Compute the txid: double SHA-256 of the serialization without the
+/// segwit marker, flag and witnesses, in internal byte order.
+///
+/// Reverse the bytes for the hex form shown by explorers.
+#[inline]
+#[must_use]
+pub fn txid(tx: &Transaction) -> [u8; 32] {
+    tx.compute_txid().to_byte_array()
+}
+
+/// Compute the wtxid: double SHA-256 of the full segwit serialization, in
+/// internal byte order.
+///
+/// Equals [`txid`] for a transaction without witness data.
+#[inline]
+#[must_use]
+pub fn wtxid(tx: &Transaction) -> [u8; 32] {
+    tx.compute_wtxid().to_byte_array()
+}
+
+/// Estimate the signed vsize of a one-input P2WPKH child paying `script`.
+fn child_vsize(script: &ScriptBuf) -> u64 {
+    let base = CHILD_BASE_SIZE + script.len() as u64;
+    (base * 4 + P2WPKH_WITNESS_WEIGHT).div_ceil(4)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::Network;
+
+    const DESTINATION: &str = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+
+    fn destination() -> Address {
+        Address::parse(DESTINATION, Network::Mainnet).unwrap()
+    }
+
+    #[test]
+    fn test_child_fee_covers_parent_deficit() {
+        let parent_vsize = 200;
+        let parent_fee = Amount::from_satoshi(200);
+        let rate = 10;
+        let parent_value = Amount::from_satoshi(100_000);
+
+        let child = build_cpfp(
+            [0xab; 32],
+            1,
+            parent_value,
+            parent_vsize,
+            parent_fee,
+            rate,
+            &destination(),
+        )
+        .unwrap();
+
+        let child_fee = parent_value.as_satoshi().unwrap() - child.output[0].value.to_sat();
+        let deficit = parent_vsize * rate - parent_fee.as_satoshi().unwrap();
+        let estimated_vsize = child_vsize(&child.output[0].script_pubkey);
+        assert!(child_fee >= deficit + estimated_vsize * rate);
+        assert_eq!(
+            (parent_fee.as_satoshi().unwrap() + child_fee) / (parent_vsize + estimated_vsize),
+            rate
+        );
+        assert_eq!(child.input[0].previous_output.vout, 1);
+        assert_eq!(
+            child.input[0].previous_output.txid.to_byte_array(),
+            [0xab; 32]
+        );
+    }
+
+    #[test]
+    fn test_child_pays_own_rate_when_parent_meets_target() {
+        let parent_value = Amount::from_satoshi(100_000);
+        let rate = 10;
+        // The parent alone already pays 50 sat/vB.
+        let child = build_cpfp(
+            [0xab; 32],
+            0,
+            parent_value,
+            200,
+            Amount::from_satoshi(10_000),
+            rate,
+            &destination(),
+        )
+        .unwrap();
+
+        let child_fee = parent_value.as_satoshi().unwrap() - child.output[0].value.to_sat();
+        assert_eq!(
+            child_fee,
+            rate * child_vsize(&child.output[0].script_pubkey)
+        );
+    }
+
+    #[test]
+    fn test_child_vsize_estimate_is_upper_bound() {
+        let mut child = build_cpfp(
+            [0; 32],
+            0,
+            Amount::from_satoshi(50_000),
+            150,
+            Amount::from_satoshi(150),
+            5,
+            &destination(),
+        )
+        .unwrap();
+        let estimated = child_vsize(&child.output[0].script_pubkey);
+        child.input[0].witness = Witness::from_slice(&[vec![0u8; 72], vec![0u8; 33]]);
+        assert!(child.vsize() as u64 <= estimated);
+    }
+
+    /// A transaction with inputs of the given script sig length and witness
+    /// item lengths, paying 10 000 sat to each address in `outputs`.
+    fn sized_tx(script_sigs_and_witnesses: &[(usize, &[usize])], outputs: &[&str]) -> Transaction {
+        Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO.into(),
+            input: script_sigs_and_witnesses
+                .iter()
+                .map(|&(script_sig_len, items)| TxIn {
+                    previous_output: OutPoint::null(),
+                    script_sig: ScriptBuf::from_bytes(vec![0; script_sig_len]),
+                    sequence: Sequence::rbf().into(),
+                    witness: Witness::from_slice(
+                        &items.iter().map(|&len| vec![0u8; len]).collect::<Vec<_>>(),
+                    ),
+                })
+                .collect(),
+            output: outputs
+                .iter()
+                .map(|address| TxOut {
+                    value: bitcoin::Amount::from_sat(10_000),
+                    script_pubkey: Address::parse(address, Network::Mainnet)
+                        .unwrap()
+                        .inner()
+                        .script_pubkey(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_estimate_vsize_segwit_2_in_2_out() {
+        let p2wpkh = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+        // Typical 71- or 72-byte DER signatures.
+        let tx = sized_tx(&[(0, &[72, 33]), (0, &[71, 33])], &[p2wpkh, p2wpkh]);
+        let estimate = estimate_vsize(&[AddressType::P2wpkh; 2], &[AddressType::P2wpkh; 2]);
+        assert_eq!(estimate, 209);
+        assert!(estimate >= tx.vsize());
+        assert!(estimate - tx.vsize() <= 1);
+    }
+
+    #[test]
+    fn test_estimate_vsize_mixed() {
+        let p2tr = "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297";
+        let p2pkh = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        let p2sh = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+        let tx = sized_tx(
+            &[(107, &[]), (23, &[72, 33]), (0, &[64])],
+            &[p2tr, p2pkh, p2sh],
+        );
+        let estimate = estimate_vsize(
+            &[
+                AddressType::P2pkh,
+                AddressType::P2shP2wpkh,
+                AddressType::P2tr,
+            ],
+            &[
+                AddressType::P2tr,
+                AddressType::P2pkh,
+                AddressType::P2shP2wpkh,
+            ],
+        );
+        assert_eq!(estimate, tx.vsize());
+
+        // Legacy only: no marker, flag or witness stacks.
+        let legacy = sized_tx(&[(107, &[])], &[p2pkh]);
+        assert_eq!(
+            estimate_vsize(&[AddressType::P2pkh], &[AddressType::P2pkh]),
+            legacy.vsize()
+        );
+    }
+
+    #[test]
+    fn test_txid_and_wtxid() {
+        let unsigned = build_cpfp(
+            [0x11; 32],
+            0,
+            Amount::from_satoshi(50_000),
+            150,
+            Amount::from_satoshi(150),
+            5,
+            &destination(),
+        )
+        .unwrap();
+        assert_eq!(txid(&unsigned), wtxid(&unsigned));
+
+        let mut signed = unsigned.clone();
+        signed.input[0].witness = Witness::from_slice(&[vec![0x30; 71], vec![0x02; 33]]);
+        // The witness is excluded from the txid but committed to by the wtxid.
+        assert_eq!(txid(&signed), txid(&unsigned));
+        assert_ne!(wtxid(&signed), txid(&signed));
+
+        // Without witnesses the serialization has no marker and flag.
+        let stripped = bitcoin::consensus::serialize(&unsigned);
+        let expected = bitcoin::hashes::sha256d::Hash::hash(&stripped).to_byte_array();
+        assert_eq!(txid(&signed), expected);
+    }
+
+    #[test]
+    fn test_insufficient_parent_value() {
+        let result = build_cpfp(
+            [0; 32],
+            0,
+            Amount::from_satoshi(1_000),
+            200,
+            Amount::from_satoshi(0),
+            50,
+            &destination(),
+        );
+        assert!(matches!(result, Err(Error::InsufficientFunds { .. })));
+    }
+
+    #[test]
+    fn test_fee_sanity() {
+        let mut signed = build_cpfp(
+            [0; 32],
+            0,
+            Amount::from_satoshi(100_000),
+            150,
+            Amount::from_satoshi(150),
+            5,
+            &destination(),
+        )
+        .unwrap();
+        signed.input[0].witness = Witness::from_slice(&[vec![0x30; 71], vec![0x02; 33]]);
+        let input_value = Amount::from_satoshi(100_000);
+        check_fee_sanity(
+            &signed,
+            input_value,
+            DEFAULT_MAX_FEE_RATE,
+            DEFAULT_MAX_TOTAL_FEE,
+        )
+        .unwrap();
+
+        // Half of the input value burnt as fee.
+        signed.output[0].value = bitcoin::Amount::from_sat(50_000);
+        let rate_capped = check_fee_sanity(&signed, input_value, 100, DEFAULT_MAX_TOTAL_FEE);
+        assert!(matches!(
+            rate_capped,
+            Err(Error::ExcessiveFee { fee: 50_000, .. })
+        ));
+        let total_capped =
+            check_fee_sanity(&signed, input_value, u64::MAX, Amount::from_satoshi(10_000));
+        assert!(matches!(total_capped, Err(Error::ExcessiveFee { .. })));
+
+        signed.output[0].value = bitcoin::Amount::from_sat(100_001);
+        let overspent = check_fee_sanity(
+            &signed,
+            input_value,
+            u64::MAX,
+            Amount::from_satoshi(u64::MAX),
+        );
+        assert!(matches!(overspent, Err(Error::InsufficientFunds { .. })));
+
+        let wei = check_fee_sanity(
+            &signed,
+            Amount::from_wei(100_000),
+            DEFAULT_MAX_FEE_RATE,
+            DEFAULT_MAX_TOTAL_FEE,
+        );
+        assert!(matches!(wei, Err(Error::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_fee_sanity_rate_is_not_rounded_down() {
+        let mut signed = build_cpfp(
+            [0; 32],
+            0,
+            Amount::from_satoshi(100_000),
+            150,
+            Amount::from_satoshi(150),
+            5,
+            &destination(),
+        )
+        .unwrap();
+        signed.input[0].witness = Witness::from_slice(&[vec![0x30; 71], vec![0x02; 33]]);
+        let vsize = signed.vsize() as u64;
+
+        // Exactly 100 sat/vB is allowed.
+        signed.output[0].value = bitcoin::Amount::from_sat(100_000 - 100 * vsize);
+        let input_value = Amount::from_satoshi(100_000);
+        check_fee_sanity(&signed, input_value, 100, DEFAULT_MAX_TOTAL_FEE).unwrap();
+
+        // One satoshi more is above the cap, though it floors to 100 sat/vB.
+        signed.output[0].value = bitcoin::Amount::from_sat(100_000 - 100 * vsize - 1);
+        let result = check_fee_sanity(&signed, input_value, 100, DEFAULT_MAX_TOTAL_FEE);
+        assert!(matches!(
+            result,
+            Err(Error::ExcessiveFee { fee_rate: 101, .. })
+        ));
+    }
+}
@@ -1,15 +1,18 @@
 //! Common types for Bitcoin wallet operations.
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 use alloc::{format, string::ToString};
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
+use bitcoin::bip32::ChildNumber;
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 use crate::{Error, Network};
 use core::fmt;
 use core::str::FromStr;
 
 /// Bitcoin address types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_enums)]
 pub enum AddressType {
     /// Pay to Public Key Hash (Legacy) - starts with 1 or m/n
     P2pkh,
@@ -56,6 +59,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 
 /// Error returned when parsing an invalid address type string.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)]
 pub struct ParseAddressTypeError;
 
 impl fmt::Display for ParseAddressTypeError {
@@ -74,24 +78,38 @@ impl FromStr for AddressType {
     type Err = ParseAddressTypeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "p2pkh" | "legacy" => Ok(Self::P2pkh),
-            "p2sh" | "p2sh-p2wpkh" | "segwit" | "nested-segwit" => Ok(Self::P2shP2wpkh),
-            "p2wpkh" | "native-segwit" | "bech32" => Ok(Self::P2wpkh),
-            "p2tr" | "taproot" | "bech32m" => Ok(Self::P2tr),
-            _ => Err(ParseAddressTypeError),
-        }
+        /// Accepted names, matched case-insensitively.
+        const NAMES: [(&str, AddressType); 12] = [
+            ("p2pkh", AddressType::P2pkh),
+            ("legacy", AddressType::P2pkh),
+            ("p2sh", AddressType::P2shP2wpkh),
+            ("p2sh-p2wpkh", AddressType::P2shP2wpkh),
+            ("segwit", AddressType::P2shP2wpkh),
+            ("nested-segwit", AddressType::P2shP2wpkh),
+            ("p2wpkh", AddressType::P2wpkh),
+            ("native-segwit", AddressType::P2wpkh),
+            ("bech32", AddressType::P2wpkh),
+            ("p2tr", AddressType::P2tr),
+            ("taproot", AddressType::P2tr),
+            ("bech32m", AddressType::P2tr),
+        ];
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name))
+            .map(|&(_, address_type)| address_type)
+            .ok_or(ParseAddressTypeError)
     }
 }
 
 /// BIP32 derivation path.
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DerivationPath {
+    /// Underlying `bitcoin` crate derivation path.
     inner: bitcoin::bip32::DerivationPath,
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 impl DerivationPath {
     /// Create a BIP44/49/84 standard path.
     ///
@@ -99,8 +117,9 @@ impl DerivationPath {
     ///
     /// # Panics
     ///
-    /// This function will not panic as the path format is always valid.
+    /// Panics if `account` or `address_index` is not below `2^31`.
     #[must_use]
+    #[allow(clippy::expect_used)]
     pub fn bip_standard(
         address_type: AddressType,
         network: Network,
@@ -130,6 +149,13 @@ pub fn from_path_str(path: &str) -> Result<Self, Error> {
         Ok(Self { inner })
     }
 
+    /// Check whether every component is hardened, as SLIP-10 Ed25519
+    /// derivation requires.
+    #[must_use]
+    pub fn is_all_hardened(&self) -> bool {
+        self.inner.into_iter().all(ChildNumber::is_hardened)
+    }
+
     /// Get the inner bitcoin derivation path.
     #[inline]
     #[must_use]
@@ -138,14 +164,14 @@ pub const fn inner(&self) -> &bitcoin::bip32::DerivationPath {
     }
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 impl fmt::Display for DerivationPath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "m/{}", self.inner)
     }
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "bitcoin"))]
 impl AsRef<bitcoin::bip32::DerivationPath> for DerivationPath {
     fn as_ref(&self) -> &bitcoin::bip32::DerivationPath {
         &self.inner
@@ -153,6 +179,7 @@ fn as_ref(&self) -> &bitcoin::bip32::DerivationPath {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -204,4 +231,13 @@ fn test_address_type_purpose() {
     fn test_address_type_default() {
         assert_eq!(AddressType::default(), AddressType::P2wpkh);
     }
+
+    #[cfg(all(feature = "alloc", feature = "bitcoin"))]
+    #[test]
+    fn test_is_all_hardened() {
+        let hardened = DerivationPath::from_path_str("m/44'/501'/0'/0'").unwrap();
+        assert!(hardened.is_all_hardened());
+        let mixed = DerivationPath::from_path_str("m/44'/501'/0'/0/0").unwrap();
+        assert!(!mixed.is_all_hardened());
+    }
 }
@@ -8,10 +8,12 @@
 /// Bitcoin wallet operations.
 #[derive(Args)]
 pub struct BitcoinCommand {
+    /// Selected Bitcoin subcommand.
     #[command(subcommand)]
     command: BitcoinSubcommand,
 }
 
+/// Bitcoin subcommands.
 #[derive(Subcommand)]
 enum BitcoinSubcommand {
     /// Generate a new wallet (with mnemonic).
@@ -99,6 +101,7 @@ enum BitcoinSubcommand {
     },
 }
 
+/// CLI-compatible address type enum.
 #[derive(Clone, Copy, ValueEnum)]
 enum CliAddressType {
     /// Legacy P2PKH (starts with 1)
@@ -190,6 +193,7 @@ pub fn execute(self) -> Result<(), Box<dyn std::error::Error>> {
     }
 }
 
+/// Print an HD wallet and its derived addresses.
 #[rustfmt::skip]
 fn print_wallet(
     wallet: &Wallet,
@@ -198,10 +202,7 @@ fn print_wallet(
     count: u32,
     show_qr: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let network_str = match deriver.network() {
-        Network::Mainnet => "mainnet",
-        Network::Testnet => "testnet",
-    };
+    let network_str = deriver.network().name();
     let addresses = deriver.derive_many_with(address_type, 0, count)?;
 
     println!();
@@ -232,12 +233,10 @@ fn print_wallet(
     Ok(())
 }
 
+/// Print a single-key wallet.
 #[rustfmt::skip]
 fn print_standard_wallet(wallet: &StandardWallet, show_qr: bool) {
-    let network_str = match wallet.network() {
-        Network::Mainnet => "mainnet",
-        Network::Testnet => "testnet",
-    };
+    let network_str = wallet.network().name();
 
     println!();
     println!("      {}      {}", "Network".cyan().bold(), network_str);
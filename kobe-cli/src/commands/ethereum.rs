@@ -8,6 +8,7 @@
 /// Ethereum wallet operations.
 #[derive(Args)]
 pub struct EthereumCommand {
+    /// Selected Ethereum subcommand.
     #[command(subcommand)]
     command: EthereumSubcommand,
 }
@@ -29,13 +30,14 @@ pub enum CliDerivationStyle {
 impl From<CliDerivationStyle> for DerivationStyle {
     fn from(style: CliDerivationStyle) -> Self {
         match style {
-            CliDerivationStyle::Standard => DerivationStyle::Standard,
-            CliDerivationStyle::LedgerLive => DerivationStyle::LedgerLive,
-            CliDerivationStyle::LedgerLegacy => DerivationStyle::LedgerLegacy,
+            CliDerivationStyle::Standard => Self::Standard,
+            CliDerivationStyle::LedgerLive => Self::LedgerLive,
+            CliDerivationStyle::LedgerLegacy => Self::LedgerLegacy,
         }
     }
 }
 
+/// Ethereum subcommands.
 #[derive(Subcommand)]
 enum EthereumSubcommand {
     /// Generate a new wallet (with mnemonic).
@@ -142,6 +144,7 @@ pub fn execute(self) -> Result<(), Box<dyn std::error::Error>> {
     }
 }
 
+/// Print an HD wallet and its derived addresses.
 #[rustfmt::skip]
 fn print_wallet(
     wallet: &Wallet,
@@ -179,6 +182,7 @@ fn print_wallet(
     Ok(())
 }
 
+/// Print a single-key wallet.
 #[rustfmt::skip]
 fn print_standard_wallet(wallet: &StandardWallet, show_qr: bool) {
     println!();
@@ -16,6 +16,7 @@
 #[command(version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    /// Selected blockchain command.
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -22,7 +22,7 @@ pub enum CliDerivationStyle {
     /// Ledger Live: m/44'/501'/{index}'/0'/0'
     LedgerLive,
 
-    /// Legacy: m/501'/{index}'/0/0 (deprecated, old Phantom/Sollet)
+    /// Legacy: m/501'/{index}'/0'/0' (deprecated, old Phantom/Sollet)
     #[value(alias = "old")]
     Legacy,
 }
@@ -31,10 +31,10 @@ pub enum CliDerivationStyle {
 impl From<CliDerivationStyle> for DerivationStyle {
     fn from(style: CliDerivationStyle) -> Self {
         match style {
-            CliDerivationStyle::Standard => DerivationStyle::Standard,
-            CliDerivationStyle::Trust => DerivationStyle::Trust,
-            CliDerivationStyle::LedgerLive => DerivationStyle::LedgerLive,
-            CliDerivationStyle::Legacy => DerivationStyle::Legacy,
+            CliDerivationStyle::Standard => Self::Standard,
+            CliDerivationStyle::Trust => Self::Trust,
+            CliDerivationStyle::LedgerLive => Self::LedgerLive,
+            CliDerivationStyle::Legacy => Self::Legacy,
         }
     }
 }
@@ -42,10 +42,12 @@ fn from(style: CliDerivationStyle) -> Self {
 /// Solana wallet operations.
 #[derive(Args)]
 pub struct SolanaCommand {
+    /// Selected Solana subcommand.
     #[command(subcommand)]
     command: SolanaSubcommand,
 }
 
+/// Solana subcommands.
 #[derive(Subcommand)]
 enum SolanaSubcommand {
     /// Generate a new wallet (with mnemonic).
@@ -144,8 +146,8 @@ pub fn execute(self) -> Result<(), Box<dyn std::error::Error>> {
                 print_wallet(&wallet, &deriver, count, style.into(), qr)?;
             }
             SolanaSubcommand::ImportKey { key, qr } => {
-                let key = key.strip_prefix("0x").unwrap_or(&key);
-                let wallet = StandardWallet::from_hex(key)?;
+                let hex_key = key.strip_prefix("0x").unwrap_or(&key);
+                let wallet = StandardWallet::from_hex(hex_key)?;
                 print_standard_wallet(&wallet, qr);
             }
         }
@@ -153,6 +155,7 @@ pub fn execute(self) -> Result<(), Box<dyn std::error::Error>> {
     }
 }
 
+/// Print an HD wallet and its derived addresses.
 #[rustfmt::skip]
 fn print_wallet(
     wallet: &Wallet,
@@ -190,6 +193,7 @@ fn print_wallet(
     Ok(())
 }
 
+/// Print a single-key wallet.
 #[rustfmt::skip]
 fn print_standard_wallet(wallet: &StandardWallet, show_qr: bool) {
     println!();
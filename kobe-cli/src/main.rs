@@ -2,6 +2,8 @@
 //!
 //! Easily generate and manage wallets for Bitcoin, Ethereum, and Solana.
 
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+
 mod commands;
 pub mod qr;
 
@@ -17,6 +19,7 @@ fn main() {
     }
 }
 
+/// Dispatch the parsed command to its handler.
 fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
     match cli.command {
         Commands::Bitcoin(cmd) => cmd.execute()?,
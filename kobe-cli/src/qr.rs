@@ -54,13 +54,8 @@ pub fn render_to_terminal(data: &str) {
     println!();
     for y in (0..height).step_by(2) {
         print!("{indent}");
-        for x in 0..total_width {
-            let top = matrix[y][x];
-            let bottom = if y + 1 < height {
-                matrix[y + 1][x]
-            } else {
-                false
-            };
+        for (x, &top) in matrix[y].iter().enumerate() {
+            let bottom = matrix.get(y + 1).is_some_and(|row| row[x]);
 
             // ▀ = top half, ▄ = bottom half, █ = full, ' ' = empty
             let ch = match (top, bottom) {
@@ -76,6 +71,7 @@ pub fn render_to_terminal(data: &str) {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,44 @@
+//! Compare hashing the public key on every address lookup with reading the
+//! address stored in an [`AddressedPublicKey`].
+//!
+//! Run with `cargo bench -p kobe-eth --bench address`.
+
+#![allow(clippy::print_stdout, clippy::unwrap_used)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use kobe_eth::{AddressedPublicKey, PublicKey};
+
+/// Ganache test account #0.
+const PUBKEY: &str = "04e68acfc0253a10620dff706b0a1b1f1f5833ea3beb3bde2250d5f271f3563606672ebc45e0b7ea2e816ecb70ca03137b1c9476eec63d4632e990020b7b6fba39";
+
+/// Address lookups per measurement.
+const ITERATIONS: u32 = 100_000;
+
+/// Time `ITERATIONS` calls of `f`.
+fn measure(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let key = PublicKey::from_sec1_bytes(&hex::decode(PUBKEY).unwrap()).unwrap();
+    let addressed = AddressedPublicKey::new(key);
+
+    let hashed = measure(|| {
+        black_box(black_box(&key).to_address());
+    });
+    let stored = measure(|| {
+        black_box(black_box(&addressed).address());
+    });
+
+    println!(
+        "to_address: {:?}/call, AddressedPublicKey::address: {:?}/call",
+        hashed / ITERATIONS,
+        stored / ITERATIONS,
+    );
+}
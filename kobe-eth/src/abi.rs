@@ -0,0 +1,342 @@
+//! Solidity ABI helpers.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use alloy_primitives::{I256, U256};
+
+use crate::hash::keccak256;
+#[cfg(feature = "alloc")]
+use crate::{Address, Error};
+
+/// Size of an ABI word in bytes.
+#[cfg(feature = "alloc")]
+const WORD: usize = 32;
+
+/// An ABI parameter type, used to drive [`decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiType {
+    /// `address`.
+    Address,
+    /// `uintN`, decoded as a full 256-bit word.
+    Uint,
+    /// `intN`, decoded as a full 256-bit two's-complement word.
+    Int,
+    /// `bool`.
+    Bool,
+    /// `bytesN`, as a full 32-byte word.
+    FixedBytes,
+    /// Dynamic `bytes`.
+    Bytes,
+    /// Dynamic `string`.
+    String,
+}
+
+/// A value of an indexed event parameter.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AbiValue<'a> {
+    /// `address`.
+    Address(Address),
+    /// `uintN`.
+    Uint(U256),
+    /// `intN`.
+    Int(I256),
+    /// `bool`.
+    Bool(bool),
+    /// `bytesN`, left-aligned and zero-padded to 32 bytes.
+    FixedBytes([u8; 32]),
+    /// Dynamic `bytes`.
+    Bytes(&'a [u8]),
+    /// Dynamic `string`.
+    String(&'a str),
+}
+
+/// Compute the 4-byte function selector of a signature such as
+/// `transfer(address,uint256)`.
+///
+/// Surrounding whitespace is trimmed; the signature grammar itself is not
+/// validated, so it must already be in canonical form.
+#[inline]
+#[must_use]
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.trim().as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Compute the log topic (`topic0`) of an event signature such as
+/// `Transfer(address,address,uint256)`.
+///
+/// Unlike [`selector`], this is the full 32-byte hash. Surrounding
+/// whitespace is trimmed.
+#[inline]
+#[must_use]
+pub fn event_topic(signature: &str) -> [u8; 32] {
+    keccak256(signature.trim().as_bytes())
+}
+
+/// Encode an indexed event parameter as a 32-byte log topic.
+///
+/// Static types are ABI-encoded into one word (addresses and integers
+/// left-padded, `bytesN` right-padded). Dynamic `bytes` and `string`
+/// values cannot fit in a topic, so their `keccak256` hash is used instead.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_topic(value: &AbiValue<'_>) -> [u8; 32] {
+    let mut topic = [0u8; 32];
+    match value {
+        AbiValue::Address(address) => topic = address_word(address),
+        AbiValue::Uint(n) => topic = uint_word(*n),
+        AbiValue::Int(n) => topic = uint_word(n.into_raw()),
+        AbiValue::Bool(b) => topic[31] = u8::from(*b),
+        AbiValue::FixedBytes(bytes) => topic = *bytes,
+        AbiValue::Bytes(bytes) => topic = keccak256(bytes),
+        AbiValue::String(s) => topic = keccak256(s.as_bytes()),
+    }
+    topic
+}
+
+/// Encode an address as a left-padded 32-byte ABI word.
+#[cfg(feature = "alloc")]
+pub(crate) fn address_word(address: &Address) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address.as_bytes());
+    word
+}
+
+/// Encode an unsigned integer as a big-endian 32-byte ABI word.
+#[cfg(feature = "alloc")]
+pub(crate) const fn uint_word(value: U256) -> [u8; 32] {
+    value.to_be_bytes()
+}
+
+/// Decode ABI-encoded data, such as `eth_call` return data, into values of
+/// the given types.
+///
+/// Dynamic `bytes` and `string` values borrow from `data`.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidAbiData`] if the data is truncated, an offset or
+/// length is out of bounds, a value is out of range for its type or not
+/// canonically padded, or a string is not UTF-8.
+#[cfg(feature = "alloc")]
+pub fn decode<'a>(types: &[AbiType], data: &'a [u8]) -> Result<Vec<AbiValue<'a>>, Error> {
+    types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let head = word_at(data, i * WORD)?;
+            match ty {
+                AbiType::Address => {
+                    let (padding, bytes) = head.split_at(12);
+                    if padding.iter().any(|&b| b != 0) {
+                        return Err(Error::InvalidAbiData);
+                    }
+                    let mut address = [0u8; 20];
+                    address.copy_from_slice(bytes);
+                    Ok(AbiValue::Address(Address::from_bytes(address)))
+                }
+                AbiType::Uint => Ok(AbiValue::Uint(U256::from_be_bytes(*head))),
+                AbiType::Int => Ok(AbiValue::Int(I256::from_raw(U256::from_be_bytes(*head)))),
+                AbiType::Bool => match U256::from_be_bytes(*head) {
+                    U256::ZERO => Ok(AbiValue::Bool(false)),
+                    value if value == U256::from(1u8) => Ok(AbiValue::Bool(true)),
+                    _ => Err(Error::InvalidAbiData),
+                },
+                AbiType::FixedBytes => Ok(AbiValue::FixedBytes(*head)),
+                AbiType::Bytes => dynamic_at(data, head).map(AbiValue::Bytes),
+                AbiType::String => {
+                    let bytes = dynamic_at(data, head)?;
+                    core::str::from_utf8(bytes)
+                        .map(AbiValue::String)
+                        .map_err(|_| Error::InvalidAbiData)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Read the 32-byte word starting at `offset`.
+#[cfg(feature = "alloc")]
+fn word_at(data: &[u8], offset: usize) -> Result<&[u8; WORD], Error> {
+    data.get(offset..)
+        .and_then(<[u8]>::first_chunk)
+        .ok_or(Error::InvalidAbiData)
+}
+
+/// Read a dynamic `bytes`/`string` payload whose offset is in `head`.
+#[cfg(feature = "alloc")]
+fn dynamic_at<'a>(data: &'a [u8], head: &[u8; WORD]) -> Result<&'a [u8], Error> {
+    let offset = word_to_usize(head)?;
+    let len = word_to_usize(word_at(data, offset)?)?;
+    let start = offset + WORD;
+    data.get(start..start.checked_add(len).ok_or(Error::InvalidAbiData)?)
+        .ok_or(Error::InvalidAbiData)
+}
+
+/// Interpret a word as an offset or length.
+#[cfg(feature = "alloc")]
+fn word_to_usize(word: &[u8; WORD]) -> Result<usize, Error> {
+    usize::try_from(U256::from_be_bytes(*word)).map_err(|_| Error::InvalidAbiData)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selector() {
+        assert_eq!(
+            selector("transfer(address,uint256)"),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+        assert_eq!(selector("balanceOf(address)"), [0x70, 0xa0, 0x82, 0x31]);
+        assert_eq!(
+            selector("  transfer(address,uint256)\n"),
+            selector("transfer(address,uint256)")
+        );
+    }
+
+    #[test]
+    fn test_event_topic() {
+        assert_eq!(
+            hex::encode(event_topic("Transfer(address,address,uint256)")),
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+        assert_eq!(
+            hex::encode(event_topic("Approval(address,address,uint256)")),
+            "8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_topic_address() {
+        let address = Address::from_bytes([0xab; 20]);
+        let topic = encode_topic(&AbiValue::Address(address));
+        assert_eq!(&topic[..12], &[0u8; 12]);
+        assert_eq!(&topic[12..], address.as_bytes());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_topic_string() {
+        assert_eq!(
+            encode_topic(&AbiValue::String("hello")),
+            keccak256(b"hello")
+        );
+        assert_eq!(
+            hex::encode(encode_topic(&AbiValue::String(""))),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            encode_topic(&AbiValue::Bytes(b"hello")),
+            keccak256(b"hello")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_topic_integers() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(encode_topic(&AbiValue::Uint(U256::from(1u8))), one);
+        assert_eq!(encode_topic(&AbiValue::Bool(true)), one);
+        assert_eq!(encode_topic(&AbiValue::Int(I256::MINUS_ONE)), [0xff; 32]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode_uint256() {
+        let data = hex::decode("00000000000000000000000000000000000000000000000000000000000f4240")
+            .unwrap();
+        assert_eq!(
+            decode(&[AbiType::Uint], &data).unwrap(),
+            [AbiValue::Uint(U256::from(1_000_000u64))]
+        );
+        // `allowance()` after an unlimited approval.
+        assert_eq!(
+            decode(&[AbiType::Uint], &[0xff; 32]).unwrap(),
+            [AbiValue::Uint(U256::MAX)]
+        );
+        assert_eq!(
+            decode(&[AbiType::Int], &[0xff; 32]).unwrap(),
+            [AbiValue::Int(I256::MINUS_ONE)]
+        );
+        let mut min = [0u8; 32];
+        min[0] = 0x80;
+        assert_eq!(
+            decode(&[AbiType::Int], &min).unwrap(),
+            [AbiValue::Int(I256::MIN)]
+        );
+        assert_eq!(
+            decode(&[AbiType::Bool], &[0xff; 32]),
+            Err(Error::InvalidAbiData)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode_address() {
+        // `owner()` returning the ENS registry address.
+        let data = hex::decode("00000000000000000000000000000000000c2e074ec69a0dfb2997ba6c7d2e1e")
+            .unwrap();
+        let expected = Address::parse("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e").unwrap();
+        assert_eq!(
+            decode(&[AbiType::Address], &data).unwrap(),
+            [AbiValue::Address(expected)]
+        );
+
+        let mut dirty = data;
+        dirty[0] = 1;
+        assert_eq!(
+            decode(&[AbiType::Address], &dirty),
+            Err(Error::InvalidAbiData)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode_string() {
+        // `name()` returning "Dai Stablecoin".
+        let data = hex::decode(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "000000000000000000000000000000000000000000000000000000000000000e",
+            "44616920537461626c65636f696e000000000000000000000000000000000000",
+        ))
+        .unwrap();
+        assert_eq!(
+            decode(&[AbiType::String], &data).unwrap(),
+            [AbiValue::String("Dai Stablecoin")]
+        );
+        assert_eq!(
+            decode(&[AbiType::Bytes], &data).unwrap(),
+            [AbiValue::Bytes(b"Dai Stablecoin")]
+        );
+        assert_eq!(
+            decode(&[AbiType::String], &data[..70]),
+            Err(Error::InvalidAbiData)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode_mixed() {
+        let data = hex::decode(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "abcd000000000000000000000000000000000000000000000000000000000000",
+        ))
+        .unwrap();
+        assert_eq!(
+            decode(&[AbiType::Bool, AbiType::Bytes], &data).unwrap(),
+            [AbiValue::Bool(true), AbiValue::Bytes(&[0xab, 0xcd])]
+        );
+    }
+}
@@ -1,12 +1,141 @@
-//! Utility functions for Ethereum wallet operations.
+//! Ethereum address type and utility functions.
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+use core::fmt;
 
-use alloy_primitives::{Address, keccak256};
+use alloy_primitives::{Address as AlloyAddress, keccak256};
+
+use crate::{Error, Network, PublicKey, Signature};
+
+/// A 20-byte Ethereum address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address {
+    /// Underlying `alloy` address.
+    inner: AlloyAddress,
+}
+
+impl Address {
+    /// Create an address from its raw 20 bytes.
+    #[inline]
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        Self {
+            inner: AlloyAddress::new(bytes),
+        }
+    }
+
+    /// Derive the address of a public key.
+    #[inline]
+    #[must_use]
+    pub fn from_public_key(public_key: &PublicKey) -> Self {
+        public_key.to_address()
+    }
+
+    /// Recover the signer's address from an EIP-191 personal message signature.
+    ///
+    /// Equivalent to `PublicKey::recover_from_message(..)?.to_address()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid or recovery fails.
+    pub fn recover_from_message(message: &[u8], signature: &Signature) -> Result<Self, Error> {
+        PublicKey::recover_from_message(message, signature).map(|pk| pk.to_address())
+    }
+
+    /// Recover the signer's address from a signature over a 32-byte hash.
+    ///
+    /// This is the `ecrecover` operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid or recovery fails.
+    pub fn recover_from_prehash(hash: &[u8; 32], signature: &Signature) -> Result<Self, Error> {
+        PublicKey::recover_from_prehash(hash, signature).map(|pk| pk.to_address())
+    }
+
+    /// Parse a `0x`-prefixed hex address.
+    ///
+    /// All-lowercase and all-uppercase addresses are accepted as-is; mixed-case
+    /// addresses must carry a valid EIP-55 checksum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAddress`] if the string is malformed or the
+    /// checksum does not match.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let digits = s.strip_prefix("0x").ok_or(Error::InvalidAddress)?;
+        let mut bytes = [0u8; 20];
+        hex::decode_to_slice(digits, &mut bytes).map_err(|_| Error::InvalidAddress)?;
+        let address = Self::from_bytes(bytes);
+
+        let has_lower = digits.bytes().any(|b| b.is_ascii_lowercase());
+        let has_upper = digits.bytes().any(|b| b.is_ascii_uppercase());
+        if has_lower && has_upper && address.to_checksum() != s {
+            return Err(Error::InvalidAddress);
+        }
+        Ok(address)
+    }
+
+    /// Check whether `s` is a valid address on `network`.
+    ///
+    /// Ethereum addresses are the same on every EVM chain, so only the
+    /// format and, for mixed-case input, the EIP-55 checksum are checked.
+    /// The network is taken so that cross-chain callers can validate a
+    /// "send to" field the same way for every chain.
+    #[inline]
+    #[must_use]
+    pub fn is_valid_for(s: &str, _network: Network) -> bool {
+        Self::parse(s).is_ok()
+    }
+
+    /// Get the raw 20 address bytes.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 20] {
+        &self.inner.0.0
+    }
+
+    /// Get the checksummed address string (EIP-55).
+    #[inline]
+    #[must_use]
+    pub fn to_checksum(&self) -> String {
+        to_checksum_address(&self.inner)
+    }
+
+    /// Get the all-lowercase `0x`-prefixed address string, without checksum.
+    #[inline]
+    #[must_use]
+    pub fn to_lowercase(&self) -> String {
+        let mut out = String::with_capacity(42);
+        out.push_str("0x");
+        out.push_str(&hex::encode(self.as_bytes()));
+        out
+    }
+
+    /// Get the underlying `alloy` address.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &AlloyAddress {
+        &self.inner
+    }
+}
+
+impl fmt::Display for Address {
+    /// Formats the address in its EIP-55 checksummed form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_checksum())
+    }
+}
+
+impl From<AlloyAddress> for Address {
+    fn from(inner: AlloyAddress) -> Self {
+        Self { inner }
+    }
+}
 
 /// Convert address to checksummed format (EIP-55).
-pub fn to_checksum_address(address: &Address) -> String {
+pub fn to_checksum_address(address: &AlloyAddress) -> String {
     let addr_hex = hex::encode::<&[u8]>(address.as_slice());
     let hash = keccak256(addr_hex.as_bytes());
 
@@ -30,7 +159,7 @@ pub fn to_checksum_address(address: &Address) -> String {
 }
 
 /// Convert public key bytes to Ethereum address.
-pub fn public_key_to_address(public_key_bytes: &[u8]) -> Address {
+pub fn public_key_to_address(public_key_bytes: &[u8]) -> AlloyAddress {
     // Skip the first byte (0x04 prefix for uncompressed key) if present
     let key_bytes = if public_key_bytes.len() == 65 && public_key_bytes[0] == 0x04 {
         &public_key_bytes[1..]
@@ -39,12 +168,55 @@ pub fn public_key_to_address(public_key_bytes: &[u8]) -> Address {
     };
 
     let hash = keccak256(key_bytes);
-    Address::from_slice(&hash[12..])
+    AlloyAddress::from_slice(&hash[12..])
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use crate::fixtures::{SOME_DATA, SOME_DATA_SIGNER, some_data_signature};
+
+    #[test]
+    fn test_parse() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let address = Address::parse(checksummed).unwrap();
+        assert_eq!(address.to_checksum(), checksummed);
+        assert_eq!(
+            Address::parse(&checksummed.to_lowercase()).unwrap(),
+            address
+        );
+
+        // Bad checksum, missing prefix, wrong length.
+        assert!(Address::parse("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+        assert!(Address::parse("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+        assert!(Address::parse("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA").is_err());
+    }
+
+    #[test]
+    fn test_display_is_checksummed() {
+        let address = Address::parse("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359").unwrap();
+        assert_eq!(
+            format!("{address}"),
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+        );
+        assert_eq!(
+            address.to_lowercase(),
+            "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359"
+        );
+    }
+
+    #[test]
+    fn test_is_valid_for() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        for network in [Network::Ethereum, Network::Polygon, Network::Bsc] {
+            assert!(Address::is_valid_for(checksummed, network));
+            assert!(!Address::is_valid_for(
+                "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+                network
+            ));
+        }
+    }
 
     // EIP-55 official test vectors
     #[test]
@@ -88,7 +260,7 @@ fn test_checksum_address_eip55_vectors() {
         ];
 
         for (input, expected) in test_cases {
-            let addr = Address::from_slice(&hex::decode(input).unwrap());
+            let addr = AlloyAddress::from_slice(&hex::decode(input).unwrap());
             let checksummed = to_checksum_address(&addr);
             assert_eq!(checksummed, expected, "Failed for input: {input}");
         }
@@ -126,8 +298,32 @@ fn test_public_key_to_address_no_prefix() {
     // Test zero address
     #[test]
     fn test_checksum_zero_address() {
-        let addr = Address::ZERO;
+        let addr = AlloyAddress::ZERO;
         let checksummed = to_checksum_address(&addr);
         assert_eq!(checksummed, "0x0000000000000000000000000000000000000000");
     }
+
+    #[test]
+    fn test_recover_from_message() {
+        let signature = some_data_signature();
+        let address = Address::recover_from_message(SOME_DATA, &signature).unwrap();
+        let public_key = PublicKey::recover_from_message(SOME_DATA, &signature).unwrap();
+
+        assert_eq!(address, public_key.to_address());
+        assert_eq!(address.to_checksum(), SOME_DATA_SIGNER);
+    }
+
+    #[test]
+    fn test_recover_from_prehash() {
+        let signature = some_data_signature();
+        let hash = alloy_primitives::eip191_hash_message(SOME_DATA).0;
+
+        let address = Address::recover_from_prehash(&hash, &signature).unwrap();
+        let public_key = PublicKey::recover_from_prehash(&hash, &signature).unwrap();
+        assert_eq!(address, public_key.to_address());
+        assert_eq!(
+            address,
+            Address::recover_from_message(SOME_DATA, &signature).unwrap()
+        );
+    }
 }
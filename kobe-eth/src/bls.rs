@@ -0,0 +1,280 @@
+//! EIP-2333 / EIP-2334 BLS12-381 key derivation.
+//!
+//! Ethereum validator keys live on BLS12-381 and use their own derivation
+//! tree: each child secret key is derived from its parent through a pair
+//! of Lamport keys and HKDF-SHA256, so every level is effectively hardened.
+//! EIP-2334 fixes the paths, `m/12381/3600/i/0` for the withdrawal key and
+//! `m/12381/3600/i/0/0` for the signing key of validator `i`.
+//!
+//! This module only derives secret keys; it does not compute BLS public
+//! keys or signatures.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crypto_bigint::{Encoding, NonZero, U384};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::Error;
+
+/// EIP-2334 purpose level for BLS12-381 keys.
+pub const PURPOSE: u32 = 12381;
+
+/// EIP-2334 coin type for Ethereum.
+pub const COIN_TYPE: u32 = 3600;
+
+/// Order `r` of the BLS12-381 scalar field, zero-extended to 384 bits.
+const CURVE_ORDER: U384 = U384::from_be_hex(concat!(
+    "00000000000000000000000000000000",
+    "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+));
+
+/// Initial HKDF salt of `HKDF_mod_r`.
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+/// Output length `L` of `HKDF_mod_r`, `ceil(3 * ceil(log2(r)) / 16)`.
+const KEYGEN_OKM_LEN: usize = 48;
+
+/// HKDF-Expand info of `HKDF_mod_r`: empty `key_info || I2OSP(L, 2)`.
+const KEYGEN_INFO: [u8; 2] = [0, 48];
+
+/// Number of 32-byte chunks in a Lamport secret key.
+const LAMPORT_CHUNKS: u8 = 255;
+
+/// HMAC-SHA256.
+type HmacSha256 = Hmac<Sha256>;
+
+/// A BLS12-381 secret key derived per EIP-2333.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct BlsPrivateKey {
+    /// Big-endian scalar in `[1, r)`.
+    bytes: [u8; 32],
+}
+
+impl BlsPrivateKey {
+    /// Derive the master secret key from a seed (`derive_master_SK`).
+    ///
+    /// The seed is usually the 64-byte BIP-39 seed of the validator mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `seed` is shorter than 32 bytes.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        if seed.len() < 32 {
+            return Err(Error::Derivation(
+                "seed must be at least 32 bytes".to_string(),
+            ));
+        }
+        Ok(Self {
+            bytes: hkdf_mod_r(seed),
+        })
+    }
+
+    /// Derive the child secret key at `index` (`derive_child_SK`).
+    #[must_use]
+    pub fn derive_child(&self, index: u32) -> Self {
+        Self {
+            bytes: hkdf_mod_r(&lamport_public_key(&self.bytes, index)),
+        }
+    }
+
+    /// Derive a descendant along an EIP-2334 path such as `m/12381/3600/0/0`.
+    ///
+    /// Indices are plain decimals: EIP-2333 derivation is always hardened,
+    /// so the `'` marker is not used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path does not start with `m` or an index is
+    /// not a `u32`.
+    pub fn derive_path(&self, path: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidPath(path.to_string());
+        let mut segments = path.split('/');
+        if segments.next() != Some("m") {
+            return Err(invalid());
+        }
+        segments.try_fold(self.clone(), |key, segment| {
+            let index = segment.parse::<u32>().map_err(|_| invalid())?;
+            Ok(key.derive_child(index))
+        })
+    }
+
+    /// Derive the withdrawal key of validator `index` (`m/12381/3600/i/0`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `seed` is shorter than 32 bytes.
+    pub fn withdrawal_key(seed: &[u8], index: u32) -> Result<Self, Error> {
+        Ok(Self::from_seed(seed)?
+            .derive_child(PURPOSE)
+            .derive_child(COIN_TYPE)
+            .derive_child(index)
+            .derive_child(0))
+    }
+
+    /// Derive the signing key of validator `index` (`m/12381/3600/i/0/0`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `seed` is shorter than 32 bytes.
+    pub fn signing_key(seed: &[u8], index: u32) -> Result<Self, Error> {
+        Ok(Self::withdrawal_key(seed, index)?.derive_child(0))
+    }
+
+    /// Get the big-endian secret scalar.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.bytes
+    }
+
+    /// Get the secret scalar as lowercase hex, without `0x` prefix.
+    #[inline]
+    #[must_use]
+    pub fn to_hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(self.bytes))
+    }
+}
+
+impl fmt::Debug for BlsPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlsPrivateKey")
+            .field("bytes", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Compute `HMAC-SHA256(key, parts[0] || parts[1] || ...)`.
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    #[allow(clippy::expect_used)]
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// `HKDF_mod_r(IKM)` with an empty `key_info`: map keying material to a
+/// non-zero scalar modulo `r`.
+fn hkdf_mod_r(ikm: &[u8]) -> [u8; 32] {
+    let modulus = NonZero::from_uint(CURVE_ORDER);
+    let mut salt: [u8; 32] = Sha256::digest(KEYGEN_SALT).into();
+    loop {
+        let prk = Zeroizing::new(hmac_sha256(&salt, &[ikm, &[0]]));
+        // HKDF-Expand to 48 bytes: T(1) || T(2)[..16].
+        let t1 = Zeroizing::new(hmac_sha256(prk.as_slice(), &[&KEYGEN_INFO, &[1]]));
+        let t2 = Zeroizing::new(hmac_sha256(
+            prk.as_slice(),
+            &[t1.as_slice(), &KEYGEN_INFO, &[2]],
+        ));
+        let mut okm = Zeroizing::new([0u8; KEYGEN_OKM_LEN]);
+        okm[..32].copy_from_slice(t1.as_slice());
+        okm[32..].copy_from_slice(&t2[..16]);
+
+        let scalar = U384::from_be_slice(okm.as_slice()).rem(&modulus);
+        let wide = Zeroizing::new(scalar.to_be_bytes());
+        let mut sk = [0u8; 32];
+        sk.copy_from_slice(&wide[16..]);
+        if sk != [0u8; 32] {
+            return sk;
+        }
+        salt = Sha256::digest(salt).into();
+    }
+}
+
+/// `parent_SK_to_lamport_PK`: compress the Lamport public key derived from
+/// the parent secret and `index`.
+fn lamport_public_key(parent: &[u8; 32], index: u32) -> [u8; 32] {
+    let salt = index.to_be_bytes();
+    let mut flipped = Zeroizing::new(*parent);
+    for byte in flipped.iter_mut() {
+        *byte = !*byte;
+    }
+    let mut compressed = Sha256::new();
+    for ikm in [parent, &*flipped] {
+        // `IKM_to_lamport_SK` expands to exactly 255 HKDF blocks, one per
+        // Lamport chunk, so each block is hashed as soon as it is produced.
+        let prk = Zeroizing::new(hmac_sha256(&salt, &[ikm]));
+        let mut block = Zeroizing::new([0u8; 32]);
+        for counter in 1..=LAMPORT_CHUNKS {
+            let previous: &[u8] = if counter == 1 { &[] } else { block.as_slice() };
+            let next = hmac_sha256(prk.as_slice(), &[previous, &[counter]]);
+            *block = next;
+            compressed.update(Sha256::digest(block.as_slice()));
+        }
+    }
+    compressed.finalize().into()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip2333_vector_0() {
+        let seed = hex::decode(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        )
+        .unwrap();
+        let master = BlsPrivateKey::from_seed(&seed).unwrap();
+        // 6083874454709270928345386274498605044986640685124978867557563392430687146096
+        assert_eq!(
+            master.to_hex().as_str(),
+            "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070"
+        );
+        // 20397789859736650942317412262472558107875392172444076792671091975210932703118
+        assert_eq!(
+            master.derive_child(0).to_hex().as_str(),
+            "2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e"
+        );
+    }
+
+    #[test]
+    fn test_eip2333_vector_1() {
+        let seed = hex::decode("3141592653589793238462643383279502884197169399375105820974944592")
+            .unwrap();
+        let master = BlsPrivateKey::from_seed(&seed).unwrap();
+        // 29757020647961307431480504535336562678282505419141012933316116377660817309383
+        assert_eq!(
+            master.to_hex().as_str(),
+            "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7"
+        );
+        // 25457201688850691947727629385191704516744796114925897962676248250929345014287
+        assert_eq!(
+            master.derive_child(3_141_592_653).to_hex().as_str(),
+            "384843fad5f3d777ea39de3e47a8f999ae91f89e42bffa993d91d9782d152a0f"
+        );
+    }
+
+    #[test]
+    fn test_eip2334_paths() {
+        let seed = [0x42u8; 64];
+        let master = BlsPrivateKey::from_seed(&seed).unwrap();
+        let withdrawal = BlsPrivateKey::withdrawal_key(&seed, 7).unwrap();
+        let signing = BlsPrivateKey::signing_key(&seed, 7).unwrap();
+        assert_eq!(withdrawal, master.derive_path("m/12381/3600/7/0").unwrap());
+        assert_eq!(signing, master.derive_path("m/12381/3600/7/0/0").unwrap());
+        assert_ne!(withdrawal, signing);
+        assert_eq!(master.derive_path("m").unwrap(), master);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(BlsPrivateKey::from_seed(&[0u8; 31]).is_err());
+        let master = BlsPrivateKey::from_seed(&[0u8; 32]).unwrap();
+        assert!(master.derive_path("12381/3600").is_err());
+        assert!(master.derive_path("m/12381'/3600").is_err());
+        assert!(master.derive_path("m/4294967296").is_err());
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let master = BlsPrivateKey::from_seed(&[0u8; 32]).unwrap();
+        let debug = format!("{master:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(master.to_hex().as_str()));
+    }
+}
@@ -153,6 +153,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 impl std::error::Error for ParseDerivationStyleError {}
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -180,6 +181,14 @@ fn test_ledger_legacy_paths() {
         assert_eq!(style.path(10), "m/44'/60'/0'/10");
     }
 
+    #[test]
+    fn test_all_styles_index_3() {
+        let expected = ["m/44'/60'/0'/0/3", "m/44'/60'/3'/0/0", "m/44'/60'/0'/3"];
+        for (style, path) in DerivationStyle::all().iter().zip(expected) {
+            assert_eq!(style.path(3), path, "{style}");
+        }
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(
@@ -12,9 +12,9 @@
 use kobe::Wallet;
 use zeroize::Zeroizing;
 
-use crate::Error;
 use crate::address::{public_key_to_address, to_checksum_address};
 use crate::derivation_style::DerivationStyle;
+use crate::{Address, Error};
 
 /// Ethereum address deriver from a unified wallet seed.
 ///
@@ -40,6 +40,7 @@ pub struct Deriver<'a> {
 
 /// A derived Ethereum address with associated keys.
 #[derive(Debug, Clone)]
+#[allow(clippy::exhaustive_structs)]
 pub struct DerivedAddress {
     /// Derivation path used (e.g., `m/44'/60'/0'/0/0`).
     pub path: String,
@@ -172,6 +173,21 @@ pub fn derive_path(&self, path: &str) -> Result<DerivedAddress, Error> {
         })
     }
 
+    /// Check that the key at `path` owns `expected`.
+    ///
+    /// This is meant for confirming a restored wallet before trusting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` is malformed or derivation fails.
+    pub fn verify_derives_to(&self, path: &str, expected: &Address) -> Result<bool, Error> {
+        let public_key = self
+            .derive_key(path)?
+            .verifying_key()
+            .to_encoded_point(false);
+        Ok(Address::from(public_key_to_address(public_key.as_bytes())) == *expected)
+    }
+
     /// Derive a private key at the given path using bip32 crate.
     fn derive_key(&self, path: &str) -> Result<SigningKey, Error> {
         // Parse derivation path
@@ -189,6 +205,7 @@ fn derive_key(&self, path: &str) -> Result<SigningKey, Error> {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::shadow_unrelated)]
 mod tests {
     use super::*;
 
@@ -209,6 +226,30 @@ fn test_derive_address() {
         assert_eq!(addr.path, "m/44'/60'/0'/0/0");
     }
 
+    #[test]
+    fn test_bip44_vector() {
+        let vector = kobe::testing::BIP44_ETH_VECTOR;
+        let wallet = Wallet::from_mnemonic(vector.mnemonic, None).unwrap();
+        let addr = Deriver::new(&wallet).derive_path(vector.path).unwrap();
+        assert_eq!(addr.address, vector.address);
+    }
+
+    #[test]
+    fn test_verify_derives_to() {
+        let vector = kobe::testing::BIP44_ETH_VECTOR;
+        let wallet = Wallet::from_mnemonic(vector.mnemonic, None).unwrap();
+        let deriver = Deriver::new(&wallet);
+        let expected = Address::parse(vector.address).unwrap();
+
+        assert!(deriver.verify_derives_to(vector.path, &expected).unwrap());
+        assert!(
+            !deriver
+                .verify_derives_to("m/44'/60'/0'/0/1", &expected)
+                .unwrap()
+        );
+        assert!(deriver.verify_derives_to("m/44'/x", &expected).is_err());
+    }
+
     #[test]
     fn test_derive_multiple() {
         let wallet = test_wallet();
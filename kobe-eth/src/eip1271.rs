@@ -0,0 +1,95 @@
+//! EIP-1271 smart-contract signature validation helpers.
+//!
+//! Contract wallets validate signatures through
+//! `isValidSignature(bytes32 hash, bytes signature) returns (bytes4)`,
+//! returning [`MAGIC_VALUE`] when the signature is valid. Performing the
+//! check requires an `eth_call` against a node; this module builds the
+//! calldata and interprets the result.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::U256;
+
+use crate::abi::uint_word;
+
+/// Function selector of `isValidSignature(bytes32,bytes)`.
+pub const IS_VALID_SIGNATURE_SELECTOR: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// Value returned by `isValidSignature` for a valid signature.
+pub const MAGIC_VALUE: [u8; 4] = IS_VALID_SIGNATURE_SELECTOR;
+
+/// ABI-encode a call to `isValidSignature(bytes32,bytes)`.
+///
+/// The result can be used directly as the `data` field of an `eth_call`.
+#[must_use]
+pub fn encode_is_valid_signature_call(hash: &[u8; 32], signature: &[u8]) -> Vec<u8> {
+    let padded_len = signature.len().div_ceil(32) * 32;
+    let mut data = Vec::with_capacity(4 + 32 * 3 + padded_len);
+
+    data.extend_from_slice(&IS_VALID_SIGNATURE_SELECTOR);
+    data.extend_from_slice(hash);
+    // Offset of the dynamic `bytes` argument, relative to the start of the arguments.
+    data.extend_from_slice(&uint_word(U256::from(64u64)));
+    data.extend_from_slice(&uint_word(U256::from(signature.len())));
+    data.extend_from_slice(signature);
+    data.resize(4 + 32 * 3 + padded_len, 0);
+
+    data
+}
+
+/// Check whether `eth_call` return data is the EIP-1271 magic value.
+///
+/// The return value is an ABI-encoded `bytes4`, i.e. the magic value
+/// left-aligned in a 32-byte word.
+#[must_use]
+pub fn is_magic_return(return_data: &[u8]) -> bool {
+    return_data.len() >= 32
+        && return_data[..4] == MAGIC_VALUE
+        && return_data[4..32].iter().all(|&b| b == 0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::abi::selector;
+    use alloy_primitives::keccak256;
+
+    #[test]
+    fn test_selector() {
+        let hash = keccak256("isValidSignature(bytes32,bytes)");
+        assert_eq!(hash[..4], IS_VALID_SIGNATURE_SELECTOR);
+        assert_eq!(
+            selector("isValidSignature(bytes32,bytes)"),
+            IS_VALID_SIGNATURE_SELECTOR
+        );
+    }
+
+    #[test]
+    fn test_encode_call() {
+        let hash = [0x11; 32];
+        let signature = [0xab; 65];
+        let data = encode_is_valid_signature_call(&hash, &signature);
+
+        assert_eq!(data.len(), 4 + 32 * 3 + 96);
+        assert_eq!(data[..4], IS_VALID_SIGNATURE_SELECTOR);
+        assert_eq!(data[4..36], hash);
+        assert_eq!(data[67], 0x40);
+        assert_eq!(data[99], 65);
+        assert_eq!(data[100..165], signature);
+        assert!(data[165..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_is_magic_return() {
+        let mut ret = [0u8; 32];
+        ret[..4].copy_from_slice(&MAGIC_VALUE);
+        assert!(is_magic_return(&ret));
+
+        assert!(!is_magic_return(&MAGIC_VALUE));
+        assert!(!is_magic_return(&[0u8; 32]));
+
+        ret[31] = 1;
+        assert!(!is_magic_return(&ret));
+    }
+}
@@ -0,0 +1,178 @@
+//! Recognition of ERC-20 token calls.
+//!
+//! Wallet UIs label a transaction by the 4-byte selector at the start of
+//! its call data. [`decode_method`] recognizes the state-changing ERC-20
+//! methods and decodes their arguments. Amounts are full 256-bit values,
+//! since unlimited approvals use `2^256 - 1`.
+
+use alloy_primitives::U256;
+
+use crate::Address;
+use crate::abi::{AbiType, AbiValue, decode};
+
+/// Selector of `transfer(address,uint256)`.
+pub const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+/// Selector of `approve(address,uint256)`.
+pub const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+
+/// Selector of `transferFrom(address,address,uint256)`.
+pub const TRANSFER_FROM_SELECTOR: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+
+/// A decoded ERC-20 method call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Erc20Method {
+    /// `transfer(to, amount)`.
+    Transfer {
+        /// Recipient.
+        to: Address,
+        /// Amount in the token's base units.
+        amount: U256,
+    },
+    /// `approve(spender, amount)`.
+    Approve {
+        /// Account allowed to spend the tokens.
+        spender: Address,
+        /// Allowance in the token's base units.
+        amount: U256,
+    },
+    /// `transferFrom(from, to, amount)`.
+    TransferFrom {
+        /// Owner the tokens are taken from.
+        from: Address,
+        /// Recipient.
+        to: Address,
+        /// Amount in the token's base units.
+        amount: U256,
+    },
+}
+
+/// Decode call data as an ERC-20 method call.
+///
+/// Returns `None` for an unknown selector, or if the arguments are not
+/// exactly the canonical ABI encoding for the method.
+#[must_use]
+pub fn decode_method(data: &[u8]) -> Option<Erc20Method> {
+    let (selector, args) = data.split_first_chunk::<4>()?;
+    match *selector {
+        TRANSFER_SELECTOR => {
+            let (to, amount) = address_and_amount(args)?;
+            Some(Erc20Method::Transfer { to, amount })
+        }
+        APPROVE_SELECTOR => {
+            let (spender, amount) = address_and_amount(args)?;
+            Some(Erc20Method::Approve { spender, amount })
+        }
+        TRANSFER_FROM_SELECTOR => {
+            let (owner, rest) = args.split_first_chunk::<32>()?;
+            let from = address(owner)?;
+            let (to, amount) = address_and_amount(rest)?;
+            Some(Erc20Method::TransferFrom { from, to, amount })
+        }
+        _ => None,
+    }
+}
+
+/// Decode exactly two words: an `address` and a `uint256`.
+fn address_and_amount(args: &[u8]) -> Option<(Address, U256)> {
+    let (head, tail) = args.split_first_chunk::<32>()?;
+    let amount: &[u8; 32] = tail.try_into().ok()?;
+    Some((address(head)?, U256::from_be_bytes(*amount)))
+}
+
+/// Decode an `address` word, rejecting non-zero padding.
+fn address(word: &[u8; 32]) -> Option<Address> {
+    match decode(&[AbiType::Address], word).ok()?.as_slice() {
+        [AbiValue::Address(address)] => Some(*address),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::abi::selector;
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(TRANSFER_SELECTOR, selector("transfer(address,uint256)"));
+        assert_eq!(APPROVE_SELECTOR, selector("approve(address,uint256)"));
+        assert_eq!(
+            TRANSFER_FROM_SELECTOR,
+            selector("transferFrom(address,address,uint256)")
+        );
+    }
+
+    #[test]
+    fn test_decode_transfer() {
+        // 25 USDC (6 decimals) to 0x28C6c06298d514Db089934071355E5743bf21d60.
+        let data = hex::decode(concat!(
+            "a9059cbb",
+            "00000000000000000000000028c6c06298d514db089934071355e5743bf21d60",
+            "00000000000000000000000000000000000000000000000000000000017d7840",
+        ))
+        .unwrap();
+        assert_eq!(
+            decode_method(&data),
+            Some(Erc20Method::Transfer {
+                to: Address::parse("0x28C6c06298d514Db089934071355E5743bf21d60").unwrap(),
+                amount: U256::from(25_000_000u64),
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unlimited_approve() {
+        // Unlimited allowance for the Uniswap V2 router.
+        let data = hex::decode(concat!(
+            "095ea7b3",
+            "0000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488d",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ))
+        .unwrap();
+        assert_eq!(
+            decode_method(&data),
+            Some(Erc20Method::Approve {
+                spender: Address::parse("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D").unwrap(),
+                amount: U256::MAX,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_transfer_from() {
+        let mut data = TRANSFER_FROM_SELECTOR.to_vec();
+        data.extend_from_slice(&[0u8; 12]);
+        data.extend_from_slice(&[0x11; 20]);
+        data.extend_from_slice(&[0u8; 12]);
+        data.extend_from_slice(&[0x22; 20]);
+        data.extend_from_slice(&U256::from(7u64).to_be_bytes::<32>());
+        assert_eq!(
+            decode_method(&data),
+            Some(Erc20Method::TransferFrom {
+                from: Address::from_bytes([0x11; 20]),
+                to: Address::from_bytes([0x22; 20]),
+                amount: U256::from(7u64),
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_and_malformed() {
+        assert_eq!(decode_method(&[]), None);
+        assert_eq!(decode_method(&selector("balanceOf(address)")), None);
+
+        let mut data = TRANSFER_SELECTOR.to_vec();
+        data.extend_from_slice(&[0u8; 63]);
+        assert_eq!(decode_method(&data), None);
+        data.extend_from_slice(&[0u8; 2]);
+        assert_eq!(decode_method(&data), None);
+
+        // Dirty address padding.
+        let mut dirty = TRANSFER_SELECTOR.to_vec();
+        dirty.extend_from_slice(&[0xff; 64]);
+        assert_eq!(decode_method(&dirty), None);
+    }
+}
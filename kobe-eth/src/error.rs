@@ -11,11 +11,41 @@
 /// Errors that can occur during Ethereum wallet operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
+// Without `alloc` every other variant carries at most a byte, so
+// `ChainIdMismatch` trips the size lint, and there is no `Box` to shrink it.
+#[cfg_attr(not(feature = "alloc"), allow(variant_size_differences))]
 pub enum Error {
     /// Invalid private key format or value.
     InvalidPrivateKey,
     /// Invalid hex string format.
     InvalidHex,
+    /// Invalid public key.
+    InvalidPublicKey,
+    /// Invalid signature or failed signature recovery.
+    InvalidSignature,
+    /// Invalid address string.
+    InvalidAddress,
+    /// Input has an unsupported length.
+    InvalidLength,
+    /// Malformed payment URI.
+    InvalidUri,
+    /// Malformed or out-of-range ABI-encoded data.
+    InvalidAbiData,
+    /// Malformed raw transaction encoding.
+    InvalidTransaction,
+    /// Raw transaction has an unknown EIP-2718 type byte.
+    UnsupportedTransactionType(u8),
+    /// Incrementing the nonce would overflow `u64`.
+    NonceOverflow,
+    /// Chain id is above the EIP-2294 limit, so `v` cannot encode it.
+    InvalidChainId,
+    /// Transaction chain id does not match the intended network.
+    ChainIdMismatch {
+        /// Chain id of the intended network.
+        expected: u64,
+        /// Chain id embedded in the transaction.
+        actual: u64,
+    },
     /// Key derivation error with details.
     #[cfg(feature = "alloc")]
     Derivation(String),
@@ -29,6 +59,22 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidPrivateKey => write!(f, "invalid private key"),
             Self::InvalidHex => write!(f, "invalid hex string"),
+            Self::InvalidPublicKey => write!(f, "invalid public key"),
+            Self::InvalidSignature => write!(f, "invalid signature"),
+            Self::InvalidAddress => write!(f, "invalid address"),
+            Self::InvalidLength => write!(f, "invalid input length"),
+            Self::InvalidUri => write!(f, "invalid payment URI"),
+            Self::InvalidAbiData => write!(f, "invalid ABI-encoded data"),
+            Self::InvalidTransaction => write!(f, "invalid raw transaction"),
+            Self::UnsupportedTransactionType(tx_type) => {
+                write!(f, "unsupported transaction type: 0x{tx_type:02x}")
+            }
+            Self::NonceOverflow => write!(f, "nonce overflow"),
+            Self::InvalidChainId => write!(f, "chain id out of range"),
+            Self::ChainIdMismatch { expected, actual } => write!(
+                f,
+                "chain id mismatch: transaction has {actual}, network expects {expected}"
+            ),
             #[cfg(feature = "alloc")]
             Self::Derivation(msg) => write!(f, "key derivation error: {msg}"),
             #[cfg(feature = "alloc")]
@@ -0,0 +1,27 @@
+//! Test fixtures shared across modules.
+
+use crate::Signature;
+
+/// Message signed in the web3.js `accounts.sign` documentation.
+pub const SOME_DATA: &[u8] = b"Some data";
+
+/// Address of the key that signed [`SOME_DATA`].
+pub const SOME_DATA_SIGNER: &str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+
+/// The web3.js `personal_sign` signature of [`SOME_DATA`], with raw
+/// recovery id `1`.
+pub fn some_data_signature() -> Signature {
+    Signature::new(
+        bytes32("b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd"),
+        bytes32("6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029"),
+        1,
+    )
+    .unwrap()
+}
+
+/// Decode a 32-byte hex string.
+fn bytes32(hex_str: &str) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    hex::decode_to_slice(hex_str, &mut out).unwrap();
+    out
+}
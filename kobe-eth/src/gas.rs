@@ -0,0 +1,46 @@
+//! Pluggable fee estimation.
+
+/// A source of EIP-1559 fee suggestions.
+///
+/// The crate is offline, so fee strategy is left to the caller: implement
+/// this over a node's `eth_feeHistory`, a fee API, or fixed values, and pass
+/// it to [`Transaction::with_fees_from`](crate::Transaction::with_fees_from).
+pub trait GasOracle {
+    /// Suggest `(max_fee_per_gas, max_priority_fee_per_gas)` in wei.
+    fn suggest_fees(&self) -> (u128, u128);
+}
+
+impl<T: GasOracle + ?Sized> GasOracle for &T {
+    #[inline]
+    fn suggest_fees(&self) -> (u128, u128) {
+        (**self).suggest_fees()
+    }
+}
+
+/// A [`GasOracle`] that always suggests the same fees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticGasOracle {
+    /// Maximum total fee per gas (wei).
+    max_fee_per_gas: u128,
+    /// Maximum priority fee per gas (wei).
+    max_priority_fee_per_gas: u128,
+}
+
+impl StaticGasOracle {
+    /// Create an oracle returning fixed fees.
+    #[inline]
+    #[must_use]
+    pub const fn new(max_fee_per_gas: u128, max_priority_fee_per_gas: u128) -> Self {
+        Self {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        }
+    }
+}
+
+impl GasOracle for StaticGasOracle {
+    #[inline]
+    fn suggest_fees(&self) -> (u128, u128) {
+        (self.max_fee_per_gas, self.max_priority_fee_per_gas)
+    }
+}
@@ -0,0 +1,160 @@
+//! Keccak and SHA-3 hash functions.
+//!
+//! Ethereum uses the original Keccak submission, which pads with `0x01`;
+//! FIPS 202 SHA-3 pads with `0x06`. The two families therefore produce
+//! different digests for the same input and must not be mixed up.
+//! [`HashAlgo`] names the choice explicitly where it is configurable.
+
+use sha3::digest::Digest;
+use sha3::{Keccak256, Keccak512, Sha3_256, Sha3_512};
+
+/// A 256-bit hash function for message signing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum HashAlgo {
+    /// Keccak-256, as used by Ethereum.
+    #[default]
+    Keccak256,
+    /// FIPS 202 SHA3-256.
+    Sha3_256,
+}
+
+impl HashAlgo {
+    /// Hash `data`.
+    #[inline]
+    #[must_use]
+    pub fn digest(self, data: &[u8]) -> [u8; 32] {
+        match self {
+            Self::Keccak256 => keccak256(data),
+            Self::Sha3_256 => sha3_256(data),
+        }
+    }
+
+    /// Hash a message with the EIP-191 personal message prefix,
+    /// `"\x19Ethereum Signed Message:\n" || len(message) || message`.
+    ///
+    /// With [`HashAlgo::Keccak256`] this is the `personal_sign` hash.
+    #[must_use]
+    pub fn message_hash(self, message: &[u8]) -> [u8; 32] {
+        match self {
+            Self::Keccak256 => prefixed_digest::<Keccak256>(message),
+            Self::Sha3_256 => prefixed_digest::<Sha3_256>(message),
+        }
+    }
+}
+
+/// Hash `message` under the EIP-191 personal message prefix with `D`.
+#[allow(clippy::cast_possible_truncation)] // a decimal digit fits in `u8`
+fn prefixed_digest<D: Digest>(message: &[u8]) -> [u8; 32]
+where
+    [u8; 32]: From<sha3::digest::Output<D>>,
+{
+    // Decimal digits of the length, most significant first.
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    let mut len = message.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (len % 10) as u8;
+        len /= 10;
+        if len == 0 {
+            break;
+        }
+    }
+    let mut hasher = D::new();
+    hasher.update(b"\x19Ethereum Signed Message:\n");
+    hasher.update(&digits[start..]);
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Compute the Keccak-256 digest (Ethereum's `keccak256`).
+#[inline]
+#[must_use]
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Compute the Keccak-512 digest.
+#[inline]
+#[must_use]
+pub fn keccak512(data: &[u8]) -> [u8; 64] {
+    Keccak512::digest(data).into()
+}
+
+/// Compute the FIPS 202 SHA3-256 digest.
+#[inline]
+#[must_use]
+pub fn sha3_256(data: &[u8]) -> [u8; 32] {
+    Sha3_256::digest(data).into()
+}
+
+/// Compute the FIPS 202 SHA3-512 digest.
+#[inline]
+#[must_use]
+pub fn sha3_512(data: &[u8]) -> [u8; 64] {
+    Sha3_512::digest(data).into()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_input_vectors() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(sha3_256(b"")),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+        assert_eq!(
+            hex::encode(keccak512(b"")),
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304\
+             c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e"
+        );
+        assert_eq!(
+            hex::encode(sha3_512(b"")),
+            "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a6\
+             15b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26"
+        );
+    }
+
+    #[test]
+    fn test_keccak_differs_from_sha3() {
+        assert_ne!(keccak256(b""), sha3_256(b""));
+        assert_ne!(keccak512(b"abc"), sha3_512(b"abc"));
+    }
+
+    #[test]
+    fn test_hash_algo_message_hash() {
+        let message = b"Some data";
+        let keccak = HashAlgo::Keccak256.message_hash(message);
+        assert_eq!(keccak, alloy_primitives::eip191_hash_message(message).0);
+        assert_ne!(HashAlgo::Sha3_256.message_hash(message), keccak);
+        assert_eq!(HashAlgo::Sha3_256.digest(b"abc"), sha3_256(b"abc"));
+
+        let long = [0x61u8; 1234];
+        assert_eq!(
+            HashAlgo::Keccak256.message_hash(&long),
+            alloy_primitives::eip191_hash_message(long).0
+        );
+    }
+
+    #[test]
+    fn test_sha3_256_abc() {
+        assert_eq!(
+            hex::encode(sha3_256(b"abc")),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+    }
+
+    #[test]
+    fn test_keccak256_matches_alloy() {
+        let data = b"kobe";
+        assert_eq!(keccak256(data), alloy_primitives::keccak256(data).0);
+    }
+}
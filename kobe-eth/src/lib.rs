@@ -7,6 +7,9 @@
 //! - `std` (default): Enable standard library support
 //! - `alloc`: Enable heap allocation without full std (for `no_std` environments)
 //! - `rand`: Enable random key generation for `StandardWallet`
+//! - `macros`: Enable compile-time checked literals such as [`eth_address!`] and [`eth_private_key!`]
+//! - `wasm`: Enable `rand` with the browser RNG backend for `wasm32-unknown-unknown`
+//! - `k256-interop`: Enable conversions between `StandardWallet` and `k256::ecdsa::SigningKey`
 //!
 //! # Usage
 //!
@@ -35,23 +38,74 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod abi;
 #[cfg(feature = "alloc")]
 mod address;
 #[cfg(feature = "alloc")]
+pub mod bls;
+#[cfg(feature = "alloc")]
 mod derivation_style;
 #[cfg(feature = "alloc")]
 mod deriver;
+#[cfg(feature = "alloc")]
+pub mod eip1271;
+#[cfg(feature = "alloc")]
+pub mod erc20;
 mod error;
+#[cfg(all(test, feature = "alloc"))]
+#[allow(clippy::unwrap_used)]
+mod fixtures;
+mod gas;
+pub mod hash;
+mod network;
+#[cfg(feature = "alloc")]
+pub mod permit;
+#[cfg(feature = "alloc")]
+mod public_key;
+#[cfg(feature = "alloc")]
+mod raw_transaction;
+#[cfg(feature = "alloc")]
+mod rlp;
+#[cfg(feature = "alloc")]
+mod signature;
+#[cfg(feature = "alloc")]
+mod signer;
 #[cfg(feature = "alloc")]
 mod standard_wallet;
+#[cfg(feature = "alloc")]
+mod transaction;
+#[cfg(feature = "alloc")]
+pub mod uri;
+#[cfg(feature = "alloc")]
+mod watch_only;
 
+#[cfg(feature = "alloc")]
+pub use address::Address;
 #[cfg(feature = "alloc")]
 pub use derivation_style::{DerivationStyle, ParseDerivationStyleError};
 #[cfg(feature = "alloc")]
 pub use deriver::{DerivedAddress, Deriver};
 pub use error::Error;
+pub use gas::{GasOracle, StaticGasOracle};
+#[cfg(feature = "macros")]
+pub use kobe_macros::{eth_address, eth_private_key};
+pub use network::Network;
+#[cfg(feature = "alloc")]
+pub use public_key::{AddressedPublicKey, PublicKey};
+#[cfg(feature = "alloc")]
+pub use raw_transaction::{EthTransaction, LegacyTransaction};
+#[cfg(feature = "alloc")]
+pub use signature::{EthSignature, MAX_CHAIN_ID, Signature, SignatureLayout};
+#[cfg(feature = "alloc")]
+pub use signer::Signer;
 #[cfg(feature = "alloc")]
 pub use standard_wallet::StandardWallet;
+#[cfg(feature = "alloc")]
+pub use transaction::{
+    AccessListItem, EIP1559_TX_TYPE, SignedTransaction, Transaction, TransactionSummary,
+};
+#[cfg(feature = "alloc")]
+pub use watch_only::WatchOnly;
 
 /// A convenient Result type alias for kobe-eth operations.
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,251 @@
+//! EVM network definitions.
+//!
+//! All EVM-compatible chains share Ethereum's key and address format, so a
+//! single wallet can be used across them. They differ in chain id and in
+//! fee-market features such as EIP-1559.
+
+use core::fmt;
+
+/// Supported EVM networks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Network {
+    /// Ethereum mainnet.
+    #[default]
+    Ethereum,
+    /// Ethereum Sepolia testnet.
+    Sepolia,
+    /// Ethereum Holesky testnet.
+    Holesky,
+    /// Polygon PoS mainnet.
+    Polygon,
+    /// Polygon Amoy testnet.
+    PolygonAmoy,
+    /// Arbitrum One mainnet.
+    Arbitrum,
+    /// Arbitrum Sepolia testnet.
+    ArbitrumSepolia,
+    /// OP Mainnet.
+    Optimism,
+    /// OP Sepolia testnet.
+    OptimismSepolia,
+    /// Base mainnet.
+    Base,
+    /// Base Sepolia testnet.
+    BaseSepolia,
+    /// BNB Smart Chain mainnet.
+    Bsc,
+    /// BNB Smart Chain testnet.
+    BscTestnet,
+    /// Avalanche C-Chain mainnet.
+    Avalanche,
+    /// Avalanche Fuji testnet.
+    AvalancheFuji,
+}
+
+impl Network {
+    /// Get the EIP-155 chain id.
+    #[inline]
+    #[must_use]
+    pub const fn chain_id(self) -> u64 {
+        match self {
+            Self::Ethereum => 1,
+            Self::Sepolia => 11_155_111,
+            Self::Holesky => 17_000,
+            Self::Polygon => 137,
+            Self::PolygonAmoy => 80_002,
+            Self::Arbitrum => 42_161,
+            Self::ArbitrumSepolia => 421_614,
+            Self::Optimism => 10,
+            Self::OptimismSepolia => 11_155_420,
+            Self::Base => 8_453,
+            Self::BaseSepolia => 84_532,
+            Self::Bsc => 56,
+            Self::BscTestnet => 97,
+            Self::Avalanche => 43_114,
+            Self::AvalancheFuji => 43_113,
+        }
+    }
+
+    /// Look up a network by its chain id.
+    #[must_use]
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|network| network.chain_id() == chain_id)
+    }
+
+    /// Get network name as string.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Ethereum => "ethereum",
+            Self::Sepolia => "sepolia",
+            Self::Holesky => "holesky",
+            Self::Polygon => "polygon",
+            Self::PolygonAmoy => "polygon-amoy",
+            Self::Arbitrum => "arbitrum",
+            Self::ArbitrumSepolia => "arbitrum-sepolia",
+            Self::Optimism => "optimism",
+            Self::OptimismSepolia => "optimism-sepolia",
+            Self::Base => "base",
+            Self::BaseSepolia => "base-sepolia",
+            Self::Bsc => "bsc",
+            Self::BscTestnet => "bsc-testnet",
+            Self::Avalanche => "avalanche",
+            Self::AvalancheFuji => "avalanche-fuji",
+        }
+    }
+
+    /// Check whether this is a test network.
+    #[inline]
+    #[must_use]
+    pub const fn is_testnet(self) -> bool {
+        matches!(
+            self,
+            Self::Sepolia
+                | Self::Holesky
+                | Self::PolygonAmoy
+                | Self::ArbitrumSepolia
+                | Self::OptimismSepolia
+                | Self::BaseSepolia
+                | Self::BscTestnet
+                | Self::AvalancheFuji
+        )
+    }
+
+    /// Check whether this network supports EIP-1559 (type 2) fee markets.
+    #[inline]
+    #[must_use]
+    pub const fn supports_eip1559(self) -> bool {
+        !matches!(self, Self::Bsc | Self::BscTestnet)
+    }
+
+    /// Get all supported networks.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::Ethereum,
+            Self::Sepolia,
+            Self::Holesky,
+            Self::Polygon,
+            Self::PolygonAmoy,
+            Self::Arbitrum,
+            Self::ArbitrumSepolia,
+            Self::Optimism,
+            Self::OptimismSepolia,
+            Self::Base,
+            Self::BaseSepolia,
+            Self::Bsc,
+            Self::BscTestnet,
+            Self::Avalanche,
+            Self::AvalancheFuji,
+        ]
+    }
+
+    /// Get all supported mainnets.
+    #[must_use]
+    pub const fn mainnets() -> &'static [Self] {
+        &[
+            Self::Ethereum,
+            Self::Polygon,
+            Self::Arbitrum,
+            Self::Optimism,
+            Self::Base,
+            Self::Bsc,
+            Self::Avalanche,
+        ]
+    }
+
+    /// Get all supported testnets.
+    #[must_use]
+    pub const fn testnets() -> &'static [Self] {
+        &[
+            Self::Sepolia,
+            Self::Holesky,
+            Self::PolygonAmoy,
+            Self::ArbitrumSepolia,
+            Self::OptimismSepolia,
+            Self::BaseSepolia,
+            Self::BscTestnet,
+            Self::AvalancheFuji,
+        ]
+    }
+
+    /// Get all supported networks with EIP-1559 fee markets.
+    #[must_use]
+    pub const fn eip1559_networks() -> &'static [Self] {
+        &[
+            Self::Ethereum,
+            Self::Sepolia,
+            Self::Holesky,
+            Self::Polygon,
+            Self::PolygonAmoy,
+            Self::Arbitrum,
+            Self::ArbitrumSepolia,
+            Self::Optimism,
+            Self::OptimismSepolia,
+            Self::Base,
+            Self::BaseSepolia,
+            Self::Avalanche,
+            Self::AvalancheFuji,
+        ]
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categories_consistent_with_all() {
+        let all = Network::all();
+        assert_eq!(
+            Network::mainnets().len() + Network::testnets().len(),
+            all.len()
+        );
+
+        for network in all {
+            assert_eq!(
+                Network::testnets().contains(network),
+                network.is_testnet(),
+                "{network}"
+            );
+            assert_eq!(
+                Network::mainnets().contains(network),
+                !network.is_testnet(),
+                "{network}"
+            );
+            assert_eq!(
+                Network::eip1559_networks().contains(network),
+                network.supports_eip1559(),
+                "{network}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_eip1559_support() {
+        // Avalanche C-Chain has dynamic fees since Apricot Phase 3; BSC is legacy-only.
+        assert!(Network::Avalanche.supports_eip1559());
+        assert!(Network::AvalancheFuji.supports_eip1559());
+        assert!(!Network::Bsc.supports_eip1559());
+        assert!(!Network::BscTestnet.supports_eip1559());
+    }
+
+    #[test]
+    fn test_chain_ids_unique() {
+        for network in Network::all() {
+            assert_eq!(Network::from_chain_id(network.chain_id()), Some(*network));
+        }
+        assert_eq!(Network::from_chain_id(0), None);
+    }
+}
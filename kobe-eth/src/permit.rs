@@ -0,0 +1,334 @@
+//! EIP-2612 `permit` helpers.
+//!
+//! A permit lets a token holder approve a spender with an off-chain
+//! EIP-712 signature instead of an `approve` transaction. The signature
+//! carries a `deadline` timestamp after which the token contract rejects
+//! it. This module computes the EIP-712 digest to sign and encodes the
+//! resulting `permit(...)` call.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::U256;
+
+use crate::abi::{address_word, uint_word};
+use crate::hash::keccak256;
+use crate::{Address, Error, Signature, Signer};
+
+/// `keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")`.
+pub const EIP712_DOMAIN_TYPEHASH: [u8; 32] = [
+    0x8b, 0x73, 0xc3, 0xc6, 0x9b, 0xb8, 0xfe, 0x3d, 0x51, 0x2e, 0xcc, 0x4c, 0xf7, 0x59, 0xcc, 0x79,
+    0x23, 0x9f, 0x7b, 0x17, 0x9b, 0x0f, 0xfa, 0xca, 0xa9, 0xa7, 0x5d, 0x52, 0x2b, 0x39, 0x40, 0x0f,
+];
+
+/// `keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")`.
+pub const PERMIT_TYPEHASH: [u8; 32] = [
+    0x6e, 0x71, 0xed, 0xae, 0x12, 0xb1, 0xb9, 0x7f, 0x4d, 0x1f, 0x60, 0x37, 0x0f, 0xef, 0x10, 0x10,
+    0x5f, 0xa2, 0xfa, 0xae, 0x01, 0x26, 0x11, 0x4a, 0x16, 0x9c, 0x64, 0x84, 0x5d, 0x61, 0x26, 0xc9,
+];
+
+/// Function selector of `permit(address,address,uint256,uint256,uint8,bytes32,bytes32)`.
+pub const PERMIT_SELECTOR: [u8; 4] = [0xd5, 0x05, 0xac, 0xcf];
+
+/// An EIP-712 domain identifying the token contract a permit is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eip712Domain<'a> {
+    /// Token name, as returned by `name()`.
+    name: &'a str,
+    /// Signing domain version (usually `"1"`).
+    version: &'a str,
+    /// EIP-155 chain ID.
+    chain_id: u64,
+    /// Token contract address.
+    verifying_contract: Address,
+}
+
+impl<'a> Eip712Domain<'a> {
+    /// Create a domain from its four fields.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        name: &'a str,
+        version: &'a str,
+        chain_id: u64,
+        verifying_contract: Address,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            chain_id,
+            verifying_contract,
+        }
+    }
+
+    /// Compute the domain separator, as returned by `DOMAIN_SEPARATOR()`.
+    #[must_use]
+    pub fn separator(&self) -> [u8; 32] {
+        let mut encoded = Vec::with_capacity(32 * 5);
+        encoded.extend_from_slice(&EIP712_DOMAIN_TYPEHASH);
+        encoded.extend_from_slice(&keccak256(self.name.as_bytes()));
+        encoded.extend_from_slice(&keccak256(self.version.as_bytes()));
+        encoded.extend_from_slice(&uint_word(U256::from(self.chain_id)));
+        encoded.extend_from_slice(&address_word(&self.verifying_contract));
+        keccak256(&encoded)
+    }
+}
+
+/// An EIP-2612 permit approving `spender` to move `value` of `owner`'s tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permit {
+    /// Token holder granting the allowance.
+    owner: Address,
+    /// Account receiving the allowance.
+    spender: Address,
+    /// Allowance amount in token base units.
+    value: U256,
+    /// The owner's current `nonces(owner)` value.
+    nonce: U256,
+    /// Unix timestamp after which the permit is rejected.
+    deadline: u64,
+}
+
+impl Permit {
+    /// Create a permit.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        owner: Address,
+        spender: Address,
+        value: U256,
+        nonce: U256,
+        deadline: u64,
+    ) -> Self {
+        Self {
+            owner,
+            spender,
+            value,
+            nonce,
+            deadline,
+        }
+    }
+
+    /// Get the token holder.
+    #[inline]
+    #[must_use]
+    pub const fn owner(&self) -> &Address {
+        &self.owner
+    }
+
+    /// Get the spender.
+    #[inline]
+    #[must_use]
+    pub const fn spender(&self) -> &Address {
+        &self.spender
+    }
+
+    /// Get the allowance amount.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> U256 {
+        self.value
+    }
+
+    /// Get the owner nonce.
+    #[inline]
+    #[must_use]
+    pub const fn nonce(&self) -> U256 {
+        self.nonce
+    }
+
+    /// Get the deadline timestamp.
+    #[inline]
+    #[must_use]
+    pub const fn deadline(&self) -> u64 {
+        self.deadline
+    }
+
+    /// Check whether the permit has expired at Unix time `now`.
+    ///
+    /// Tokens accept a permit while `block.timestamp <= deadline`.
+    #[inline]
+    #[must_use]
+    pub const fn is_expired(&self, now: u64) -> bool {
+        now > self.deadline
+    }
+
+    /// Compute the EIP-712 struct hash of the permit.
+    #[must_use]
+    pub fn struct_hash(&self) -> [u8; 32] {
+        let mut encoded = Vec::with_capacity(32 * 6);
+        encoded.extend_from_slice(&PERMIT_TYPEHASH);
+        encoded.extend_from_slice(&address_word(&self.owner));
+        encoded.extend_from_slice(&address_word(&self.spender));
+        encoded.extend_from_slice(&uint_word(self.value));
+        encoded.extend_from_slice(&uint_word(self.nonce));
+        encoded.extend_from_slice(&uint_word(U256::from(self.deadline)));
+        keccak256(&encoded)
+    }
+
+    /// Compute the digest the owner signs:
+    /// `keccak256(0x1901 || domainSeparator || structHash)`.
+    #[must_use]
+    pub fn digest(&self, domain: &Eip712Domain<'_>) -> [u8; 32] {
+        let mut encoded = [0u8; 66];
+        encoded[..2].copy_from_slice(&[0x19, 0x01]);
+        encoded[2..34].copy_from_slice(&domain.separator());
+        encoded[34..].copy_from_slice(&self.struct_hash());
+        keccak256(&encoded)
+    }
+
+    /// Sign the permit digest for `domain` with `signer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer fails.
+    pub fn sign_with(
+        &self,
+        domain: &Eip712Domain<'_>,
+        signer: &impl Signer,
+    ) -> Result<Signature, Error> {
+        signer.sign_prehash(&self.digest(domain))
+    }
+
+    /// ABI-encode the `permit(owner, spender, value, deadline, v, r, s)` call.
+    ///
+    /// `v` is encoded as `27 + recovery id`, as token contracts expect.
+    #[must_use]
+    pub fn encode_call(&self, signature: &Signature) -> Vec<u8> {
+        let mut data = Vec::with_capacity(4 + 32 * 7);
+        data.extend_from_slice(&PERMIT_SELECTOR);
+        data.extend_from_slice(&address_word(&self.owner));
+        data.extend_from_slice(&address_word(&self.spender));
+        data.extend_from_slice(&uint_word(self.value));
+        data.extend_from_slice(&uint_word(U256::from(self.deadline)));
+        data.extend_from_slice(&uint_word(U256::from(signature.legacy_v())));
+        data.extend_from_slice(signature.r());
+        data.extend_from_slice(signature.s());
+        data
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::StandardWallet;
+    use crate::abi::selector;
+
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn usdc_domain() -> Eip712Domain<'static> {
+        Eip712Domain::new("USD Coin", "2", 1, Address::parse(USDC).unwrap())
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(
+            EIP712_DOMAIN_TYPEHASH,
+            keccak256(
+                b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+            )
+        );
+        assert_eq!(
+            PERMIT_TYPEHASH,
+            keccak256(
+                b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+            )
+        );
+        assert_eq!(
+            PERMIT_SELECTOR,
+            selector("permit(address,address,uint256,uint256,uint8,bytes32,bytes32)")
+        );
+    }
+
+    #[test]
+    fn test_eip712_spec_domain_separator() {
+        // The `Mail` example domain from the EIP-712 specification.
+        let domain = Eip712Domain::new(
+            "Ether Mail",
+            "1",
+            1,
+            Address::parse("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap(),
+        );
+        assert_eq!(
+            hex::encode(domain.separator()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+    }
+
+    #[test]
+    fn test_usdc_domain_separator() {
+        // `DOMAIN_SEPARATOR()` of USDC on Ethereum mainnet.
+        assert_eq!(
+            hex::encode(usdc_domain().separator()),
+            "06c37168a7db5138defc7866392bb87a741f9b3d104deb5094588ce041cae335"
+        );
+    }
+
+    #[test]
+    fn test_permit_digest_and_signature() {
+        let wallet = StandardWallet::from_hex(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        let owner = Address::parse(&wallet.address()).unwrap();
+        let spender = Address::from_bytes([0x22; 20]);
+        let permit = Permit::new(
+            owner,
+            spender,
+            U256::from(1_000_000u64),
+            U256::ZERO,
+            1_700_000_000,
+        );
+        let domain = usdc_domain();
+
+        let digest = permit.digest(&domain);
+        assert_eq!(
+            hex::encode(digest),
+            "546b31d1d0311cec88c459fb7e9dd30a2f4fde906225d4bec98ad1ef10aaa58e"
+        );
+
+        let signature = permit.sign_with(&domain, &wallet).unwrap();
+        assert_eq!(
+            Address::recover_from_prehash(&digest, &signature).unwrap(),
+            owner
+        );
+
+        let call = permit.encode_call(&signature);
+        assert_eq!(call.len(), 4 + 32 * 7);
+        assert_eq!(call[..4], PERMIT_SELECTOR);
+        assert_eq!(&call[16..36], owner.as_bytes());
+        assert_eq!(call[4 + 32 * 4 + 31], signature.legacy_v());
+        assert_eq!(&call[4 + 32 * 5..4 + 32 * 6], signature.r());
+    }
+
+    #[test]
+    fn test_unlimited_permit() {
+        let permit = Permit::new(
+            Address::parse("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F").unwrap(),
+            Address::from_bytes([0x22; 20]),
+            U256::MAX,
+            U256::from(3u64),
+            1_700_000_000,
+        );
+        assert_eq!(
+            hex::encode(permit.digest(&usdc_domain())),
+            "0f6943c8f05ae99ebd5941f2f2456a0ab69894540b9d8130b659b3f22493e811"
+        );
+
+        let call = permit.encode_call(&Signature::new([1; 32], [2; 32], 0).unwrap());
+        assert_eq!(call[4 + 32 * 2..4 + 32 * 3], [0xff; 32]);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let permit = Permit::new(
+            Address::from_bytes([1; 20]),
+            Address::from_bytes([2; 20]),
+            U256::from(1u64),
+            U256::ZERO,
+            100,
+        );
+        assert!(!permit.is_expired(100));
+        assert!(permit.is_expired(101));
+    }
+}
@@ -0,0 +1,185 @@
+//! Ethereum public key type.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use alloy_primitives::eip191_hash_message;
+use k256::ecdsa::VerifyingKey;
+
+use crate::address::public_key_to_address;
+use crate::{Address, Error, Signature};
+
+/// A secp256k1 public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    /// Underlying `k256` verifying key.
+    inner: VerifyingKey,
+}
+
+impl PublicKey {
+    /// Parse a public key from SEC1 bytes (compressed or uncompressed).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid public key.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let inner = VerifyingKey::from_sec1_bytes(bytes).map_err(|_| Error::InvalidPublicKey)?;
+        Ok(Self { inner })
+    }
+
+    /// Recover the signer's public key from an EIP-191 personal message signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid or recovery fails.
+    pub fn recover_from_message(message: &[u8], signature: &Signature) -> Result<Self, Error> {
+        Self::recover_from_prehash(&eip191_hash_message(message).0, signature)
+    }
+
+    /// Recover the signer's public key from a signature over a 32-byte hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid or recovery fails.
+    pub fn recover_from_prehash(hash: &[u8; 32], signature: &Signature) -> Result<Self, Error> {
+        let (sig, recovery_id) = signature.to_k256()?;
+        let inner = VerifyingKey::recover_from_prehash(hash, &sig, recovery_id)
+            .map_err(|_| Error::InvalidSignature)?;
+        Ok(Self { inner })
+    }
+
+    /// Get the Ethereum address of this public key.
+    #[inline]
+    #[must_use]
+    pub fn to_address(&self) -> Address {
+        Address::from(public_key_to_address(&self.to_uncompressed()))
+    }
+
+    /// Get the 65-byte uncompressed SEC1 encoding (`0x04 || x || y`).
+    #[must_use]
+    pub fn to_uncompressed(&self) -> [u8; 65] {
+        let point = self.inner.to_encoded_point(false);
+        let mut bytes = [0u8; 65];
+        bytes.copy_from_slice(point.as_bytes());
+        bytes
+    }
+
+    /// Get the big-endian affine `(x, y)` coordinates of the point.
+    #[must_use]
+    pub fn coordinates(&self) -> ([u8; 32], [u8; 32]) {
+        let uncompressed = self.to_uncompressed();
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x.copy_from_slice(&uncompressed[1..33]);
+        y.copy_from_slice(&uncompressed[33..]);
+        (x, y)
+    }
+
+    /// Get the public key in uncompressed hex format without 0x prefix.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_uncompressed())
+    }
+
+    /// Get the underlying `k256` verifying key.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &VerifyingKey {
+        &self.inner
+    }
+}
+
+impl From<VerifyingKey> for PublicKey {
+    fn from(inner: VerifyingKey) -> Self {
+        Self { inner }
+    }
+}
+
+/// A public key together with its Ethereum address.
+///
+/// [`PublicKey::to_address`] hashes the key on every call. This wrapper
+/// hashes it once, in [`Self::new`], so code that shows the address
+/// repeatedly can keep it next to the key. The address is a plain field
+/// rather than a lazily filled cell: the type stays `Copy` and comparable,
+/// at the cost of one `keccak256` up front even if the address is never read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressedPublicKey {
+    /// The public key.
+    key: PublicKey,
+    /// Address of [`Self::key`].
+    address: Address,
+}
+
+impl AddressedPublicKey {
+    /// Pair `key` with its address.
+    #[must_use]
+    pub fn new(key: PublicKey) -> Self {
+        Self {
+            address: key.to_address(),
+            key,
+        }
+    }
+
+    /// Get the public key.
+    #[inline]
+    #[must_use]
+    pub const fn public_key(&self) -> &PublicKey {
+        &self.key
+    }
+
+    /// Get the address without rehashing the key.
+    #[inline]
+    #[must_use]
+    pub const fn address(&self) -> Address {
+        self.address
+    }
+}
+
+impl From<PublicKey> for AddressedPublicKey {
+    #[inline]
+    fn from(key: PublicKey) -> Self {
+        Self::new(key)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    /// Ganache test account #0.
+    const PUBKEY: &str = "04e68acfc0253a10620dff706b0a1b1f1f5833ea3beb3bde2250d5f271f3563606672ebc45e0b7ea2e816ecb70ca03137b1c9476eec63d4632e990020b7b6fba39";
+
+    #[test]
+    fn test_to_address() {
+        let key = PublicKey::from_sec1_bytes(&hex::decode(PUBKEY).unwrap()).unwrap();
+        assert_eq!(
+            key.to_address().to_checksum(),
+            "0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1"
+        );
+    }
+
+    #[test]
+    fn test_addressed_public_key() {
+        let key = PublicKey::from_sec1_bytes(&hex::decode(PUBKEY).unwrap()).unwrap();
+        let addressed = AddressedPublicKey::from(key);
+        assert_eq!(addressed.public_key(), &key);
+        assert_eq!(addressed.address(), key.to_address());
+        assert_eq!(addressed.address(), addressed.address());
+        assert_eq!(
+            addressed.address().to_checksum(),
+            "0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1"
+        );
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let key = PublicKey::from_sec1_bytes(&hex::decode(PUBKEY).unwrap()).unwrap();
+        let (x, y) = key.coordinates();
+        assert_eq!(x, key.to_uncompressed()[1..33]);
+        assert_eq!(y, key.to_uncompressed()[33..]);
+        assert_eq!(hex::encode(x), PUBKEY[2..66]);
+    }
+}
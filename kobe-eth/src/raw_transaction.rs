@@ -0,0 +1,627 @@
+//! Parsing of externally provided raw transactions.
+//!
+//! Relayers and tooling receive transactions as hex-encoded bytes. An
+//! EIP-2718 typed envelope starts with its type byte (below `0x80`); a
+//! legacy transaction is a bare RLP list and so starts at `0xc0` or above.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::keccak256;
+
+use crate::rlp::{
+    Item, decode_exact, decode_list, decode_uint, encode_bytes, encode_list, encode_uint,
+    encode_word,
+};
+use crate::{
+    AccessListItem, Address, EIP1559_TX_TYPE, Error, EthSignature, Signature, SignedTransaction,
+    Signer, Transaction,
+};
+
+/// A pre-EIP-2718 legacy transaction, optionally EIP-155 replay-protected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyTransaction {
+    /// Sender nonce.
+    nonce: u64,
+    /// Gas price (wei).
+    gas_price: u128,
+    /// Gas limit.
+    gas_limit: u64,
+    /// Recipient, or `None` for contract creation.
+    to: Option<Address>,
+    /// Value transferred (wei).
+    value: u128,
+    /// Call data.
+    data: Vec<u8>,
+    /// EIP-155 chain id, or `None` for a pre-EIP-155 transaction.
+    chain_id: Option<u64>,
+    /// Signature with its chain id, or `None` for an unsigned transaction.
+    signature: Option<EthSignature>,
+}
+
+impl LegacyTransaction {
+    /// Create an empty unsigned transaction.
+    ///
+    /// `chain_id` selects the signing scheme: `Some(id)` signs under
+    /// EIP-155 with replay protection, even for `Some(0)`, while `None`
+    /// signs the pre-EIP-155 hash, valid on every chain. All other fields
+    /// start at zero, with no recipient and empty call data.
+    #[inline]
+    #[must_use]
+    pub const fn new(chain_id: Option<u64>) -> Self {
+        Self {
+            nonce: 0,
+            gas_price: 0,
+            gas_limit: 0,
+            to: None,
+            value: 0,
+            data: Vec::new(),
+            chain_id,
+            signature: None,
+        }
+    }
+
+    /// Set the sender nonce.
+    #[inline]
+    #[must_use]
+    pub const fn with_nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Set the gas price (wei).
+    #[inline]
+    #[must_use]
+    pub const fn with_gas_price(mut self, gas_price: u128) -> Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    /// Set the gas limit.
+    #[inline]
+    #[must_use]
+    pub const fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Set the recipient.
+    #[inline]
+    #[must_use]
+    pub const fn with_to(mut self, to: Address) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Set the value transferred (wei).
+    #[inline]
+    #[must_use]
+    pub const fn with_value(mut self, value: u128) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the call data.
+    #[inline]
+    #[must_use]
+    pub fn with_data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    /// Get the sender nonce.
+    #[inline]
+    #[must_use]
+    pub const fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Get the gas price (wei).
+    #[inline]
+    #[must_use]
+    pub const fn gas_price(&self) -> u128 {
+        self.gas_price
+    }
+
+    /// Get the gas limit.
+    #[inline]
+    #[must_use]
+    pub const fn gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Get the recipient, or `None` for contract creation.
+    #[inline]
+    #[must_use]
+    pub const fn to(&self) -> Option<&Address> {
+        self.to.as_ref()
+    }
+
+    /// Get the value transferred (wei).
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> u128 {
+        self.value
+    }
+
+    /// Get the call data.
+    #[inline]
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the EIP-155 chain id, if the transaction is replay-protected.
+    #[inline]
+    #[must_use]
+    pub const fn chain_id(&self) -> Option<u64> {
+        self.chain_id
+    }
+
+    /// Get the signature, if the transaction is signed.
+    #[inline]
+    #[must_use]
+    pub const fn signature(&self) -> Option<&Signature> {
+        match &self.signature {
+            Some(signature) => Some(signature.signature()),
+            None => None,
+        }
+    }
+
+    /// Compute the hash the sender signed.
+    ///
+    /// This is `keccak256(rlp([nonce, gasPrice, gas, to, value, data]))`,
+    /// extended with `[chainId, 0, 0]` under EIP-155.
+    #[must_use]
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut payload = self.fields_rlp();
+        if let Some(chain_id) = self.chain_id {
+            encode_uint(&mut payload, chain_id.into());
+            encode_uint(&mut payload, 0);
+            encode_uint(&mut payload, 0);
+        }
+        let mut preimage = Vec::new();
+        encode_list(&mut preimage, &payload);
+        keccak256(&preimage).0
+    }
+
+    /// Get the encoded `v` of the signature, if the transaction is signed.
+    ///
+    /// This is `27 + recovery id` without a chain id, and
+    /// `chain_id * 2 + 35 + recovery id` under EIP-155.
+    #[must_use]
+    pub fn v(&self) -> Option<u64> {
+        self.signature.as_ref().map(EthSignature::v)
+    }
+
+    /// Sign the transaction with `signer`, replacing any existing signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer fails, and
+    /// [`Error::InvalidChainId`] if the chain id exceeds
+    /// [`MAX_CHAIN_ID`](crate::MAX_CHAIN_ID).
+    pub fn sign_with(&self, signer: &impl Signer) -> Result<Self, Error> {
+        let signature = signer.sign_prehash(&self.signing_hash())?;
+        self.clone().with_signature(signature)
+    }
+
+    /// Attach a signature produced externally over
+    /// [`LegacyTransaction::signing_hash`], replacing any existing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidChainId`] if the chain id exceeds
+    /// [`MAX_CHAIN_ID`](crate::MAX_CHAIN_ID).
+    #[inline]
+    pub fn with_signature(mut self, signature: Signature) -> Result<Self, Error> {
+        self.signature = Some(EthSignature::new(signature, self.chain_id)?);
+        Ok(self)
+    }
+
+    /// Encode as a raw transaction.
+    ///
+    /// A signed transaction appends `v, r, s`. An unsigned EIP-155
+    /// transaction appends `chainId, 0, 0`, matching its signing preimage.
+    #[must_use]
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut payload = self.fields_rlp();
+        if let Some(signature) = &self.signature {
+            encode_uint(&mut payload, signature.v().into());
+            encode_word(&mut payload, signature.signature().r());
+            encode_word(&mut payload, signature.signature().s());
+        } else if let Some(chain_id) = self.chain_id {
+            encode_uint(&mut payload, chain_id.into());
+            encode_uint(&mut payload, 0);
+            encode_uint(&mut payload, 0);
+        }
+        let mut out = Vec::new();
+        encode_list(&mut out, &payload);
+        out
+    }
+
+    /// Recover the sender address from the signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction is unsigned or the signature is
+    /// invalid.
+    pub fn recover_sender(&self) -> Result<Address, Error> {
+        let signature = self.signature().ok_or(Error::InvalidSignature)?;
+        Address::recover_from_prehash(&self.signing_hash(), signature)
+    }
+
+    /// RLP-encode the six unsigned fields, without the list header.
+    fn fields_rlp(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_uint(&mut out, self.nonce.into());
+        encode_uint(&mut out, self.gas_price);
+        encode_uint(&mut out, self.gas_limit.into());
+        encode_bytes(&mut out, self.to.as_ref().map_or(&[], |to| to.as_bytes()));
+        encode_uint(&mut out, self.value);
+        encode_bytes(&mut out, &self.data);
+        out
+    }
+}
+
+/// A transaction decoded from its raw encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EthTransaction {
+    /// A legacy transaction, signed or unsigned.
+    Legacy(LegacyTransaction),
+    /// An unsigned EIP-1559 transaction (`0x02 || rlp([...fields])`).
+    Eip1559(Transaction),
+    /// A signed EIP-1559 transaction.
+    SignedEip1559(SignedTransaction),
+}
+
+impl EthTransaction {
+    /// Parse a raw transaction from hex, with or without `0x` prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidHex`] for malformed hex,
+    /// [`Error::UnsupportedTransactionType`] for an unknown type byte, and
+    /// [`Error::InvalidTransaction`] for malformed RLP or fields.
+    pub fn from_hex(s: &str) -> Result<Self, Error> {
+        let bytes =
+            hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|_| Error::InvalidHex)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a raw transaction from its bytes.
+    ///
+    /// # Errors
+    ///
+    /// See [`EthTransaction::from_hex`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes.split_first() {
+            None => Err(Error::InvalidTransaction),
+            Some((&first, _)) if first >= 0xc0 => decode_legacy(bytes).map(Self::Legacy),
+            Some((&EIP1559_TX_TYPE, payload)) => decode_eip1559(payload),
+            Some((&tx_type, _)) => Err(Error::UnsupportedTransactionType(tx_type)),
+        }
+    }
+
+    /// Get the signature, if the transaction is signed.
+    #[must_use]
+    pub const fn signature(&self) -> Option<&Signature> {
+        match self {
+            Self::Legacy(tx) => tx.signature(),
+            Self::Eip1559(_) => None,
+            Self::SignedEip1559(tx) => Some(tx.signature()),
+        }
+    }
+}
+
+/// Decode the top-level RLP list of a transaction into its items.
+fn decode_fields(bytes: &[u8]) -> Result<Vec<Item<'_>>, Error> {
+    match decode_exact(bytes) {
+        Some(Item::List(payload)) => decode_list(payload).ok_or(Error::InvalidTransaction),
+        _ => Err(Error::InvalidTransaction),
+    }
+}
+
+/// Decode a legacy transaction: 6 unsigned fields, or 9 with `v, r, s`.
+fn decode_legacy(bytes: &[u8]) -> Result<LegacyTransaction, Error> {
+    let fields = decode_fields(bytes)?;
+    let (unsigned, trailer) = match fields.len() {
+        6 => (fields.as_slice(), None),
+        9 => (&fields[..6], Some(&fields[6..])),
+        _ => return Err(Error::InvalidTransaction),
+    };
+    let mut tx = LegacyTransaction {
+        nonce: uint64(&unsigned[0])?,
+        gas_price: uint(&unsigned[1])?,
+        gas_limit: uint64(&unsigned[2])?,
+        to: recipient(&unsigned[3])?,
+        value: uint(&unsigned[4])?,
+        data: bytes_of(&unsigned[5])?.to_vec(),
+        chain_id: None,
+        signature: None,
+    };
+    if let Some([v_item, r_item, s_item]) = trailer {
+        let v = uint64(v_item)?;
+        let r = word(r_item)?;
+        let s = word(s_item)?;
+        if r == [0; 32] && s == [0; 32] {
+            // Unsigned EIP-155 form: `v` carries the chain id.
+            tx.chain_id = Some(v);
+        } else {
+            let signature = EthSignature::from_v(r, s, v)?;
+            tx.chain_id = signature.chain_id();
+            tx.signature = Some(signature);
+        }
+    }
+    Ok(tx)
+}
+
+/// Decode an EIP-1559 payload: 9 unsigned fields, or 12 with `y, r, s`.
+fn decode_eip1559(payload: &[u8]) -> Result<EthTransaction, Error> {
+    let fields = decode_fields(payload)?;
+    if fields.len() != 9 && fields.len() != 12 {
+        return Err(Error::InvalidTransaction);
+    }
+    let mut tx = Transaction::new(uint64(&fields[0])?)
+        .with_nonce(uint64(&fields[1])?)
+        .with_fees(uint(&fields[2])?, uint(&fields[3])?)
+        .with_gas_limit(uint64(&fields[4])?)
+        .with_value(uint(&fields[6])?)
+        .with_data(bytes_of(&fields[7])?)
+        .with_access_list(access_list(&fields[8])?);
+    if let Some(to) = recipient(&fields[5])? {
+        tx = tx.with_to(to);
+    }
+    let [y_parity, r, s] = &fields[9..] else {
+        return Ok(EthTransaction::Eip1559(tx));
+    };
+    let recovery_id = match uint(y_parity)? {
+        0 => 0,
+        1 => 1,
+        _ => return Err(Error::InvalidSignature),
+    };
+    let signature = Signature::new(word(r)?, word(s)?, recovery_id)?;
+    Ok(EthTransaction::SignedEip1559(SignedTransaction::new(
+        tx, signature,
+    )))
+}
+
+/// Expect a byte string item.
+const fn bytes_of<'a>(item: &Item<'a>) -> Result<&'a [u8], Error> {
+    match item {
+        Item::Bytes(bytes) => Ok(bytes),
+        Item::List(_) => Err(Error::InvalidTransaction),
+    }
+}
+
+/// Decode a minimal unsigned integer item.
+fn uint(item: &Item<'_>) -> Result<u128, Error> {
+    decode_uint(bytes_of(item)?).ok_or(Error::InvalidTransaction)
+}
+
+/// Decode a minimal unsigned integer item that must fit in a `u64`.
+fn uint64(item: &Item<'_>) -> Result<u64, Error> {
+    u64::try_from(uint(item)?).map_err(|_| Error::InvalidTransaction)
+}
+
+/// Decode a signature scalar, left-padding it to 32 bytes.
+fn word(item: &Item<'_>) -> Result<[u8; 32], Error> {
+    let bytes = bytes_of(item)?;
+    if bytes.len() > 32 || bytes.first() == Some(&0) {
+        return Err(Error::InvalidTransaction);
+    }
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(bytes);
+    Ok(out)
+}
+
+/// Decode the `to` field: empty for contract creation, else 20 bytes.
+fn recipient(item: &Item<'_>) -> Result<Option<Address>, Error> {
+    match bytes_of(item)? {
+        [] => Ok(None),
+        bytes => {
+            let array: [u8; 20] = bytes.try_into().map_err(|_| Error::InvalidTransaction)?;
+            Ok(Some(Address::from_bytes(array)))
+        }
+    }
+}
+
+/// Decode an access list: `[[address, [storage_key, ...]], ...]`.
+fn access_list(item: &Item<'_>) -> Result<Vec<AccessListItem>, Error> {
+    list_of(item)?
+        .iter()
+        .map(|entry| {
+            let [address_item, keys_item] = list_of(entry)?[..] else {
+                return Err(Error::InvalidTransaction);
+            };
+            let address = recipient(&address_item)?.ok_or(Error::InvalidTransaction)?;
+            let keys = list_of(&keys_item)?
+                .iter()
+                .map(bytes_of)
+                .collect::<Result<Vec<_>, _>>()?;
+            AccessListItem::new(address, &keys).map_err(|_| Error::InvalidTransaction)
+        })
+        .collect()
+}
+
+/// Expect a list item and decode its items.
+fn list_of<'a>(item: &Item<'a>) -> Result<Vec<Item<'a>>, Error> {
+    match item {
+        Item::List(payload) => decode_list(payload).ok_or(Error::InvalidTransaction),
+        Item::Bytes(_) => Err(Error::InvalidTransaction),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::StandardWallet;
+
+    /// Signed transaction from the EIP-155 specification example.
+    const EIP155_TX: &str = "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    /// Private key of the EIP-155 example.
+    const EIP155_KEY: &str = "4646464646464646464646464646464646464646464646464646464646464646";
+
+    fn sample() -> Transaction {
+        Transaction::new(1)
+            .with_nonce(9)
+            .with_gas_limit(21_000)
+            .with_fees(1_000_000_000, 20_000_000_000)
+            .with_to(Address::from_bytes([0x35; 20]))
+            .with_value(1_000_000_000_000_000_000)
+            .with_data([0xde, 0xad])
+    }
+
+    #[test]
+    fn test_legacy_eip155() {
+        let EthTransaction::Legacy(tx) = EthTransaction::from_hex(EIP155_TX).unwrap() else {
+            unreachable!("legacy transaction expected");
+        };
+        assert_eq!(tx.nonce(), 9);
+        assert_eq!(tx.gas_price(), 20_000_000_000);
+        assert_eq!(tx.gas_limit(), 21_000);
+        assert_eq!(tx.to(), Some(&Address::from_bytes([0x35; 20])));
+        assert_eq!(tx.value(), 1_000_000_000_000_000_000);
+        assert!(tx.data().is_empty());
+        assert_eq!(tx.chain_id(), Some(1));
+        assert_eq!(tx.signature().unwrap().v(), 0);
+
+        let wallet = StandardWallet::from_hex(EIP155_KEY).unwrap();
+        assert_eq!(tx.recover_sender().unwrap().to_checksum(), wallet.address());
+    }
+
+    #[test]
+    fn test_legacy_sign_eip155() {
+        let wallet = StandardWallet::from_hex(EIP155_KEY).unwrap();
+        let tx = LegacyTransaction::new(Some(1))
+            .with_nonce(9)
+            .with_gas_price(20_000_000_000)
+            .with_gas_limit(21_000)
+            .with_to(Address::from_bytes([0x35; 20]))
+            .with_value(1_000_000_000_000_000_000);
+        assert_eq!(
+            hex::encode(tx.signing_hash()),
+            "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+        );
+        let signed = tx.sign_with(&wallet).unwrap();
+        assert_eq!(signed.v(), Some(37));
+        let signature = wallet.sign_hash(&tx.signing_hash()).unwrap();
+        assert_eq!(tx.with_signature(signature).unwrap(), signed);
+        assert_eq!(format!("0x{}", hex::encode(signed.to_raw())), EIP155_TX);
+    }
+
+    #[test]
+    fn test_legacy_rejects_unencodable_chain_id() {
+        let wallet = StandardWallet::from_hex(EIP155_KEY).unwrap();
+        let tx = LegacyTransaction::new(Some(u64::MAX));
+        assert_eq!(tx.sign_with(&wallet), Err(Error::InvalidChainId));
+
+        let signature = wallet.sign_hash(&tx.signing_hash()).unwrap();
+        assert_eq!(tx.with_signature(signature), Err(Error::InvalidChainId));
+    }
+
+    #[test]
+    fn test_legacy_pre_eip155_vs_chain_id_zero() {
+        let wallet = StandardWallet::from_hex(EIP155_KEY).unwrap();
+        let unprotected = LegacyTransaction::new(None)
+            .with_nonce(1)
+            .with_gas_price(1_000_000_000)
+            .with_gas_limit(21_000)
+            .with_to(Address::from_bytes([0x35; 20]));
+        let chain_zero = LegacyTransaction {
+            chain_id: Some(0),
+            ..unprotected.clone()
+        };
+        assert_ne!(unprotected.signing_hash(), chain_zero.signing_hash());
+
+        for (tx, v_base) in [(unprotected, 27), (chain_zero, 35)] {
+            let signed = tx.sign_with(&wallet).unwrap();
+            let recovery_id = u64::from(signed.signature().unwrap().v());
+            assert_eq!(signed.v(), Some(v_base + recovery_id));
+
+            let EthTransaction::Legacy(parsed) =
+                EthTransaction::from_bytes(&signed.to_raw()).unwrap()
+            else {
+                unreachable!("legacy transaction expected");
+            };
+            assert_eq!(parsed, signed);
+            assert_eq!(
+                parsed.recover_sender().unwrap().to_checksum(),
+                wallet.address()
+            );
+        }
+    }
+
+    #[test]
+    fn test_signed_eip1559_round_trip() {
+        let wallet = StandardWallet::from_hex(EIP155_KEY).unwrap();
+        let signed = sample().sign_with(&wallet).unwrap();
+        let raw = hex::encode(signed.to_raw());
+
+        let parsed = EthTransaction::from_hex(&raw).unwrap();
+        assert_eq!(parsed, EthTransaction::SignedEip1559(signed.clone()));
+        assert_eq!(parsed.signature(), Some(signed.signature()));
+    }
+
+    #[test]
+    fn test_access_list_round_trip() {
+        let wallet = StandardWallet::from_hex(EIP155_KEY).unwrap();
+        let item = AccessListItem::new(Address::from_bytes([0x11; 20]), &[[0x22; 32]]).unwrap();
+        let signed = sample()
+            .with_access_list(vec![item])
+            .sign_with(&wallet)
+            .unwrap();
+
+        let parsed = EthTransaction::from_bytes(&signed.to_raw()).unwrap();
+        assert_eq!(parsed, EthTransaction::SignedEip1559(signed));
+    }
+
+    #[test]
+    fn test_unsigned_transactions() {
+        let tx = sample();
+        let mut raw = vec![EIP1559_TX_TYPE];
+        encode_list(&mut raw, &tx.fields_rlp());
+        let parsed = EthTransaction::from_bytes(&raw).unwrap();
+        assert_eq!(parsed, EthTransaction::Eip1559(tx));
+        assert_eq!(parsed.signature(), None);
+
+        let legacy = LegacyTransaction {
+            nonce: 1,
+            gas_price: 2,
+            gas_limit: 21_000,
+            to: None,
+            value: 0,
+            data: vec![0x60, 0x80],
+            chain_id: None,
+            signature: None,
+        };
+        let mut raw_legacy = Vec::new();
+        encode_list(&mut raw_legacy, &legacy.fields_rlp());
+        assert_eq!(
+            EthTransaction::from_bytes(&raw_legacy).unwrap(),
+            EthTransaction::Legacy(legacy)
+        );
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(EthTransaction::from_hex("0xzz"), Err(Error::InvalidHex));
+        assert_eq!(EthTransaction::from_hex(""), Err(Error::InvalidTransaction));
+        assert_eq!(
+            EthTransaction::from_hex("0x05c0"),
+            Err(Error::UnsupportedTransactionType(0x05))
+        );
+        // Truncated legacy transaction.
+        assert_eq!(
+            EthTransaction::from_hex(&EIP155_TX[..EIP155_TX.len() - 2]),
+            Err(Error::InvalidTransaction)
+        );
+        // Typed envelope with the wrong number of fields.
+        assert_eq!(
+            EthTransaction::from_hex("0x02c101"),
+            Err(Error::InvalidTransaction)
+        );
+    }
+}
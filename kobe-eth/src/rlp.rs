@@ -0,0 +1,211 @@
+//! Minimal RLP encoding and decoding for transaction serialization.
+
+use alloc::vec::Vec;
+
+/// Append the RLP encoding of a byte string.
+pub fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    match bytes {
+        [byte] if *byte < 0x80 => out.push(*byte),
+        _ => {
+            encode_length(out, bytes.len(), 0x80);
+            out.extend_from_slice(bytes);
+        }
+    }
+}
+
+/// Append the RLP encoding of an unsigned integer (minimal big-endian).
+pub fn encode_uint(out: &mut Vec<u8>, value: u128) {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    encode_bytes(out, &bytes[start..]);
+}
+
+/// Append the RLP encoding of a 32-byte big-endian scalar, such as a
+/// signature's `r` or `s`, as a minimal integer.
+pub fn encode_word(out: &mut Vec<u8>, word: &[u8; 32]) {
+    let start = word.iter().position(|&b| b != 0).unwrap_or(word.len());
+    encode_bytes(out, &word[start..]);
+}
+
+/// Append the RLP encoding of a list whose items are already encoded in `payload`.
+pub fn encode_list(out: &mut Vec<u8>, payload: &[u8]) {
+    encode_length(out, payload.len(), 0xc0);
+    out.extend_from_slice(payload);
+}
+
+/// Append an RLP length prefix with the given `offset` (`0x80` or `0xc0`).
+#[allow(clippy::cast_possible_truncation)] // lengths are bounded by the checks below
+fn encode_length(out: &mut Vec<u8>, len: usize, offset: u8) {
+    if len < 56 {
+        out.push(offset + len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        out.push(offset + 55 + (bytes.len() - start) as u8);
+        out.extend_from_slice(&bytes[start..]);
+    }
+}
+
+/// A decoded RLP item, borrowing from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item<'a> {
+    /// A byte string.
+    Bytes(&'a [u8]),
+    /// A list; holds the encoded payload of its items.
+    List(&'a [u8]),
+}
+
+/// Decode the single canonical RLP item that makes up all of `input`.
+pub fn decode_exact(input: &[u8]) -> Option<Item<'_>> {
+    match decode_item(input)? {
+        (item, []) => Some(item),
+        _ => None,
+    }
+}
+
+/// Decode the items of an RLP list payload.
+pub fn decode_list(mut payload: &[u8]) -> Option<Vec<Item<'_>>> {
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let (item, rest) = decode_item(payload)?;
+        items.push(item);
+        payload = rest;
+    }
+    Some(items)
+}
+
+/// Decode a minimal big-endian unsigned integer of at most 16 bytes.
+pub fn decode_uint(bytes: &[u8]) -> Option<u128> {
+    if bytes.len() > 16 || bytes.first() == Some(&0) {
+        return None;
+    }
+    Some(bytes.iter().fold(0, |acc, &b| (acc << 8) | u128::from(b)))
+}
+
+/// Decode the first item of `input`, returning it and the remaining bytes.
+///
+/// Only canonical encodings are accepted: single bytes below `0x80` must
+/// not be wrapped, and long-form lengths must be minimal and at least 56.
+fn decode_item(input: &[u8]) -> Option<(Item<'_>, &[u8])> {
+    let (&prefix, after_prefix) = input.split_first()?;
+    let (is_list, payload_len, body) = match prefix {
+        0x00..=0x7f => return Some((Item::Bytes(&input[..1]), after_prefix)),
+        0x80..=0xb7 => (false, usize::from(prefix - 0x80), after_prefix),
+        0xb8..=0xbf => {
+            let (len, rest) = decode_long_length(after_prefix, usize::from(prefix - 0xb7))?;
+            (false, len, rest)
+        }
+        0xc0..=0xf7 => (true, usize::from(prefix - 0xc0), after_prefix),
+        0xf8..=0xff => {
+            let (len, rest) = decode_long_length(after_prefix, usize::from(prefix - 0xf7))?;
+            (true, len, rest)
+        }
+    };
+    if body.len() < payload_len {
+        return None;
+    }
+    let (payload, tail) = body.split_at(payload_len);
+    if is_list {
+        return Some((Item::List(payload), tail));
+    }
+    if let [byte] = payload
+        && *byte < 0x80
+    {
+        return None;
+    }
+    Some((Item::Bytes(payload), tail))
+}
+
+/// Read a `len_of_len`-byte big-endian length that must be minimal and >= 56.
+fn decode_long_length(input: &[u8], len_of_len: usize) -> Option<(usize, &[u8])> {
+    if input.len() < len_of_len || len_of_len > size_of::<usize>() {
+        return None;
+    }
+    let (len_bytes, rest) = input.split_at(len_of_len);
+    if len_bytes[0] == 0 {
+        return None;
+    }
+    let len = len_bytes
+        .iter()
+        .fold(0usize, |acc, &b| (acc << 8) | usize::from(b));
+    (len >= 56).then_some((len, rest))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn bytes(input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_bytes(&mut out, input);
+        out
+    }
+
+    fn uint(value: u128) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_uint(&mut out, value);
+        out
+    }
+
+    #[test]
+    fn test_encode_bytes() {
+        assert_eq!(bytes(b"dog"), [0x83, b'd', b'o', b'g']);
+        assert_eq!(bytes(&[]), [0x80]);
+        assert_eq!(bytes(&[0x7f]), [0x7f]);
+
+        let long = bytes(&[b'a'; 56]);
+        assert_eq!(&long[..2], &[0xb8, 56]);
+        assert_eq!(long.len(), 58);
+    }
+
+    #[test]
+    fn test_encode_uint() {
+        assert_eq!(uint(0), [0x80]);
+        assert_eq!(uint(15), [0x0f]);
+        assert_eq!(uint(1024), [0x82, 0x04, 0x00]);
+    }
+
+    #[test]
+    fn test_encode_list() {
+        // ["cat", "dog"]
+        let mut payload = bytes(b"cat");
+        payload.extend(bytes(b"dog"));
+        let mut out = Vec::new();
+        encode_list(&mut out, &payload);
+        assert_eq!(out, [0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']);
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let mut payload = bytes(b"cat");
+        payload.extend(uint(1024));
+        payload.extend(bytes(&[b'a'; 56]));
+        let mut out = Vec::new();
+        encode_list(&mut out, &payload);
+
+        let item = decode_exact(&out);
+        assert!(matches!(item, Some(Item::List(_))));
+        let items = match item {
+            Some(Item::List(list)) => decode_list(list).unwrap(),
+            _ => Vec::new(),
+        };
+        assert_eq!(items[0], Item::Bytes(b"cat"));
+        assert_eq!(items[1], Item::Bytes(&[0x04, 0x00]));
+        assert_eq!(decode_uint(&[0x04, 0x00]), Some(1024));
+        assert_eq!(items[2], Item::Bytes(&[b'a'; 56]));
+    }
+
+    #[test]
+    fn test_decode_rejects_non_canonical() {
+        // Single byte below 0x80 wrapped in a string header.
+        assert_eq!(decode_exact(&[0x81, 0x05]), None);
+        // Long form used for a short string.
+        assert_eq!(decode_exact(&[0xb8, 0x01, 0xff]), None);
+        // Truncated payload and trailing bytes.
+        assert_eq!(decode_exact(&[0x83, b'd', b'o']), None);
+        assert_eq!(decode_exact(&[0x80, 0x80]), None);
+        // Integers with leading zeros.
+        assert_eq!(decode_uint(&[0x00, 0x01]), None);
+    }
+}
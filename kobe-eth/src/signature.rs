@@ -0,0 +1,409 @@
+//! Recoverable ECDSA signature type for Ethereum.
+
+use alloc::string::String;
+
+use k256::ecdsa::{self, RecoveryId};
+
+use crate::Error;
+
+/// Byte order of a 65-byte serialized signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum SignatureLayout {
+    /// `r || s || v`, as returned by `eth_sign` and `personal_sign`.
+    #[default]
+    Rsv,
+    /// `v || r || s`, used by some hardware wallets and contracts.
+    Vrs,
+}
+
+/// A recoverable secp256k1 ECDSA signature.
+///
+/// Holds the `r` and `s` scalars together with the recovery id `v`
+/// (`0` or `1`, the parity of the ephemeral point's y-coordinate). A legacy
+/// `v` of `27`/`28` is stored reduced by `27`. EIP-155 `v` values carry a
+/// chain id and belong in an [`EthSignature`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Signature {
+    /// The `r` scalar (big-endian).
+    r: [u8; 32],
+    /// The `s` scalar (big-endian).
+    s: [u8; 32],
+    /// Recovery id (`0` or `1`).
+    v: u8,
+}
+
+impl Signature {
+    /// Create a signature from its `r`, `s` and recovery id `v` components.
+    ///
+    /// `v` may be a raw recovery id (`0`/`1`) or the legacy `27`/`28` form,
+    /// which is reduced by `27`, so [`Self::v`] returns the raw recovery id
+    /// for either form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSignature`] for any other `v`.
+    pub const fn new(r: [u8; 32], s: [u8; 32], v: u8) -> Result<Self, Error> {
+        let recovery_id = match v {
+            0 | 1 => v,
+            27 | 28 => v - 27,
+            _ => return Err(Error::InvalidSignature),
+        };
+        Ok(Self {
+            r,
+            s,
+            v: recovery_id,
+        })
+    }
+
+    /// Get the `r` scalar.
+    #[inline]
+    #[must_use]
+    pub const fn r(&self) -> &[u8; 32] {
+        &self.r
+    }
+
+    /// Get the `s` scalar.
+    #[inline]
+    #[must_use]
+    pub const fn s(&self) -> &[u8; 32] {
+        &self.s
+    }
+
+    /// Get the recovery id.
+    #[inline]
+    #[must_use]
+    pub const fn v(&self) -> u8 {
+        self.v
+    }
+
+    /// Get the legacy `v` byte, `27 + recovery id`.
+    #[inline]
+    #[must_use]
+    pub const fn legacy_v(&self) -> u8 {
+        // `new` keeps the stored value at `0` or `1`.
+        27 + self.v
+    }
+
+    /// Serialize as 65 bytes `r || s || v`, with `v` as `27 + recovery id`.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..64].copy_from_slice(&self.s);
+        bytes[64] = self.legacy_v();
+        bytes
+    }
+
+    /// Encode as `0x`-prefixed hex of the 65-byte RSV form (see [`Self::to_bytes`]).
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        let mut out = String::from("0x");
+        out.push_str(&hex::encode(self.to_bytes()));
+        out
+    }
+
+    /// Decode a hex signature, with or without a `0x` prefix.
+    ///
+    /// A 64-byte input is `r || s` with the recovery id defaulted to `0`.
+    /// A 65-byte input is read in the given `layout`; `v` may be a raw
+    /// recovery id (`0`/`1`) or the legacy `27`/`28` form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidHex`] if the input is not hex, or
+    /// [`Error::InvalidSignature`] if it has the wrong length or `v` is
+    /// out of range.
+    pub fn from_hex(s: &str, layout: SignatureLayout) -> Result<Self, Error> {
+        let bytes =
+            hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|_| Error::InvalidHex)?;
+        let (v, rs) = match (bytes.len(), layout) {
+            (64, _) => (0, bytes.as_slice()),
+            (65, SignatureLayout::Rsv) => (bytes[64], &bytes[..64]),
+            (65, SignatureLayout::Vrs) => (bytes[0], &bytes[1..]),
+            _ => return Err(Error::InvalidSignature),
+        };
+        Self::from_rs_v(rs, v)
+    }
+
+    /// Decode a raw signature: 64 bytes `r || s` with the recovery id
+    /// defaulted to `0`, or 65 bytes `r || s || v`.
+    ///
+    /// `v` may be a raw recovery id (`0`/`1`) or the legacy `27`/`28` form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if the input is neither 64 nor 65
+    /// bytes long, or [`Error::InvalidSignature`] if `v` is out of range.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes.len() {
+            64 => Self::from_rs_v(bytes, 0),
+            65 => Self::from_rs_v(&bytes[..64], bytes[64]),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// Build from a 64-byte `r || s` slice and a raw or legacy `v`.
+    fn from_rs_v(rs: &[u8], v: u8) -> Result<Self, Error> {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&rs[..32]);
+        s.copy_from_slice(&rs[32..]);
+        Self::new(r, s, v)
+    }
+
+    /// Build from a `k256` signature and recovery id.
+    ///
+    /// Fails if the recovery id has the x-reduced bit set, which Ethereum
+    /// cannot encode.
+    pub(crate) fn from_k256(
+        signature: &ecdsa::Signature,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, Error> {
+        let (r, s) = signature.split_bytes();
+        Self::new(r.into(), s.into(), recovery_id.to_byte())
+    }
+
+    /// Convert to a `k256` signature and recovery id.
+    ///
+    /// Signatures created with a legacy `27`/`28` recover like their `0`/`1`
+    /// forms, since [`Self::new`] has already reduced `v`.
+    pub(crate) fn to_k256(self) -> Result<(ecdsa::Signature, RecoveryId), Error> {
+        let signature =
+            ecdsa::Signature::from_scalars(self.r, self.s).map_err(|_| Error::InvalidSignature)?;
+        let recovery_id = RecoveryId::from_byte(self.v).ok_or(Error::InvalidSignature)?;
+        Ok((signature, recovery_id))
+    }
+}
+
+/// Largest chain id whose EIP-155 `v` fits in a `u64` (EIP-2294).
+pub const MAX_CHAIN_ID: u64 = u64::MAX / 2 - 36;
+
+/// A [`Signature`] together with the EIP-155 chain id it was made for.
+///
+/// [`Signature`] stores only the raw recovery id, so the chain id folded
+/// into a legacy transaction's `v` is lost. `EthSignature` keeps it, so
+/// that `v` can be reconstructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EthSignature {
+    /// Chain-agnostic signature.
+    signature: Signature,
+    /// EIP-155 chain id, or `None` for a pre-EIP-155 signature.
+    chain_id: Option<u64>,
+}
+
+impl EthSignature {
+    /// Attach an EIP-155 chain id (or `None`) to a signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidChainId`] if `chain_id` exceeds
+    /// [`MAX_CHAIN_ID`].
+    pub const fn new(signature: Signature, chain_id: Option<u64>) -> Result<Self, Error> {
+        if let Some(id) = chain_id
+            && id > MAX_CHAIN_ID
+        {
+            return Err(Error::InvalidChainId);
+        }
+        Ok(Self {
+            signature,
+            chain_id,
+        })
+    }
+
+    /// Split an encoded `v` into the recovery id and chain id.
+    ///
+    /// `27`/`28` is a pre-EIP-155 signature; `35` and above is
+    /// `chain_id * 2 + 35 + recovery id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSignature`] if `v` is neither form, and
+    /// [`Error::InvalidChainId`] if the chain id exceeds [`MAX_CHAIN_ID`].
+    pub fn from_v(r: [u8; 32], s: [u8; 32], v: u64) -> Result<Self, Error> {
+        let (chain_id, parity) = match v {
+            27 | 28 => (None, v - 27),
+            35.. => (Some((v - 35) / 2), (v - 35) % 2),
+            _ => return Err(Error::InvalidSignature),
+        };
+        Self::new(Signature::new(r, s, u8::from(parity == 1))?, chain_id)
+    }
+
+    /// Get the chain-agnostic signature.
+    #[inline]
+    #[must_use]
+    pub const fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Get the EIP-155 chain id.
+    #[inline]
+    #[must_use]
+    pub const fn chain_id(&self) -> Option<u64> {
+        self.chain_id
+    }
+
+    /// Get the encoded `v`: `27 + recovery id` without a chain id, and
+    /// `chain_id * 2 + 35 + recovery id` under EIP-155.
+    ///
+    /// [`EthSignature::new`] bounds the chain id and recovery id, so this
+    /// cannot overflow.
+    #[must_use]
+    pub fn v(&self) -> u64 {
+        let recovery_id = u64::from(self.signature.v());
+        match self.chain_id {
+            None => 27 + recovery_id,
+            Some(chain_id) => chain_id * 2 + 35 + recovery_id,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Signature {
+        Signature::new([0x11; 32], [0x22; 32], 1).unwrap()
+    }
+
+    #[test]
+    fn test_hex_round_trip_rsv() {
+        let signature = sample();
+        let encoded = signature.to_hex();
+        assert_eq!(encoded.len(), 2 + 130);
+        assert!(encoded.starts_with("0x"));
+        assert!(encoded.ends_with("1c"));
+        assert_eq!(
+            Signature::from_hex(&encoded, SignatureLayout::Rsv).unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn test_from_hex_vrs() {
+        let encoded = format!("01{}{}", "11".repeat(32), "22".repeat(32));
+        assert_eq!(
+            Signature::from_hex(&encoded, SignatureLayout::Vrs).unwrap(),
+            sample()
+        );
+    }
+
+    #[test]
+    fn test_from_hex_64_bytes() {
+        let encoded = format!("0x{}{}", "11".repeat(32), "22".repeat(32));
+        for layout in [SignatureLayout::Rsv, SignatureLayout::Vrs] {
+            let signature = Signature::from_hex(&encoded, layout).unwrap();
+            assert_eq!(
+                signature,
+                Signature::new([0x11; 32], [0x22; 32], 0).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_hex_rejects_invalid() {
+        let rs = "11".repeat(64);
+        assert_eq!(
+            Signature::from_hex(&format!("{rs}1d"), SignatureLayout::Rsv),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            Signature::from_hex(&rs[..126], SignatureLayout::Rsv),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            Signature::from_hex("0xzz", SignatureLayout::Rsv),
+            Err(Error::InvalidHex)
+        );
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let mut bytes = [0u8; 65];
+        bytes[..32].fill(0x11);
+        bytes[32..64].fill(0x22);
+        bytes[64] = 28;
+        assert_eq!(Signature::from_slice(&bytes).unwrap(), sample());
+        assert_eq!(
+            Signature::from_slice(&bytes[..64]).unwrap(),
+            Signature::new([0x11; 32], [0x22; 32], 0).unwrap()
+        );
+        assert_eq!(
+            Signature::from_slice(&bytes[..63]),
+            Err(Error::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_recover_with_legacy_v() {
+        use crate::PublicKey;
+        use crate::fixtures::{SOME_DATA, some_data_signature};
+
+        let raw = some_data_signature();
+        let (r, s) = (*raw.r(), *raw.s());
+        let legacy = Signature::new(r, s, 28).unwrap();
+        assert_eq!(
+            PublicKey::recover_from_message(SOME_DATA, &legacy).unwrap(),
+            PublicKey::recover_from_message(SOME_DATA, &raw).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_legacy_v_is_normalized() {
+        let legacy = Signature::new([0x11; 32], [0x22; 32], 28).unwrap();
+        assert_eq!(legacy, sample());
+        assert_eq!(legacy.v(), 1);
+        assert_eq!(legacy.to_bytes()[64], 28);
+    }
+
+    #[test]
+    fn test_new_rejects_other_v() {
+        for v in [2, 26, 29, 35, 37, u8::MAX] {
+            assert_eq!(
+                Signature::new([0x11; 32], [0x22; 32], v),
+                Err(Error::InvalidSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn test_eth_signature_eip155_v() {
+        // Polygon: 137 * 2 + 35 + recovery id.
+        let polygon = EthSignature::new(
+            Signature::new([0x11; 32], [0x22; 32], 0).unwrap(),
+            Some(137),
+        )
+        .unwrap();
+        assert_eq!(polygon.v(), 309);
+        assert_eq!(
+            EthSignature::from_v([0x11; 32], [0x22; 32], 309).unwrap(),
+            polygon
+        );
+        assert_eq!(EthSignature::new(sample(), Some(137)).unwrap().v(), 310);
+
+        let legacy = EthSignature::from_v([0x11; 32], [0x22; 32], 28).unwrap();
+        assert_eq!(legacy.chain_id(), None);
+        assert_eq!(*legacy.signature(), sample());
+        assert_eq!(legacy.v(), 28);
+
+        assert_eq!(
+            EthSignature::from_v([0x11; 32], [0x22; 32], 1),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_eth_signature_rejects_unencodable_v() {
+        let max = EthSignature::new(sample(), Some(MAX_CHAIN_ID)).unwrap();
+        assert_eq!(max.v(), u64::MAX - 37);
+        assert_eq!(
+            EthSignature::new(sample(), Some(MAX_CHAIN_ID + 1)),
+            Err(Error::InvalidChainId)
+        );
+        assert_eq!(
+            EthSignature::from_v([0x11; 32], [0x22; 32], u64::MAX),
+            Err(Error::InvalidChainId)
+        );
+    }
+}
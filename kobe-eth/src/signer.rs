@@ -0,0 +1,37 @@
+//! Signer abstraction for external key custody.
+
+use crate::hash::HashAlgo;
+use crate::{Error, Signature};
+
+/// A source of recoverable secp256k1 signatures over 32-byte prehashes.
+///
+/// Transaction assembly only needs a [`Signer`], so keys can live in an HSM
+/// or hardware wallet. [`StandardWallet`](crate::StandardWallet) is the
+/// in-memory implementation.
+pub trait Signer {
+    /// Sign a 32-byte prehash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer refuses or fails to sign.
+    fn sign_prehash(&self, hash: &[u8; 32]) -> Result<Signature, Error>;
+
+    /// Sign an EIP-191 style personal message, hashed with `algo`.
+    ///
+    /// [`HashAlgo::Keccak256`] gives the Ethereum `personal_sign`
+    /// signature; other algorithms serve chains built on standard SHA-3.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer refuses or fails to sign.
+    fn sign_message_with(&self, message: &[u8], algo: HashAlgo) -> Result<Signature, Error> {
+        self.sign_prehash(&algo.message_hash(message))
+    }
+}
+
+impl<T: Signer + ?Sized> Signer for &T {
+    #[inline]
+    fn sign_prehash(&self, hash: &[u8; 32]) -> Result<Signature, Error> {
+        (**self).sign_prehash(hash)
+    }
+}
@@ -5,13 +5,15 @@
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 use alloy_primitives::Address;
 use k256::ecdsa::SigningKey;
 use zeroize::Zeroizing;
 
-use crate::Error;
 use crate::address::{public_key_to_address, to_checksum_address};
+use crate::{Error, PublicKey, Signature, SignedTransaction, Signer, Transaction};
 
 /// A standard Ethereum wallet with a single private key.
 ///
@@ -59,6 +61,9 @@ pub fn generate() -> Result<Self, Error> {
 
     /// Create a wallet from raw 32-byte secret key.
     ///
+    /// The bytes are interpreted as a big-endian scalar, as used by
+    /// secp256k1 and BIP-32. Use [`Self::from_bytes_le`] for little-endian input.
+    ///
     /// # Errors
     ///
     /// Returns an error if the secret key is invalid.
@@ -72,14 +77,25 @@ pub fn generate() -> Result<Self, Error> {
         })
     }
 
+    /// Create a wallet from a raw 32-byte secret key in little-endian order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret key is invalid.
+    pub fn from_bytes_le(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let mut be = Zeroizing::new(*bytes);
+        be.reverse();
+        Self::from_bytes(&be)
+    }
+
     /// Import a wallet from a hex-encoded secret key.
     ///
     /// # Errors
     ///
     /// Returns an error if the hex string is invalid or the secret key is invalid.
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
-        let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-        let bytes = hex::decode(hex_str).map_err(|_| Error::InvalidHex)?;
+        let stripped = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+        let bytes = hex::decode(stripped).map_err(|_| Error::InvalidHex)?;
 
         let private_key = SigningKey::from_slice(&bytes).map_err(|_| Error::InvalidPrivateKey)?;
         let address = Self::derive_address(&private_key);
@@ -90,6 +106,33 @@ pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
         })
     }
 
+    /// Wrap an existing `k256` signing key.
+    ///
+    /// # Note
+    ///
+    /// This function requires the `k256-interop` feature to be enabled.
+    #[cfg(feature = "k256-interop")]
+    #[must_use]
+    pub fn from_signing_key(private_key: SigningKey) -> Self {
+        let address = Self::derive_address(&private_key);
+        Self {
+            private_key,
+            address,
+        }
+    }
+
+    /// Get a copy of the underlying `k256` signing key.
+    ///
+    /// # Note
+    ///
+    /// This function requires the `k256-interop` feature to be enabled.
+    #[cfg(feature = "k256-interop")]
+    #[inline]
+    #[must_use]
+    pub fn to_signing_key(&self) -> SigningKey {
+        self.private_key.clone()
+    }
+
     /// Derive address from private key.
     fn derive_address(private_key: &SigningKey) -> Address {
         let public_key = private_key.verifying_key();
@@ -97,13 +140,22 @@ fn derive_address(private_key: &SigningKey) -> Address {
         public_key_to_address(public_key_bytes.as_bytes())
     }
 
-    /// Get the secret key as raw bytes (zeroized on drop).
+    /// Get the secret key as raw big-endian bytes (zeroized on drop).
     #[inline]
     #[must_use]
     pub fn secret_bytes(&self) -> Zeroizing<[u8; 32]> {
         Zeroizing::new(self.private_key.to_bytes().into())
     }
 
+    /// Get the secret key as raw little-endian bytes (zeroized on drop).
+    #[inline]
+    #[must_use]
+    pub fn to_bytes_le(&self) -> Zeroizing<[u8; 32]> {
+        let mut bytes = self.secret_bytes();
+        bytes.reverse();
+        bytes
+    }
+
     /// Get the secret key in hex format without 0x prefix (zeroized on drop).
     #[inline]
     #[must_use]
@@ -111,6 +163,76 @@ pub fn secret_hex(&self) -> Zeroizing<String> {
         Zeroizing::new(hex::encode(self.private_key.to_bytes()))
     }
 
+    /// Sign a 32-byte hash, producing a recoverable signature.
+    ///
+    /// The hash is signed as given, with no EIP-191 prefix. This is what
+    /// ERC-4337 `UserOperation` hashes and other precomputed digests need.
+    ///
+    /// # Note
+    ///
+    /// This signs whatever it is given. A hash handed over by a dapp may
+    /// be the signing hash of a transaction or a permit, so only sign
+    /// hashes computed locally from data the user has reviewed; prefer
+    /// [`Self::sign_message`] for plain messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> Result<Signature, Error> {
+        let (signature, recovery_id) = self
+            .private_key
+            .sign_prehash_recoverable(hash)
+            .map_err(|_| Error::InvalidSignature)?;
+        Signature::from_k256(&signature, recovery_id)
+    }
+
+    /// Sign a batch of transactions, e.g. a run of sequential nonces.
+    ///
+    /// All transactions are signed with the already-loaded signing key;
+    /// results are returned in input order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first signing error; no partial batch is returned.
+    pub fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+    ) -> Result<Vec<SignedTransaction>, Error> {
+        transactions.iter().map(|tx| tx.sign_with(self)).collect()
+    }
+
+    /// Sign a message using the EIP-191 personal message format (`personal_sign`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub fn sign_message(&self, message: &[u8]) -> Result<Signature, Error> {
+        self.sign_hash(&alloy_primitives::eip191_hash_message(message).0)
+    }
+
+    /// Sign `message` namespaced by an application `domain`.
+    ///
+    /// The signed hash is
+    /// `keccak256(len(domain) || domain || message)`, with the domain length
+    /// as an 8-byte big-endian integer so that no two (domain, message)
+    /// pairs share an encoding. A signature made for one domain therefore
+    /// cannot be replayed under another, nor as an EIP-191 message or a
+    /// transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub fn sign_with_domain(&self, domain: &[u8], message: &[u8]) -> Result<Signature, Error> {
+        self.sign_hash(&domain_hash(domain, message))
+    }
+
+    /// Get the public key.
+    #[inline]
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(*self.private_key.verifying_key())
+    }
+
     /// Get the public key in uncompressed hex format without 0x prefix.
     #[inline]
     #[must_use]
@@ -128,9 +250,27 @@ pub fn address(&self) -> String {
     }
 }
 
+/// Hash `message` under `domain` for [`StandardWallet::sign_with_domain`].
+fn domain_hash(domain: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut hasher = alloy_primitives::Keccak256::new();
+    hasher.update((domain.len() as u64).to_be_bytes());
+    hasher.update(domain);
+    hasher.update(message);
+    hasher.finalize().0
+}
+
+impl Signer for StandardWallet {
+    #[inline]
+    fn sign_prehash(&self, hash: &[u8; 32]) -> Result<Signature, Error> {
+        self.sign_hash(hash)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use crate::fixtures::{SOME_DATA, some_data_signature};
 
     #[cfg(feature = "rand")]
     #[test]
@@ -170,4 +310,133 @@ fn test_from_bytes() {
         let imported = StandardWallet::from_bytes(&bytes).unwrap();
         assert_eq!(wallet.address(), imported.address());
     }
+
+    #[test]
+    fn test_sign_message_recovers_signer() {
+        let wallet = StandardWallet::from_hex(
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .unwrap();
+        let signature = wallet.sign_message(SOME_DATA).unwrap();
+
+        let recovered = crate::Address::recover_from_message(SOME_DATA, &signature).unwrap();
+        assert_eq!(recovered.to_checksum(), wallet.address());
+        assert_eq!(recovered, wallet.public_key().to_address());
+        assert_eq!(signature, some_data_signature());
+    }
+
+    #[test]
+    fn test_sign_hash_is_unprefixed() {
+        let wallet = StandardWallet::from_hex(
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .unwrap();
+        let hash = alloy_primitives::keccak256(b"user operation").0;
+        let signature = wallet.sign_hash(&hash).unwrap();
+
+        let recovered = crate::Address::recover_from_prehash(&hash, &signature).unwrap();
+        assert_eq!(recovered, wallet.public_key().to_address());
+        assert_ne!(signature, wallet.sign_message(&hash).unwrap());
+    }
+
+    #[test]
+    fn test_sign_with_domain() {
+        let wallet = StandardWallet::from_hex(
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .unwrap();
+        let login = wallet
+            .sign_with_domain(b"example.com/login", b"nonce")
+            .unwrap();
+        let vote = wallet
+            .sign_with_domain(b"example.com/vote", b"nonce")
+            .unwrap();
+        assert_ne!(login, vote);
+        assert_ne!(login, wallet.sign_message(b"nonce").unwrap());
+
+        let hash = domain_hash(b"example.com/login", b"nonce");
+        let recovered = crate::Address::recover_from_prehash(&hash, &login).unwrap();
+        assert_eq!(recovered, wallet.public_key().to_address());
+
+        // Moving bytes across the domain boundary changes the hash.
+        assert_ne!(domain_hash(b"ab", b"c"), domain_hash(b"a", b"bc"));
+    }
+
+    #[cfg(feature = "k256-interop")]
+    #[test]
+    fn test_signing_key_round_trip() {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        let key = SigningKey::from_slice(&bytes).unwrap();
+
+        let wallet = StandardWallet::from_signing_key(key.clone());
+        assert_eq!(wallet.to_signing_key(), key);
+        assert_eq!(*wallet.secret_bytes(), bytes);
+        assert_eq!(
+            wallet.address(),
+            StandardWallet::from_bytes(&bytes).unwrap().address()
+        );
+    }
+
+    #[test]
+    fn test_sign_message_with_hash_algo() {
+        use crate::Signer;
+        use crate::hash::HashAlgo;
+
+        let mut bytes = [0u8; 32];
+        bytes[31] = 7;
+        let wallet = StandardWallet::from_bytes(&bytes).unwrap();
+        let keccak = wallet
+            .sign_message_with(b"hello", HashAlgo::Keccak256)
+            .unwrap();
+        assert_eq!(keccak, wallet.sign_message(b"hello").unwrap());
+        assert_ne!(
+            wallet
+                .sign_message_with(b"hello", HashAlgo::Sha3_256)
+                .unwrap(),
+            keccak
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_le() {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        bytes[0] = 2;
+
+        let be = StandardWallet::from_bytes(&bytes).unwrap();
+        let le = StandardWallet::from_bytes_le(&bytes).unwrap();
+        assert_ne!(be.address(), le.address());
+
+        assert_eq!(*le.to_bytes_le(), bytes);
+        let mut reversed = bytes;
+        reversed.reverse();
+        assert_eq!(*le.secret_bytes(), reversed);
+    }
+
+    #[test]
+    fn test_sign_transactions_batch() {
+        let wallet = StandardWallet::from_hex(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        let sender = crate::Address::parse(&wallet.address()).unwrap();
+        let transactions: Vec<Transaction> = (0..3)
+            .map(|nonce| {
+                Transaction::new(1)
+                    .with_nonce(nonce)
+                    .with_gas_limit(21_000)
+                    .with_to(crate::Address::from_bytes([0x35; 20]))
+                    .with_value(1)
+            })
+            .collect();
+
+        let signed = wallet.sign_transactions(&transactions).unwrap();
+        assert_eq!(signed.len(), 3);
+        for (signed_tx, tx) in signed.iter().zip(&transactions) {
+            assert_eq!(signed_tx.transaction(), tx);
+            assert_eq!(signed_tx.recover_sender().unwrap(), sender);
+        }
+        assert!(wallet.sign_transactions(&[]).unwrap().is_empty());
+    }
 }
@@ -0,0 +1,672 @@
+//! EIP-1559 (type 2) Ethereum transactions.
+//!
+//! A [`Transaction`] only assembles the signing preimage and the final
+//! envelope; signing is delegated to any [`Signer`], so keys may stay in
+//! external custody.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::keccak256;
+use kobe::Amount;
+
+use crate::rlp::{encode_bytes, encode_list, encode_uint, encode_word};
+use crate::{Address, Error, GasOracle, Network, Signature, Signer};
+
+/// EIP-2718 transaction type byte for EIP-1559 transactions.
+pub const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// An EIP-2930 access list entry: a contract address and the storage
+/// slots the transaction will touch in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListItem {
+    /// Accessed contract.
+    address: Address,
+    /// Accessed storage slots.
+    storage_keys: Vec<[u8; 32]>,
+}
+
+impl AccessListItem {
+    /// Create an entry for `address` with the given storage keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if a storage key is not exactly
+    /// 32 bytes; keys are never padded or truncated.
+    pub fn new(address: Address, keys: &[impl AsRef<[u8]>]) -> Result<Self, Error> {
+        let storage_keys = keys
+            .iter()
+            .map(|key| <[u8; 32]>::try_from(key.as_ref()).map_err(|_| Error::InvalidLength))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            address,
+            storage_keys,
+        })
+    }
+
+    /// Get the accessed contract address.
+    #[inline]
+    #[must_use]
+    pub const fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Get the accessed storage keys.
+    #[inline]
+    #[must_use]
+    pub fn storage_keys(&self) -> &[[u8; 32]] {
+        &self.storage_keys
+    }
+}
+
+/// An unsigned EIP-1559 (type 2) transaction.
+///
+/// # Example
+///
+/// ```
+/// use kobe_eth::{Address, StandardWallet, Transaction};
+///
+/// let wallet = StandardWallet::from_hex(
+///     "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+/// )?;
+/// let tx = Transaction::new(1)
+///     .with_nonce(0)
+///     .with_gas_limit(21_000)
+///     .with_fees(1_000_000_000, 30_000_000_000)
+///     .with_to(Address::from_bytes([0x11; 20]))
+///     .with_value(1_000_000_000_000_000);
+/// let signed = tx.sign_with(&wallet)?;
+/// assert_eq!(signed.to_raw()[0], 0x02);
+/// # Ok::<(), kobe_eth::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    /// EIP-155 chain id.
+    chain_id: u64,
+    /// Sender nonce.
+    nonce: u64,
+    /// Maximum priority fee per gas (wei).
+    max_priority_fee_per_gas: u128,
+    /// Maximum total fee per gas (wei).
+    max_fee_per_gas: u128,
+    /// Gas limit.
+    gas_limit: u64,
+    /// Recipient, or `None` for contract creation.
+    to: Option<Address>,
+    /// Value transferred (wei).
+    value: u128,
+    /// Call data.
+    data: Vec<u8>,
+    /// EIP-2930 access list.
+    access_list: Vec<AccessListItem>,
+}
+
+impl Transaction {
+    /// Create an empty transaction for `chain_id`.
+    ///
+    /// All other fields start at zero, with no recipient, empty call data
+    /// and an empty access list.
+    #[inline]
+    #[must_use]
+    pub const fn new(chain_id: u64) -> Self {
+        Self {
+            chain_id,
+            nonce: 0,
+            max_priority_fee_per_gas: 0,
+            max_fee_per_gas: 0,
+            gas_limit: 0,
+            to: None,
+            value: 0,
+            data: Vec::new(),
+            access_list: Vec::new(),
+        }
+    }
+
+    /// Set the sender nonce.
+    #[inline]
+    #[must_use]
+    pub const fn with_nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Get a copy of this transaction with the nonce incremented by one,
+    /// e.g. for the next transaction of a batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NonceOverflow`] if the nonce is `u64::MAX`.
+    pub fn with_next_nonce(&self) -> Result<Self, Error> {
+        let nonce = self.nonce.checked_add(1).ok_or(Error::NonceOverflow)?;
+        Ok(self.clone().with_nonce(nonce))
+    }
+
+    /// Set the gas limit.
+    #[inline]
+    #[must_use]
+    pub const fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Set the maximum priority fee and maximum total fee per gas (wei).
+    #[inline]
+    #[must_use]
+    pub const fn with_fees(
+        mut self,
+        max_priority_fee_per_gas: u128,
+        max_fee_per_gas: u128,
+    ) -> Self {
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        self.max_fee_per_gas = max_fee_per_gas;
+        self
+    }
+
+    /// Fill in fees from `oracle` unless they were already set with
+    /// [`Transaction::with_fees`].
+    ///
+    /// Fees count as unset while `max_fee_per_gas` is zero.
+    #[must_use]
+    pub fn with_fees_from(self, oracle: &impl GasOracle) -> Self {
+        if self.max_fee_per_gas != 0 {
+            return self;
+        }
+        let (max_fee_per_gas, max_priority_fee_per_gas) = oracle.suggest_fees();
+        self.with_fees(max_priority_fee_per_gas, max_fee_per_gas)
+    }
+
+    /// Set the recipient.
+    #[inline]
+    #[must_use]
+    pub const fn with_to(mut self, to: Address) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Set the value transferred (wei).
+    #[inline]
+    #[must_use]
+    pub const fn with_value(mut self, value: u128) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the call data.
+    #[inline]
+    #[must_use]
+    pub fn with_data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    /// Set the access list.
+    ///
+    /// Storage keys are validated when each [`AccessListItem`] is created.
+    #[inline]
+    #[must_use]
+    pub fn with_access_list(mut self, access_list: Vec<AccessListItem>) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
+    /// Get the chain id.
+    #[inline]
+    #[must_use]
+    pub const fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Get the sender nonce.
+    #[inline]
+    #[must_use]
+    pub const fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Get the maximum priority fee per gas (wei).
+    #[inline]
+    #[must_use]
+    pub const fn max_priority_fee_per_gas(&self) -> u128 {
+        self.max_priority_fee_per_gas
+    }
+
+    /// Get the maximum total fee per gas (wei).
+    #[inline]
+    #[must_use]
+    pub const fn max_fee_per_gas(&self) -> u128 {
+        self.max_fee_per_gas
+    }
+
+    /// Get the gas limit.
+    #[inline]
+    #[must_use]
+    pub const fn gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Get the recipient, or `None` for contract creation.
+    #[inline]
+    #[must_use]
+    pub const fn to(&self) -> Option<&Address> {
+        self.to.as_ref()
+    }
+
+    /// Get the value transferred (wei).
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> u128 {
+        self.value
+    }
+
+    /// Get the call data.
+    #[inline]
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the access list.
+    #[inline]
+    #[must_use]
+    pub fn access_list(&self) -> &[AccessListItem] {
+        &self.access_list
+    }
+
+    /// Summarize the transaction for user confirmation:
+    /// "sending `value` to `to`, fee up to `max_fee`".
+    ///
+    /// The maximum fee saturates at `u128::MAX` wei.
+    #[must_use]
+    pub fn summary(&self) -> TransactionSummary {
+        TransactionSummary {
+            to: self.to,
+            value: Amount::from_wei(self.value),
+            max_fee: Amount::from_wei(
+                self.max_fee_per_gas
+                    .saturating_mul(u128::from(self.gas_limit)),
+            ),
+            data_len: self.data.len(),
+            is_contract_call: self.to.is_some() && !self.data.is_empty(),
+        }
+    }
+
+    /// Compute the hash that must be signed for this transaction,
+    /// `keccak256(0x02 || rlp([...fields]))`.
+    ///
+    /// This is what an external signer such as a hardware wallet signs;
+    /// attach its signature with [`Transaction::with_signature`].
+    #[must_use]
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut preimage = Vec::new();
+        preimage.push(EIP1559_TX_TYPE);
+        encode_list(&mut preimage, &self.fields_rlp());
+        keccak256(&preimage).0
+    }
+
+    /// Sign the transaction with `signer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer fails.
+    pub fn sign_with(&self, signer: &impl Signer) -> Result<SignedTransaction, Error> {
+        let signature = signer.sign_prehash(&self.signing_hash())?;
+        Ok(SignedTransaction {
+            transaction: self.clone(),
+            signature,
+        })
+    }
+
+    /// Attach a signature produced externally over
+    /// [`Transaction::signing_hash`].
+    ///
+    /// Only the y-parity is checked; use
+    /// [`SignedTransaction::recover_sender`] to confirm the signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSignature`] if the y-parity is not `0` or `1`.
+    pub fn with_signature(self, signature: Signature) -> Result<SignedTransaction, Error> {
+        if signature.v() > 1 {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(SignedTransaction::new(self, signature))
+    }
+
+    /// Check that the transaction's chain id matches `network`.
+    ///
+    /// Guards against cross-chain replay, e.g. signing a chain id `1`
+    /// transaction while intending to send it on Polygon.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChainIdMismatch`] if the chain ids differ.
+    pub const fn validate_for(&self, network: Network) -> Result<(), Error> {
+        let expected = network.chain_id();
+        if self.chain_id == expected {
+            Ok(())
+        } else {
+            Err(Error::ChainIdMismatch {
+                expected,
+                actual: self.chain_id,
+            })
+        }
+    }
+
+    /// Validate the chain id against `network`, then sign with `signer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChainIdMismatch`] if the chain ids differ, or an
+    /// error if the signer fails.
+    pub fn sign_for(
+        &self,
+        network: Network,
+        signer: &impl Signer,
+    ) -> Result<SignedTransaction, Error> {
+        self.validate_for(network)?;
+        self.sign_with(signer)
+    }
+
+    /// RLP-encode the unsigned fields, without the list header.
+    pub(crate) fn fields_rlp(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_uint(&mut out, self.chain_id.into());
+        encode_uint(&mut out, self.nonce.into());
+        encode_uint(&mut out, self.max_priority_fee_per_gas);
+        encode_uint(&mut out, self.max_fee_per_gas);
+        encode_uint(&mut out, self.gas_limit.into());
+        match &self.to {
+            Some(to) => encode_bytes(&mut out, to.as_bytes()),
+            None => encode_bytes(&mut out, &[]),
+        }
+        encode_uint(&mut out, self.value);
+        encode_bytes(&mut out, &self.data);
+        let mut access_list = Vec::new();
+        for item in &self.access_list {
+            let mut storage_keys = Vec::new();
+            for key in &item.storage_keys {
+                encode_bytes(&mut storage_keys, key);
+            }
+            let mut entry = Vec::new();
+            encode_bytes(&mut entry, item.address.as_bytes());
+            encode_list(&mut entry, &storage_keys);
+            encode_list(&mut access_list, &entry);
+        }
+        encode_list(&mut out, &access_list);
+        out
+    }
+}
+
+/// What a transaction does, for display on a confirmation screen.
+///
+/// Built by [`Transaction::summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionSummary {
+    /// Recipient, or `None` for contract creation.
+    pub to: Option<Address>,
+    /// Value transferred.
+    pub value: Amount,
+    /// Worst-case fee, `max_fee_per_gas * gas_limit`.
+    pub max_fee: Amount,
+    /// Length of the call data in bytes.
+    pub data_len: usize,
+    /// Whether the transaction calls a contract (has a recipient and call data).
+    pub is_contract_call: bool,
+}
+
+/// A signed EIP-1559 transaction, ready for broadcast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTransaction {
+    /// The signed transaction.
+    transaction: Transaction,
+    /// Signature over [`Transaction::signing_hash`].
+    signature: Signature,
+}
+
+impl SignedTransaction {
+    /// Pair a transaction with its signature.
+    pub(crate) const fn new(transaction: Transaction, signature: Signature) -> Self {
+        Self {
+            transaction,
+            signature,
+        }
+    }
+
+    /// Get the signed transaction.
+    #[inline]
+    #[must_use]
+    pub const fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    /// Get the signature.
+    #[inline]
+    #[must_use]
+    pub const fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Recover the sender address from the signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is invalid.
+    pub fn recover_sender(&self) -> Result<Address, Error> {
+        Address::recover_from_prehash(&self.transaction.signing_hash(), &self.signature)
+    }
+
+    /// Serialize as an EIP-2718 envelope (`0x02 || rlp([...fields, y_parity, r, s])`).
+    #[must_use]
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut payload = self.transaction.fields_rlp();
+        encode_uint(&mut payload, self.signature.v().into());
+        encode_word(&mut payload, self.signature.r());
+        encode_word(&mut payload, self.signature.s());
+
+        let mut out = Vec::new();
+        out.push(EIP1559_TX_TYPE);
+        encode_list(&mut out, &payload);
+        out
+    }
+
+    /// Compute the transaction hash (`keccak256` of the raw envelope).
+    #[must_use]
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256(self.to_raw()).0
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{StandardWallet, StaticGasOracle};
+    use core::cell::Cell;
+
+    const KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn sample() -> Transaction {
+        Transaction::new(1)
+            .with_nonce(9)
+            .with_gas_limit(21_000)
+            .with_fees(1_000_000_000, 20_000_000_000)
+            .with_to(Address::from_bytes([0x35; 20]))
+            .with_value(1_000_000_000_000_000_000)
+    }
+
+    /// Signer that records the hash it was asked to sign.
+    struct MockSigner {
+        seen: Cell<Option<[u8; 32]>>,
+    }
+
+    impl Signer for MockSigner {
+        fn sign_prehash(&self, hash: &[u8; 32]) -> Result<Signature, Error> {
+            self.seen.set(Some(*hash));
+            Signature::new([1; 32], [2; 32], 0)
+        }
+    }
+
+    #[test]
+    fn test_sign_with_mock_signer() {
+        let tx = sample();
+        let signer = MockSigner {
+            seen: Cell::new(None),
+        };
+        let signed = tx.sign_with(&signer).unwrap();
+        assert_eq!(signer.seen.get(), Some(tx.signing_hash()));
+        assert_eq!(
+            signed.signature(),
+            &Signature::new([1; 32], [2; 32], 0).unwrap()
+        );
+        assert_eq!(signed.transaction(), &tx);
+    }
+
+    #[test]
+    fn test_sign_with_wallet_recovers_sender() {
+        let wallet = StandardWallet::from_hex(KEY).unwrap();
+        let signed = sample().sign_with(&wallet).unwrap();
+        assert_eq!(
+            signed.recover_sender().unwrap().to_checksum(),
+            wallet.address()
+        );
+    }
+
+    #[test]
+    fn test_raw_envelope() {
+        let wallet = StandardWallet::from_hex(KEY).unwrap();
+        let signed = sample().sign_with(&wallet).unwrap();
+        let raw = signed.to_raw();
+        assert_eq!(raw[0], EIP1559_TX_TYPE);
+        // Long list header: 0xf8 followed by a one-byte payload length.
+        assert_eq!(raw[1], 0xf8);
+        assert_eq!(usize::from(raw[2]), raw.len() - 3);
+        assert_eq!(signed.hash(), keccak256(&raw).0);
+    }
+
+    #[test]
+    fn test_external_signing_matches_sign_with() {
+        let wallet = StandardWallet::from_hex(
+            "4646464646464646464646464646464646464646464646464646464646464646",
+        )
+        .unwrap();
+        let tx = Transaction::new(1)
+            .with_nonce(3)
+            .with_gas_limit(21_000)
+            .with_fees(1_000_000_000, 30_000_000_000)
+            .with_to(Address::from_bytes([0x35; 20]))
+            .with_value(1);
+
+        let signature = wallet.sign_hash(&tx.signing_hash()).unwrap();
+        let external = tx.clone().with_signature(signature).unwrap();
+        assert_eq!(external.to_raw(), tx.sign_with(&wallet).unwrap().to_raw());
+
+        // A legacy `27`/`28` signature is stored as its y-parity.
+        let legacy = Signature::new(*signature.r(), *signature.s(), 27 + signature.v()).unwrap();
+        assert_eq!(tx.with_signature(legacy).unwrap(), external);
+    }
+
+    #[test]
+    fn test_with_next_nonce() {
+        let tx = Transaction::new(1).with_nonce(41).with_value(5);
+        let next = tx.with_next_nonce().unwrap();
+        assert_eq!(next.nonce(), 42);
+        assert_eq!(next.with_nonce(41), tx);
+
+        assert_eq!(
+            tx.with_nonce(u64::MAX).with_next_nonce(),
+            Err(Error::NonceOverflow)
+        );
+    }
+
+    #[test]
+    fn test_validate_for() {
+        assert!(sample().validate_for(Network::Ethereum).is_ok());
+        assert_eq!(
+            sample().validate_for(Network::Polygon),
+            Err(Error::ChainIdMismatch {
+                expected: 137,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_sign_for_rejects_wrong_network() {
+        let wallet = StandardWallet::from_hex(KEY).unwrap();
+        let err = sample().sign_for(Network::Polygon, &wallet).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ChainIdMismatch {
+                expected: 137,
+                actual: 1
+            }
+        ));
+        assert!(sample().sign_for(Network::Ethereum, &wallet).is_ok());
+    }
+
+    #[test]
+    fn test_with_fees_from_oracle() {
+        let oracle = StaticGasOracle::new(40_000_000_000, 2_000_000_000);
+        let tx = Transaction::new(1).with_fees_from(&oracle);
+        assert_eq!(tx.max_fee_per_gas(), 40_000_000_000);
+        assert_eq!(tx.max_priority_fee_per_gas(), 2_000_000_000);
+
+        // Explicit fees take precedence.
+        let explicit = sample().with_fees_from(&oracle);
+        assert_eq!(explicit.max_fee_per_gas(), 20_000_000_000);
+        assert_eq!(explicit.max_priority_fee_per_gas(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_summary_transfer() {
+        let summary = sample().summary();
+        assert_eq!(summary.to, Some(Address::from_bytes([0x35; 20])));
+        assert_eq!(summary.value, Amount::from_wei(1_000_000_000_000_000_000));
+        assert_eq!(summary.max_fee, Amount::from_wei(21_000 * 20_000_000_000));
+        assert_eq!(summary.data_len, 0);
+        assert!(!summary.is_contract_call);
+    }
+
+    #[test]
+    fn test_summary_contract_call() {
+        let call = [0xa9, 0x05, 0x9c, 0xbb, 0, 0, 0, 0];
+        let summary = sample()
+            .with_value(0)
+            .with_gas_limit(60_000)
+            .with_data(call)
+            .summary();
+        assert_eq!(summary.value, Amount::from_wei(0));
+        assert_eq!(summary.max_fee, Amount::from_wei(60_000 * 20_000_000_000));
+        assert_eq!(summary.data_len, call.len());
+        assert!(summary.is_contract_call);
+
+        let deploy = Transaction::new(1).with_data(call).summary();
+        assert_eq!(deploy.to, None);
+        assert!(!deploy.is_contract_call);
+    }
+
+    #[test]
+    fn test_signing_hash_depends_on_fields() {
+        let tx = sample();
+        assert_ne!(tx.signing_hash(), tx.clone().with_nonce(10).signing_hash());
+        assert_ne!(tx.signing_hash(), tx.with_data([0xde, 0xad]).signing_hash());
+    }
+
+    #[test]
+    fn test_access_list_rejects_short_storage_key() {
+        let address = Address::from_bytes([0x11; 20]);
+        assert_eq!(
+            AccessListItem::new(address, &[[0u8; 31]]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            AccessListItem::new(address, &[[0u8; 33]]),
+            Err(Error::InvalidLength)
+        );
+
+        let item = AccessListItem::new(address, &[[0u8; 32], [1u8; 32]]).unwrap();
+        assert_eq!(item.storage_keys(), &[[0u8; 32], [1u8; 32]]);
+        let tx = sample().with_access_list(vec![item]);
+        assert_ne!(tx.signing_hash(), sample().signing_hash());
+    }
+}
@@ -0,0 +1,260 @@
+//! EIP-681 `ethereum:` payment request URIs.
+//!
+//! Format: `ethereum:[pay-]<address>[@<chain_id>][/<function>][?<parameters>]`.
+//! Numeric parameters (`value`, `gas`, `gasLimit`, `gasPrice`) may use
+//! scientific notation such as `2.014e18`, as long as the result is an
+//! integer. Other parameters, e.g. the typed arguments of a function call,
+//! are kept in order as raw key/value pairs.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{Address, Error};
+
+/// URI scheme for Ethereum payment requests.
+const SCHEME: &str = "ethereum:";
+
+/// A parsed EIP-681 payment request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthereumUri {
+    /// Target address (recipient, or contract for function calls).
+    target: Address,
+    /// Chain id, if given with `@`.
+    chain_id: Option<u64>,
+    /// Function name, if the request is a contract call.
+    function: Option<String>,
+    /// Value to transfer (wei).
+    value: Option<u128>,
+    /// Gas limit (`gas` or `gasLimit`).
+    gas_limit: Option<u64>,
+    /// Gas price (wei).
+    gas_price: Option<u128>,
+    /// Remaining parameters, in order.
+    parameters: Vec<(String, String)>,
+}
+
+impl EthereumUri {
+    /// Get the target address.
+    #[inline]
+    #[must_use]
+    pub const fn target(&self) -> &Address {
+        &self.target
+    }
+
+    /// Get the chain id, if specified.
+    #[inline]
+    #[must_use]
+    pub const fn chain_id(&self) -> Option<u64> {
+        self.chain_id
+    }
+
+    /// Get the function name, if the request is a contract call.
+    #[inline]
+    #[must_use]
+    pub fn function(&self) -> Option<&str> {
+        self.function.as_deref()
+    }
+
+    /// Get the value to transfer (wei).
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> Option<u128> {
+        self.value
+    }
+
+    /// Get the gas limit.
+    #[inline]
+    #[must_use]
+    pub const fn gas_limit(&self) -> Option<u64> {
+        self.gas_limit
+    }
+
+    /// Get the gas price (wei).
+    #[inline]
+    #[must_use]
+    pub const fn gas_price(&self) -> Option<u128> {
+        self.gas_price
+    }
+
+    /// Get the remaining parameters (e.g. function arguments), in order.
+    #[inline]
+    #[must_use]
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// Look up a remaining parameter by key.
+    #[must_use]
+    pub fn parameter(&self, key: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl core::str::FromStr for EthereumUri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+/// Parse an EIP-681 `ethereum:` URI.
+///
+/// Only hex target addresses are supported; ENS names are rejected.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidUri`] if the URI is malformed or a numeric
+/// parameter is not an integer, and [`Error::InvalidAddress`] if the target
+/// address is invalid.
+///
+/// # Example
+///
+/// ```
+/// let uri = kobe_eth::uri::parse("ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed@1?value=2.014e18")?;
+/// assert_eq!(uri.chain_id(), Some(1));
+/// assert_eq!(uri.value(), Some(2_014_000_000_000_000_000));
+/// # Ok::<(), kobe_eth::Error>(())
+/// ```
+pub fn parse(s: &str) -> Result<EthereumUri, Error> {
+    let body = s.strip_prefix(SCHEME).ok_or(Error::InvalidUri)?;
+    let rest = body.strip_prefix("pay-").unwrap_or(body);
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (target_and_chain, function) = match path.split_once('/') {
+        Some((target, function)) if !function.is_empty() => (target, Some(function)),
+        Some(_) => return Err(Error::InvalidUri),
+        None => (path, None),
+    };
+    let (target, chain_id) = match target_and_chain.split_once('@') {
+        Some((target, chain_id)) => (
+            target,
+            Some(chain_id.parse::<u64>().map_err(|_| Error::InvalidUri)?),
+        ),
+        None => (target_and_chain, None),
+    };
+
+    let mut uri = EthereumUri {
+        target: Address::parse(target)?,
+        chain_id,
+        function: function.map(ToString::to_string),
+        value: None,
+        gas_limit: None,
+        gas_price: None,
+        parameters: Vec::new(),
+    };
+
+    for param in query.split('&').filter(|param| !param.is_empty()) {
+        let (key, raw) = param.split_once('=').ok_or(Error::InvalidUri)?;
+        match key {
+            "value" => uri.value = Some(parse_number(raw)?),
+            "gas" | "gasLimit" => {
+                let gas = u64::try_from(parse_number(raw)?).map_err(|_| Error::InvalidUri)?;
+                uri.gas_limit = Some(gas);
+            }
+            "gasPrice" => uri.gas_price = Some(parse_number(raw)?),
+            _ => uri.parameters.push((key.to_string(), raw.to_string())),
+        }
+    }
+    Ok(uri)
+}
+
+/// Parse an EIP-681 number: decimal digits with an optional fraction and
+/// exponent, which must evaluate to an integer.
+fn parse_number(s: &str) -> Result<u128, Error> {
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent.parse::<u32>().map_err(|_| Error::InvalidUri)?,
+        ),
+        None => (s, 0),
+    };
+    let (whole, frac_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty()
+        || !whole
+            .bytes()
+            .chain(frac_digits.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(Error::InvalidUri);
+    }
+
+    // Trailing fractional zeros do not affect integrality.
+    let frac = frac_digits.trim_end_matches('0');
+    let frac_len = u32::try_from(frac.len()).map_err(|_| Error::InvalidUri)?;
+    let scale = exponent.checked_sub(frac_len).ok_or(Error::InvalidUri)?;
+
+    whole
+        .bytes()
+        .chain(frac.bytes())
+        .try_fold(0u128, |acc, digit| {
+            acc.checked_mul(10)?.checked_add(u128::from(digit - b'0'))
+        })
+        .and_then(|digits| digits.checked_mul(10u128.checked_pow(scale)?))
+        .ok_or(Error::InvalidUri)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_transfer() {
+        let uri =
+            parse("ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed?value=2.014e18").unwrap();
+        assert_eq!(
+            uri.target().to_checksum(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(uri.chain_id(), None);
+        assert_eq!(uri.function(), None);
+        assert_eq!(uri.value(), Some(2_014_000_000_000_000_000));
+        assert!(uri.parameters().is_empty());
+    }
+
+    #[test]
+    fn test_erc20_transfer() {
+        let uri: EthereumUri = "ethereum:0x89205a3a3b2a69de6dbf7f01ed13b2108b2c43e7@1/transfer?address=0x8e23ee67d1332ad560396262c48ffbb01f93d052&uint256=1&gas=6e4"
+            .parse()
+            .unwrap();
+        assert_eq!(uri.chain_id(), Some(1));
+        assert_eq!(uri.function(), Some("transfer"));
+        assert_eq!(uri.gas_limit(), Some(60_000));
+        assert_eq!(
+            uri.parameter("address"),
+            Some("0x8e23ee67d1332ad560396262c48ffbb01f93d052")
+        );
+        assert_eq!(uri.parameter("uint256"), Some("1"));
+        assert_eq!(uri.parameters().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("1").unwrap(), 1);
+        assert_eq!(parse_number("1e18").unwrap(), 1_000_000_000_000_000_000);
+        assert_eq!(parse_number("2.50e1").unwrap(), 25);
+        assert!(parse_number("2.5").is_err());
+        assert!(parse_number("1.5e0").is_err());
+        assert!(parse_number("-1").is_err());
+        assert!(parse_number("e18").is_err());
+        assert!(parse_number("1e99").is_err());
+    }
+
+    #[test]
+    fn test_reject_malformed() {
+        for uri in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "ethereum:",
+            "ethereum:vitalik.eth",
+            "ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed@mainnet",
+            "ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed/",
+            "ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed?value=0.5",
+            "ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed?value",
+        ] {
+            assert!(parse(uri).is_err(), "{uri}");
+        }
+    }
+}
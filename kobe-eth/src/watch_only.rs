@@ -0,0 +1,125 @@
+//! Watch-only Ethereum accounts.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::abi::{address_word, selector};
+use crate::{Address, Error, Signature};
+
+/// An account known only by its address.
+///
+/// An address is a hash of a public key, so neither the key nor a signer
+/// can be recovered from it. `WatchOnly` makes that explicit in the type:
+/// it builds the read-only queries a wallet needs to track an account, and
+/// has no signing API.
+///
+/// ```compile_fail
+/// use kobe_eth::{Address, WatchOnly};
+///
+/// let account = WatchOnly::new(Address::from_bytes([0u8; 20]));
+/// account.sign_message(b"hello");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WatchOnly {
+    /// Watched address.
+    address: Address,
+}
+
+impl WatchOnly {
+    /// Watch the given address.
+    #[inline]
+    #[must_use]
+    pub const fn new(address: Address) -> Self {
+        Self { address }
+    }
+
+    /// Watch an address given as a `0x`-prefixed hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidAddress`] if the string is not a valid
+    /// address, including a mixed-case one with a bad EIP-55 checksum.
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        Address::parse(s).map(Self::new)
+    }
+
+    /// Get the watched address.
+    #[inline]
+    #[must_use]
+    pub const fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Get the `eth_call` data of an ERC-20 `balanceOf(address)` query for
+    /// this account.
+    #[must_use]
+    pub fn erc20_balance_of_call_data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(36);
+        data.extend_from_slice(&selector("balanceOf(address)"));
+        data.extend_from_slice(&address_word(&self.address));
+        data
+    }
+
+    /// Check whether an EIP-191 personal message signature was made by this
+    /// account.
+    #[must_use]
+    pub fn is_signer_of(&self, message: &[u8], signature: &Signature) -> bool {
+        Address::recover_from_message(message, signature).is_ok_and(|a| a == self.address)
+    }
+
+    /// Get the checksummed address string (EIP-55).
+    #[inline]
+    #[must_use]
+    pub fn to_checksum(&self) -> String {
+        self.address.to_checksum()
+    }
+}
+
+impl fmt::Display for WatchOnly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.address, f)
+    }
+}
+
+impl From<Address> for WatchOnly {
+    fn from(address: Address) -> Self {
+        Self::new(address)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{SOME_DATA, SOME_DATA_SIGNER, some_data_signature};
+
+    const ADDRESS: &str = SOME_DATA_SIGNER;
+
+    #[test]
+    fn test_format() {
+        let account = WatchOnly::parse(&ADDRESS.to_lowercase()).unwrap();
+        assert_eq!(account.to_string(), ADDRESS);
+        assert_eq!(account.to_checksum(), ADDRESS);
+        assert_eq!(*account.address(), Address::parse(ADDRESS).unwrap());
+    }
+
+    #[test]
+    fn test_balance_of_call_data() {
+        let account = WatchOnly::parse(ADDRESS).unwrap();
+        let data = account.erc20_balance_of_call_data();
+        assert_eq!(
+            hex::encode(data),
+            "70a082310000000000000000000000002c7536e3605d9c16a7a3d7b1898e529396a65c23"
+        );
+    }
+
+    #[test]
+    fn test_is_signer_of() {
+        let signature = some_data_signature();
+        let account = WatchOnly::parse(ADDRESS).unwrap();
+        assert!(account.is_signer_of(SOME_DATA, &signature));
+        assert!(!account.is_signer_of(b"Other data", &signature));
+    }
+}
@@ -0,0 +1,126 @@
+//! Compile-time checked literals for Kobe.
+//!
+//! These macros validate their input while compiling, so typos in embedded
+//! addresses or keys become build errors rather than runtime failures.
+//!
+//! They are re-exported by the chain crates behind their `macros` feature;
+//! use them through those crates rather than depending on this one directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use sha3::{Digest, Keccak256};
+use syn::{LitStr, parse_macro_input};
+
+/// Create a `kobe_eth::Address` from an EIP-55 checksummed literal.
+///
+/// The literal must be `0x` followed by 40 hex digits in checksummed case.
+/// The expansion is a constant expression.
+///
+/// # Example
+///
+/// ```
+/// use kobe_eth::{Address, eth_address};
+///
+/// const VITALIK: Address = eth_address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+/// ```
+#[proc_macro]
+pub fn eth_address(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    match parse_checksummed_address(&lit.value()) {
+        Ok(bytes) => quote!(::kobe_eth::Address::from_bytes([#(#bytes),*])).into(),
+        Err(msg) => syn::Error::new(lit.span(), msg).to_compile_error().into(),
+    }
+}
+
+/// Create a `kobe_eth::StandardWallet` from a hex private key literal.
+///
+/// The literal must be 64 hex digits, optionally prefixed with `0x`, and
+/// encode a scalar in the range `[1, n)` where `n` is the secp256k1 group
+/// order. Both are checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// use kobe_eth::eth_private_key;
+///
+/// let wallet =
+///     eth_private_key!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+/// assert_eq!(wallet.address(), "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+/// ```
+#[proc_macro]
+pub fn eth_private_key(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    match parse_secret_key(&lit.value()) {
+        Ok(bytes) => quote!({
+            match ::kobe_eth::StandardWallet::from_bytes(&[#(#bytes),*]) {
+                ::core::result::Result::Ok(wallet) => wallet,
+                ::core::result::Result::Err(_) => {
+                    ::core::unreachable!("private key validated at compile time")
+                }
+            }
+        })
+        .into(),
+        Err(msg) => syn::Error::new(lit.span(), msg).to_compile_error().into(),
+    }
+}
+
+/// Parse and validate a secp256k1 secret key literal.
+fn parse_secret_key(value: &str) -> Result<[u8; 32], String> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    if digits.len() != 64 {
+        return Err(format!(
+            "private key must have 64 hex digits, found {}",
+            digits.len()
+        ));
+    }
+
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(digits, &mut bytes).map_err(|e| format!("invalid hex: {e}"))?;
+
+    if !kobe::secp256k1::is_valid_scalar(&bytes) {
+        return Err("private key is out of range for secp256k1".to_string());
+    }
+
+    Ok(bytes)
+}
+
+/// Parse and validate an EIP-55 checksummed address literal.
+fn parse_checksummed_address(value: &str) -> Result<[u8; 20], String> {
+    let digits = value
+        .strip_prefix("0x")
+        .ok_or_else(|| "address must start with `0x`".to_string())?;
+    if digits.len() != 40 {
+        return Err(format!(
+            "address must have 40 hex digits, found {}",
+            digits.len()
+        ));
+    }
+
+    let mut bytes = [0u8; 20];
+    hex::decode_to_slice(digits, &mut bytes).map_err(|e| format!("invalid hex: {e}"))?;
+
+    let expected = to_checksum(&bytes);
+    if expected[2..] != *digits {
+        return Err(format!("invalid EIP-55 checksum, expected `{expected}`"));
+    }
+
+    Ok(bytes)
+}
+
+/// Compute the EIP-55 checksummed string for an address.
+fn to_checksum(bytes: &[u8; 20]) -> String {
+    let lower = hex::encode(bytes);
+    let hash = Keccak256::digest(lower.as_bytes());
+
+    let mut out = String::with_capacity(42);
+    out.push_str("0x");
+    for (i, c) in lower.chars().enumerate() {
+        let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+        if c.is_ascii_alphabetic() && nibble >= 8 {
+            out.push(c.to_ascii_uppercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,8 @@
+//! Compile-time behaviour tests for the literal macros.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/*_pass.rs");
+    t.compile_fail("tests/ui/*_bad_*.rs");
+}
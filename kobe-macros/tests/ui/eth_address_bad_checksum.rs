@@ -0,0 +1,5 @@
+use kobe_eth::eth_address;
+
+fn main() {
+    let _ = eth_address!("0xd8da6bf26964af9d7eed9e03e53415d37aa96045");
+}
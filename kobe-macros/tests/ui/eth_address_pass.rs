@@ -0,0 +1,12 @@
+use kobe_eth::{Address, eth_address};
+
+const VITALIK: Address = eth_address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+fn main() {
+    assert_eq!(
+        VITALIK.to_checksum(),
+        "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+    );
+    let zero = eth_address!("0x0000000000000000000000000000000000000000");
+    assert_eq!(zero.as_bytes(), &[0u8; 20]);
+}
@@ -0,0 +1,5 @@
+use kobe_eth::eth_private_key;
+
+fn main() {
+    let _ = eth_private_key!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b");
+}
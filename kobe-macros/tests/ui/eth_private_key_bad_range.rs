@@ -0,0 +1,5 @@
+use kobe_eth::eth_private_key;
+
+fn main() {
+    let _ = eth_private_key!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+}
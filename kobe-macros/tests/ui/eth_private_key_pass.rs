@@ -0,0 +1,6 @@
+use kobe_eth::eth_private_key;
+
+fn main() {
+    let wallet = eth_private_key!("0x4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
+    assert_eq!(wallet.address(), "0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1");
+}
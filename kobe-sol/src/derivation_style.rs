@@ -18,7 +18,7 @@
 /// - **Standard (Phantom/Backpack)**: `m/44'/501'/{index}'/0'`
 /// - **Trust**: `m/44'/501'/{index}'`
 /// - **Ledger Live**: `m/44'/501'/{index}'/0'/0'`
-/// - **Legacy**: `m/501'/{index}'/0/0` (deprecated)
+/// - **Legacy**: `m/501'/{index}'/0'/0'` (deprecated)
 ///
 /// # Example
 ///
@@ -75,7 +75,7 @@ pub enum DerivationStyle {
 
     /// Legacy derivation path (deprecated).
     ///
-    /// Path format: `m/501'/{index}'/0/0`
+    /// Path format: `m/501'/{index}'/0'/0'`
     ///
     /// Used by older versions of Phantom and Sollet.
     /// Only use for recovering old wallets.
@@ -102,7 +102,7 @@ pub fn path(self, index: u32) -> String {
             Self::Standard => format!("m/44'/501'/{index}'/0'"),
             Self::Trust => format!("m/44'/501'/{index}'"),
             Self::LedgerLive => format!("m/44'/501'/{index}'/0'/0'"),
-            Self::Legacy => format!("m/501'/{index}'/0/0"),
+            Self::Legacy => format!("m/501'/{index}'/0'/0'"),
         }
     }
 
@@ -184,6 +184,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
 
 #[cfg(test)]
 #[allow(deprecated)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -214,9 +215,9 @@ fn test_ledger_live_paths() {
     #[test]
     fn test_legacy_paths() {
         let style = DerivationStyle::Legacy;
-        assert_eq!(style.path(0), "m/501'/0'/0/0");
-        assert_eq!(style.path(1), "m/501'/1'/0/0");
-        assert_eq!(style.path(10), "m/501'/10'/0/0");
+        assert_eq!(style.path(0), "m/501'/0'/0'/0'");
+        assert_eq!(style.path(1), "m/501'/1'/0'/0'");
+        assert_eq!(style.path(10), "m/501'/10'/0'/0'");
     }
 
     #[test]
@@ -12,6 +12,7 @@
 
 /// A derived Solana address with associated keys.
 #[derive(Debug, Clone)]
+#[allow(clippy::exhaustive_structs)]
 pub struct DerivedAddress {
     /// Derivation path used (e.g., `m/44'/501'/0'/0'`).
     pub path: String,
@@ -156,8 +157,8 @@ pub fn derive_many_with(
     /// This is the lowest-level derivation method, allowing full control
     /// over the derivation path.
     ///
-    /// **Note**: Ed25519 (Solana) only supports hardened derivation.
-    /// All path components will be treated as hardened.
+    /// **Note**: Ed25519 (Solana) only supports hardened derivation, so
+    /// every path component must be marked hardened.
     ///
     /// # Arguments
     ///
@@ -165,7 +166,8 @@ pub fn derive_many_with(
     ///
     /// # Errors
     ///
-    /// Returns an error if derivation fails.
+    /// Returns an error if the path has a non-hardened component or
+    /// derivation fails.
     pub fn derive_path(&self, path: &str) -> Result<DerivedAddress, Error> {
         let derived = DerivedKey::derive_path(self.wallet.seed(), path)?;
 
@@ -184,6 +186,7 @@ pub fn derive_path(&self, path: &str) -> Result<DerivedAddress, Error> {
 
 #[cfg(test)]
 #[allow(deprecated)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -270,4 +273,15 @@ fn test_different_styles_produce_different_addresses() {
         assert_ne!(standard.address, legacy.address);
         assert_ne!(trust.address, ledger_live.address);
     }
+
+    #[test]
+    fn test_derive_path_reproduces_every_style() {
+        let wallet = test_wallet();
+        let deriver = Deriver::new(&wallet);
+        for &style in DerivationStyle::all() {
+            let derived = deriver.derive_with(style, 2).unwrap();
+            let again = deriver.derive_path(&derived.path).unwrap();
+            assert_eq!(again.address, derived.address, "{style}");
+        }
+    }
 }
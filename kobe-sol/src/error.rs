@@ -18,6 +18,9 @@ pub enum Error {
     /// Key derivation failed (no details in no_std).
     #[cfg(not(feature = "alloc"))]
     Derivation,
+    /// Invalid derivation path, e.g. one with a non-hardened component.
+    #[cfg(feature = "alloc")]
+    InvalidDerivationPath(String),
     /// Invalid seed length.
     InvalidSeedLength,
     /// Invalid hex string format.
@@ -33,6 +36,8 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::Derivation(msg) => write!(f, "derivation error: {msg}"),
             #[cfg(not(feature = "alloc"))]
             Self::Derivation => write!(f, "derivation error"),
+            #[cfg(feature = "alloc")]
+            Self::InvalidDerivationPath(path) => write!(f, "invalid derivation path: {path}"),
             Self::InvalidSeedLength => write!(f, "invalid seed length"),
             Self::InvalidHex => write!(f, "invalid hex string"),
             Self::Signature => write!(f, "signature error"),
@@ -7,6 +7,7 @@
 //! - `std` (default): Enable standard library support
 //! - `alloc`: Enable heap allocation without full std (for `no_std` environments)
 //! - `rand`: Enable random key generation for `StandardWallet`
+//! - `wasm`: Enable `rand` with the browser RNG backend for `wasm32-unknown-unknown`
 //!
 //! # Usage
 //!
@@ -40,6 +41,8 @@
 mod slip10;
 #[cfg(feature = "alloc")]
 mod standard_wallet;
+#[cfg(feature = "alloc")]
+pub mod stellar;
 
 #[cfg(feature = "alloc")]
 pub use derivation_style::{DerivationStyle, ParseDerivationStyleError};
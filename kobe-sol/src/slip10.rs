@@ -3,6 +3,9 @@
 //! Implements SLIP-0010 for deriving Ed25519 keys from a seed.
 //! Reference: <https://github.com/satoshilabs/slips/blob/master/slip-0010.md>
 
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use ed25519_dalek::SigningKey;
 use hmac::{Hmac, Mac};
 use sha2::Sha512;
@@ -10,8 +13,10 @@
 
 use crate::Error;
 
+/// HMAC-SHA512 instance used by SLIP-0010.
 type HmacSha512 = Hmac<Sha512>;
 
+/// HMAC key for the Ed25519 master key derivation.
 const ED25519_CURVE: &[u8] = b"ed25519 seed";
 
 /// SLIP-0010 derived key pair.
@@ -112,7 +117,7 @@ pub fn derive_ledger_live_path(seed: &[u8], index: u32) -> Result<Self, Error> {
             .derive_hardened(0)
     }
 
-    /// Derive key at Legacy path: m/501'/index'/0/0 (deprecated)
+    /// Derive key at Legacy path: m/501'/index'/0'/0' (deprecated)
     ///
     /// Used by old Phantom/Sollet versions.
     /// Note: Ed25519 requires hardened derivation, so final components are hardened.
@@ -129,42 +134,20 @@ pub fn derive_legacy_path(seed: &[u8], index: u32) -> Result<Self, Error> {
 
     /// Derive key at a custom path.
     ///
-    /// Path format: `m/44'/501'/0'/0'` (all components must be hardened for Ed25519)
+    /// Path format: `m/44'/501'/0'/0'`. Ed25519 only supports hardened
+    /// derivation, so every component must carry a `'` or `h` marker.
     ///
     /// # Errors
     ///
-    /// Returns an error if the path is invalid or derivation fails.
+    /// Returns [`Error::InvalidDerivationPath`] if the path is malformed or
+    /// contains a non-hardened component, checked before any derivation.
     pub fn derive_path(seed: &[u8], path: &str) -> Result<Self, Error> {
-        let path = path.trim();
-        if !path.starts_with("m/") && !path.starts_with("m") {
-            return Err(Error::Derivation(alloc::format!(
-                "invalid path: must start with 'm'"
-            )));
-        }
-
-        let components = path
-            .trim_start_matches("m/")
-            .trim_start_matches('m')
-            .split('/')
-            .filter(|s| !s.is_empty());
-
-        let mut current = Self::from_seed(seed)?;
-
-        for component in components {
-            let index = Self::parse_path_component(component)?;
-            current = current.derive_hardened(index)?;
-        }
-
-        Ok(current)
-    }
-
-    /// Parse a path component like "44'" or "501'" to an index.
-    fn parse_path_component(component: &str) -> Result<u32, Error> {
-        // Ed25519 only supports hardened derivation
-        let component = component.trim_end_matches('\'').trim_end_matches('h');
-        component
-            .parse::<u32>()
-            .map_err(|_| Error::Derivation(alloc::format!("invalid path component: {component}")))
+        let indices = parse_hardened_path(path)?;
+        indices
+            .into_iter()
+            .try_fold(Self::from_seed(seed)?, |key, index| {
+                key.derive_hardened(index)
+            })
     }
 
     /// Convert to Ed25519 signing key.
@@ -173,7 +156,45 @@ pub fn to_signing_key(&self) -> SigningKey {
     }
 }
 
+/// A parsed path component: the index and whether it is hardened.
+type Component = (u32, bool);
+
+/// Parse a path such as `m/44'/501'/0'` into its components.
+fn parse_path(path: &str) -> Option<Vec<Component>> {
+    let mut components = path.trim().split('/');
+    if components.next() != Some("m") {
+        return None;
+    }
+    components
+        .map(|component| {
+            let (digits, hardened) = match component
+                .strip_suffix('\'')
+                .or_else(|| component.strip_suffix('h'))
+            {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+            let index = digits.parse::<u32>().ok().filter(|&i| i < 1 << 31)?;
+            Some((index, hardened))
+        })
+        .collect()
+}
+
+/// Check whether every component of a parsed path is hardened.
+fn is_all_hardened(components: &[Component]) -> bool {
+    components.iter().all(|&(_, hardened)| hardened)
+}
+
+/// Parse a fully hardened path such as `m/44'/501'/0'` into its indices.
+fn parse_hardened_path(path: &str) -> Result<Vec<u32>, Error> {
+    let components = parse_path(path)
+        .filter(|components| is_all_hardened(components))
+        .ok_or_else(|| Error::InvalidDerivationPath(path.to_string()))?;
+    Ok(components.into_iter().map(|(index, _)| index).collect())
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -214,4 +235,42 @@ fn test_legacy_path_derivation() {
         let derived = DerivedKey::derive_legacy_path(&seed, 0).unwrap();
         assert_eq!(derived.private_key.len(), 32);
     }
+
+    #[test]
+    fn test_derive_path_matches_fixed_paths() {
+        let seed = [7u8; 64];
+        let custom = DerivedKey::derive_path(&seed, "m/44'/501'/3'/0'").unwrap();
+        let standard = DerivedKey::derive_standard_path(&seed, 3).unwrap();
+        assert_eq!(*custom.private_key, *standard.private_key);
+
+        let with_h = DerivedKey::derive_path(&seed, "m/44h/501h/3h/0h").unwrap();
+        assert_eq!(*with_h.private_key, *standard.private_key);
+    }
+
+    #[test]
+    fn test_is_all_hardened() {
+        assert!(is_all_hardened(&parse_path("m/44'/501'/0'/0'").unwrap()));
+        assert!(is_all_hardened(&parse_path("m").unwrap()));
+        assert!(!is_all_hardened(&parse_path("m/44'/501'/0'/0/0").unwrap()));
+    }
+
+    #[test]
+    fn test_derive_path_rejects_non_hardened() {
+        let seed = [7u8; 64];
+        for path in [
+            "m/44'/501'/0'/0/0",
+            "m/44'/501'/0'/0",
+            "44'/501'",
+            "m/44'/2147483648'",
+            "m//0'",
+        ] {
+            assert!(
+                matches!(
+                    DerivedKey::derive_path(&seed, path),
+                    Err(Error::InvalidDerivationPath(_))
+                ),
+                "{path}"
+            );
+        }
+    }
 }
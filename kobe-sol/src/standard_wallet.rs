@@ -78,6 +78,13 @@ pub fn address(&self) -> String {
         bs58::encode(verifying_key.as_bytes()).into_string()
     }
 
+    /// Get the Stellar account ID (`G...`) for the same Ed25519 key.
+    #[inline]
+    #[must_use]
+    pub fn stellar_address(&self) -> String {
+        crate::stellar::account_id(self.signing_key.verifying_key().as_bytes())
+    }
+
     /// Get the secret key as raw bytes (zeroized on drop).
     #[inline]
     #[must_use]
@@ -102,6 +109,7 @@ pub fn pubkey_hex(&self) -> String {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,98 @@
+//! Stellar account IDs for Ed25519 keys.
+//!
+//! Stellar encodes keys as "strkeys": a version byte, the 32-byte key and a
+//! CRC16-XModem checksum, base32-encoded. Account IDs use version byte
+//! `6 << 3` and therefore start with `G`. Stellar wallets derive keys with
+//! SLIP-10 at `m/44'/148'/x'` (SEP-0005).
+
+use alloc::string::String;
+
+/// Strkey version byte of an Ed25519 account ID (`G...`).
+pub const ACCOUNT_ID_VERSION: u8 = 6 << 3;
+
+/// RFC 4648 base32 alphabet.
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Encode an Ed25519 public key as a Stellar account ID.
+#[must_use]
+pub fn account_id(public_key: &[u8; 32]) -> String {
+    let mut payload = [0u8; 35];
+    payload[0] = ACCOUNT_ID_VERSION;
+    payload[1..33].copy_from_slice(public_key);
+    let checksum = crc16_xmodem(&payload[..33]);
+    payload[33..].copy_from_slice(&checksum.to_le_bytes());
+    base32_encode(&payload)
+}
+
+/// CRC16-XModem (polynomial `0x1021`, initial value `0`).
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, &byte| {
+        (0..8).fold(crc ^ (u16::from(byte) << 8), |acc, _| {
+            if acc & 0x8000 == 0 {
+                acc << 1
+            } else {
+                (acc << 1) ^ 0x1021
+            }
+        })
+    })
+}
+
+/// Base32-encode without padding.
+fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(5) * 8);
+    let mut buffer = 0u16;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(char::from(
+                BASE32_ALPHABET[usize::from((buffer >> bits) & 0x1f)],
+            ));
+        }
+    }
+    if bits > 0 {
+        out.push(char::from(
+            BASE32_ALPHABET[usize::from((buffer << (5 - bits)) & 0x1f)],
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::slip10::DerivedKey;
+    use kobe::Wallet;
+
+    #[test]
+    fn test_zero_key() {
+        assert_eq!(
+            account_id(&[0u8; 32]),
+            "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
+        );
+    }
+
+    #[test]
+    fn test_sep5_vector() {
+        // SEP-0005 test 1, account 0.
+        let wallet = Wallet::from_mnemonic(
+            "illness spike retreat truth genius clock brain pass fit cave bargain toe",
+            None,
+        )
+        .unwrap();
+        let key = DerivedKey::derive_path(wallet.seed(), "m/44'/148'/0'").unwrap();
+        let public_key = key.to_signing_key().verifying_key();
+        assert_eq!(
+            account_id(public_key.as_bytes()),
+            "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6"
+        );
+    }
+
+    #[test]
+    fn test_crc16_xmodem() {
+        assert_eq!(crc16_xmodem(b"123456789"), 0x31c3);
+    }
+}
@@ -0,0 +1,384 @@
+//! Denomination-tagged amounts.
+//!
+//! Satoshi and Wei are both plain integers, which makes them easy to mix up
+//! at call sites. [`Amount`] records the denomination alongside the value so
+//! that reading an amount in the wrong unit fails instead of silently
+//! reinterpreting it.
+
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::Error;
+
+/// The base unit an [`Amount`] is denominated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Denomination {
+    /// Bitcoin base unit (1 BTC = 10^8 satoshi).
+    Satoshi,
+    /// Ethereum base unit (1 ETH = 10^18 wei).
+    Wei,
+}
+
+impl Denomination {
+    /// Get the unit name.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Satoshi => "sat",
+            Self::Wei => "wei",
+        }
+    }
+
+    /// Get the number of decimal places between the whole coin and this
+    /// base unit (8 for satoshi, 18 for wei).
+    #[inline]
+    #[must_use]
+    pub const fn decimals(self) -> u32 {
+        match self {
+            Self::Satoshi => 8,
+            Self::Wei => 18,
+        }
+    }
+}
+
+impl fmt::Display for Denomination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An amount of a cryptocurrency in its base unit.
+///
+/// Amounts are ordered by value, but only within a single denomination:
+/// a satoshi amount and a wei amount are unordered, so `<` and `>` between
+/// them are `false`. There is no `Ord`; sort with [`Amount::checked_cmp`].
+///
+/// # Example
+///
+/// ```
+/// use kobe::Amount;
+///
+/// let fee = Amount::from_satoshi(1_000);
+/// assert_eq!(fee.as_satoshi(), Some(1_000));
+/// assert_eq!(fee.as_wei(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Amount {
+    /// Value in base units.
+    value: u128,
+    /// Base unit of `value`.
+    denomination: Denomination,
+}
+
+impl Amount {
+    /// Create an amount in satoshi.
+    #[inline]
+    #[must_use]
+    pub const fn from_satoshi(satoshi: u64) -> Self {
+        Self {
+            value: satoshi as u128,
+            denomination: Denomination::Satoshi,
+        }
+    }
+
+    /// Create an amount in wei.
+    #[inline]
+    #[must_use]
+    pub const fn from_wei(wei: u128) -> Self {
+        Self {
+            value: wei,
+            denomination: Denomination::Wei,
+        }
+    }
+
+    /// Get the amount in satoshi.
+    ///
+    /// Returns `None` if the amount is not denominated in satoshi.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // satoshi values are constructed from `u64`
+    pub const fn as_satoshi(&self) -> Option<u64> {
+        match self.denomination {
+            Denomination::Satoshi => Some(self.value as u64),
+            Denomination::Wei => None,
+        }
+    }
+
+    /// Get the amount in wei.
+    ///
+    /// Returns `None` if the amount is not denominated in wei.
+    #[inline]
+    #[must_use]
+    pub const fn as_wei(&self) -> Option<u128> {
+        match self.denomination {
+            Denomination::Wei => Some(self.value),
+            Denomination::Satoshi => None,
+        }
+    }
+
+    /// Get the denomination of this amount.
+    #[inline]
+    #[must_use]
+    pub const fn denomination(&self) -> Denomination {
+        self.denomination
+    }
+
+    /// Parse a whole-coin decimal string (e.g. `"0.5"` BTC) into base units
+    /// of `denomination`.
+    ///
+    /// Only ASCII digits and at most one `.` are accepted. Thousands
+    /// separators, signs, whitespace, exponents and non-ASCII digits are all
+    /// rejected, so `"1,000.5"` and `"1.000,5"` can never be misread under
+    /// a different locale convention.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidEncoding`] if the string is malformed, has
+    /// more fractional digits than the denomination allows, or overflows.
+    pub fn from_str_in(s: &str, denomination: Denomination) -> Result<Self, Error> {
+        let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+        let decimals = denomination.decimals();
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty())
+            || !is_digits(whole)
+            || !is_digits(fraction)
+            || fraction.len() > decimals as usize
+        {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let mut digits: u128 = 0;
+        for digit in whole.bytes().chain(fraction.bytes()) {
+            digits = digits
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(u128::from(digit - b'0')))
+                .ok_or(Error::InvalidEncoding)?;
+        }
+        // `fraction.len() <= decimals` was checked above.
+        #[allow(clippy::cast_possible_truncation)]
+        let scale = 10u128.pow(decimals - fraction.len() as u32);
+        let value = digits.checked_mul(scale).ok_or(Error::InvalidEncoding)?;
+
+        match denomination {
+            Denomination::Satoshi => u64::try_from(value)
+                .map(Self::from_satoshi)
+                .map_err(|_| Error::InvalidEncoding),
+            Denomination::Wei => Ok(Self::from_wei(value)),
+        }
+    }
+
+    /// Compute `self * numerator / denominator`, rounding down.
+    ///
+    /// The product is computed at 192-bit width, so e.g. a 0.3% fee
+    /// (`30 / 10_000`) of any wei amount is exact up to the final floor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DivisionByZero`] if `denominator` is zero, or
+    /// [`Error::Overflow`] if the result does not fit the denomination.
+    pub fn mul_ratio(&self, numerator: u64, denominator: u64) -> Result<Self, Error> {
+        if denominator == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        let value = mul_div(self.value, numerator, denominator).ok_or(Error::Overflow)?;
+        match self.denomination {
+            Denomination::Satoshi => u64::try_from(value)
+                .map(Self::from_satoshi)
+                .map_err(|_| Error::Overflow),
+            Denomination::Wei => Ok(Self::from_wei(value)),
+        }
+    }
+
+    /// Compare two amounts, returning `None` if their denominations differ.
+    #[inline]
+    #[must_use]
+    pub fn checked_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.denomination == other.denomination).then(|| self.value.cmp(&other.value))
+    }
+}
+
+impl PartialOrd for Amount {
+    /// Compare two amounts of the same denomination; see
+    /// [`Amount::checked_cmp`].
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.checked_cmp(other)
+    }
+}
+
+/// Compute `floor(value * numerator / denominator)` with a 192-bit
+/// intermediate, or `None` if the quotient overflows `u128`.
+///
+/// `denominator` must be non-zero.
+#[allow(clippy::cast_possible_truncation)] // limbs are split off explicitly
+fn mul_div(value: u128, numerator: u64, denominator: u64) -> Option<u128> {
+    let low = u128::from(value as u64) * u128::from(numerator);
+    let high = (value >> 64) * u128::from(numerator) + (low >> 64);
+    // Big-endian 64-bit limbs of the product.
+    let limbs = [(high >> 64) as u64, high as u64, low as u64];
+
+    let divisor = u128::from(denominator);
+    let mut quotient = [0u64; 3];
+    let mut remainder = 0u128;
+    for (limb, digit) in limbs.iter().zip(&mut quotient) {
+        let current = (remainder << 64) | u128::from(*limb);
+        *digit = (current / divisor) as u64;
+        remainder = current % divisor;
+    }
+    (quotient[0] == 0).then(|| (u128::from(quotient[1]) << 64) | u128::from(quotient[2]))
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.denomination)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_satoshi_roundtrip() {
+        let amount = Amount::from_satoshi(u64::MAX);
+        assert_eq!(amount.as_satoshi(), Some(u64::MAX));
+        assert_eq!(amount.denomination(), Denomination::Satoshi);
+    }
+
+    #[test]
+    fn test_wei_roundtrip() {
+        let amount = Amount::from_wei(u128::MAX);
+        assert_eq!(amount.as_wei(), Some(u128::MAX));
+        assert_eq!(amount.denomination(), Denomination::Wei);
+    }
+
+    #[test]
+    fn test_sort_wei() {
+        let mut amounts = [
+            Amount::from_wei(30),
+            Amount::from_wei(1),
+            Amount::from_wei(u128::MAX),
+            Amount::from_wei(0),
+        ];
+        amounts.sort_by(|a, b| a.checked_cmp(b).unwrap());
+
+        let values = amounts.map(|a| a.as_wei());
+        assert_eq!(values, [Some(0), Some(1), Some(30), Some(u128::MAX)]);
+        assert!(Amount::from_wei(2) > Amount::from_wei(1));
+    }
+
+    #[test]
+    fn test_mul_ratio() {
+        // 0.3% of 10^38 wei: the product overflows `u128`, the result does not.
+        let amount = Amount::from_wei(10u128.pow(38));
+        assert_eq!(
+            amount.mul_ratio(30, 10_000).unwrap(),
+            Amount::from_wei(3 * 10u128.pow(35))
+        );
+        assert_eq!(
+            Amount::from_wei(u128::MAX)
+                .mul_ratio(u64::MAX, u64::MAX)
+                .unwrap(),
+            Amount::from_wei(u128::MAX)
+        );
+
+        // Floor division.
+        assert_eq!(
+            Amount::from_satoshi(10_001).mul_ratio(30, 10_000).unwrap(),
+            Amount::from_satoshi(30)
+        );
+        assert_eq!(
+            Amount::from_satoshi(1).mul_ratio(30, 10_000).unwrap(),
+            Amount::from_satoshi(0)
+        );
+
+        assert!(matches!(
+            Amount::from_wei(1).mul_ratio(1, 0),
+            Err(Error::DivisionByZero)
+        ));
+        assert!(matches!(
+            Amount::from_wei(u128::MAX).mul_ratio(2, 1),
+            Err(Error::Overflow)
+        ));
+        assert!(matches!(
+            Amount::from_satoshi(u64::MAX).mul_ratio(2, 1),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_checked_cmp() {
+        assert_eq!(
+            Amount::from_satoshi(1).checked_cmp(&Amount::from_satoshi(2)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Amount::from_satoshi(1).checked_cmp(&Amount::from_wei(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cross_denomination_is_unordered() {
+        let satoshi = Amount::from_satoshi(1);
+        let wei = Amount::from_wei(2);
+        assert_eq!(satoshi.partial_cmp(&wei), None);
+        assert_eq!(wei.partial_cmp(&satoshi), None);
+    }
+
+    #[test]
+    fn test_from_str_in() {
+        assert_eq!(
+            Amount::from_str_in("1000.5", Denomination::Satoshi).unwrap(),
+            Amount::from_satoshi(100_050_000_000)
+        );
+        assert_eq!(
+            Amount::from_str_in("0.00000001", Denomination::Satoshi).unwrap(),
+            Amount::from_satoshi(1)
+        );
+        assert_eq!(
+            Amount::from_str_in(".5", Denomination::Wei).unwrap(),
+            Amount::from_wei(500_000_000_000_000_000)
+        );
+        assert_eq!(
+            Amount::from_str_in("2", Denomination::Wei).unwrap(),
+            Amount::from_wei(2_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_from_str_in_rejects_separators() {
+        for input in [
+            "1,000", "1.000,5", "1,5", "1_000", "1 000", "1.0.0", "", ".", "+1", "-1", "1e8",
+            "\u{0661}",
+        ] {
+            assert!(
+                matches!(
+                    Amount::from_str_in(input, Denomination::Satoshi),
+                    Err(Error::InvalidEncoding)
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_in_precision_and_range() {
+        assert!(Amount::from_str_in("0.000000001", Denomination::Satoshi).is_err());
+        assert!(Amount::from_str_in("184467440737.09551616", Denomination::Satoshi).is_err());
+        assert_eq!(
+            Amount::from_str_in("184467440737.09551615", Denomination::Satoshi).unwrap(),
+            Amount::from_satoshi(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_denomination_mismatch() {
+        assert_eq!(Amount::from_satoshi(100).as_wei(), None);
+        assert_eq!(Amount::from_wei(100).as_satoshi(), None);
+        assert_ne!(Amount::from_satoshi(100), Amount::from_wei(100));
+    }
+}
@@ -0,0 +1,224 @@
+//! Fixed-width integer and `CompactSize` varint helpers.
+//!
+//! Bitcoin serializes integers little-endian while Ethereum RLP is
+//! big-endian; these helpers spell the byte order out at each call site
+//! instead of hand-rolling it per field.
+//!
+//! Readers take the leading bytes of the input and return `None` when it is
+//! too short, so callers can advance their own cursor.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Error;
+
+/// Append `value` as 4 little-endian bytes.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn write_u32_le(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Append `value` as 8 little-endian bytes.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn write_u64_le(out: &mut Vec<u8>, value: u64) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Append `value` as 4 big-endian bytes.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn write_u32_be(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Append `value` as 8 big-endian bytes.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn write_u64_be(out: &mut Vec<u8>, value: u64) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Read a little-endian `u32` from the first 4 bytes.
+#[inline]
+#[must_use]
+pub fn read_u32_le(bytes: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(*bytes.first_chunk()?))
+}
+
+/// Read a little-endian `u64` from the first 8 bytes.
+#[inline]
+#[must_use]
+pub fn read_u64_le(bytes: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(*bytes.first_chunk()?))
+}
+
+/// Read a big-endian `u32` from the first 4 bytes.
+#[inline]
+#[must_use]
+pub fn read_u32_be(bytes: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(*bytes.first_chunk()?))
+}
+
+/// Read a big-endian `u64` from the first 8 bytes.
+#[inline]
+#[must_use]
+pub fn read_u64_be(bytes: &[u8]) -> Option<u64> {
+    Some(u64::from_be_bytes(*bytes.first_chunk()?))
+}
+
+/// Maximum length of a Bitcoin `CompactSize` varint.
+pub const VARINT_MAX_LEN: usize = 9;
+
+/// Length of the Bitcoin `CompactSize` encoding of `value`.
+#[inline]
+#[must_use]
+pub const fn varint_len(value: u64) -> usize {
+    match value {
+        0..=0xfc => 1,
+        0xfd..=0xffff => 3,
+        0x1_0000..=0xffff_ffff => 5,
+        _ => VARINT_MAX_LEN,
+    }
+}
+
+/// Encode `value` as a Bitcoin `CompactSize` varint without allocating.
+///
+/// Values below `0xfd` take one byte; larger values get a `0xfd`, `0xfe`
+/// or `0xff` prefix followed by 2, 4 or 8 little-endian bytes. Returns a
+/// buffer whose first [`varint_len`] bytes hold the encoding.
+#[must_use]
+pub fn varint_bytes(value: u64) -> [u8; VARINT_MAX_LEN] {
+    let mut buf = [0u8; VARINT_MAX_LEN];
+    let le = value.to_le_bytes();
+    match varint_len(value) {
+        1 => buf[0] = le[0],
+        3 => {
+            buf[0] = 0xfd;
+            buf[1..3].copy_from_slice(&le[..2]);
+        }
+        5 => {
+            buf[0] = 0xfe;
+            buf[1..5].copy_from_slice(&le[..4]);
+        }
+        _ => {
+            buf[0] = 0xff;
+            buf[1..].copy_from_slice(&le);
+        }
+    }
+    buf
+}
+
+/// Append `value` as a Bitcoin `CompactSize` varint.
+///
+/// See [`varint_bytes`] for the encoding.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn varint_encode(out: &mut Vec<u8>, value: u64) {
+    out.extend_from_slice(&varint_bytes(value)[..varint_len(value)]);
+}
+
+/// Decode a Bitcoin `CompactSize` varint from the start of `bytes`.
+///
+/// Returns the value and the number of bytes consumed, or `None` if the
+/// input is truncated or the encoding is not minimal.
+#[must_use]
+pub fn varint_decode(bytes: &[u8]) -> Option<(u64, usize)> {
+    let (&prefix, rest) = bytes.split_first()?;
+    let (value, len, min) = match prefix {
+        0xfd => (u64::from(u16::from_le_bytes(*rest.first_chunk()?)), 3, 0xfd),
+        0xfe => (u64::from(read_u32_le(rest)?), 5, 0x1_0000),
+        0xff => (read_u64_le(rest)?, 9, 0x1_0000_0000),
+        _ => return Some((u64::from(prefix), 1)),
+    };
+    (value >= min).then_some((value, len))
+}
+
+/// Decode a `CompactSize` element count, such as a transaction's input or
+/// output count, from the start of `bytes`.
+///
+/// Every element takes at least one byte, so a count larger than the bytes
+/// left after the varint cannot be valid. Rejecting it here keeps a
+/// malicious count from driving a huge allocation.
+///
+/// Returns the count and the number of bytes consumed.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidEncoding`] if the varint is truncated or not
+/// minimal, and [`Error::InvalidLength`] if the count exceeds the
+/// remaining input.
+pub fn varint_decode_count(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    let (value, len) = varint_decode(bytes).ok_or(Error::InvalidEncoding)?;
+    let remaining = bytes.len() - len;
+    match usize::try_from(value) {
+        Ok(count) if count <= remaining => Ok((count, len)),
+        _ => Err(Error::InvalidLength),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_width_round_trip() {
+        let mut out = Vec::new();
+        write_u32_le(&mut out, 0x0102_0304);
+        write_u64_be(&mut out, 0x0102_0304_0506_0708);
+        assert_eq!(&out[..4], &[0x04, 0x03, 0x02, 0x01]);
+        assert_eq!(read_u32_le(&out), Some(0x0102_0304));
+        assert_eq!(read_u64_be(&out[4..]), Some(0x0102_0304_0506_0708));
+        assert_eq!(read_u32_be(&[0, 0, 1, 0]), Some(0x100));
+        assert_eq!(read_u64_le(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_varint_boundaries() {
+        let cases: [(u64, &[u8]); 6] = [
+            (0, &[0x00]),
+            (0xfc, &[0xfc]),
+            (0xfd, &[0xfd, 0xfd, 0x00]),
+            (0xffff, &[0xfd, 0xff, 0xff]),
+            (0x1_0000, &[0xfe, 0x00, 0x00, 0x01, 0x00]),
+            (0x1_0000_0000, &[0xff, 0, 0, 0, 0, 1, 0, 0, 0]),
+        ];
+        for (value, encoded) in cases {
+            let mut out = Vec::new();
+            varint_encode(&mut out, value);
+            assert_eq!(out, encoded, "encode {value:#x}");
+            assert_eq!(varint_len(value), encoded.len());
+            assert_eq!(varint_decode(encoded), Some((value, encoded.len())));
+        }
+    }
+
+    #[test]
+    fn test_varint_rejects_non_minimal_and_truncated() {
+        assert_eq!(varint_decode(&[0xfd, 0xfc, 0x00]), None);
+        assert_eq!(varint_decode(&[0xfe, 0xff, 0xff, 0x00, 0x00]), None);
+        assert_eq!(varint_decode(&[0xfd, 0x00]), None);
+        assert_eq!(varint_decode(&[]), None);
+    }
+
+    #[test]
+    fn test_varint_decode_count_bounds() {
+        // 0xffffffff inputs declared in a 10-byte buffer.
+        let mut huge = vec![0xfe, 0xff, 0xff, 0xff, 0xff];
+        huge.resize(10, 0);
+        assert!(matches!(
+            varint_decode_count(&huge),
+            Err(Error::InvalidLength)
+        ));
+
+        assert!(matches!(varint_decode_count(&[3, 0, 0, 0]), Ok((3, 1))));
+        assert!(matches!(
+            varint_decode_count(&[3, 0, 0]),
+            Err(Error::InvalidLength)
+        ));
+        assert!(matches!(
+            varint_decode_count(&[0xfd, 0x01]),
+            Err(Error::InvalidEncoding)
+        ));
+    }
+}
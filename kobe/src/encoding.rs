@@ -0,0 +1,47 @@
+//! Chain-neutral address encodings.
+
+use alloc::string::String;
+
+use bech32::{Bech32, Hrp};
+
+use crate::Error;
+
+/// Encode raw bytes as a plain Bech32 string under `hrp`.
+///
+/// Unlike segwit addresses there is no witness-version prefix: the 8-bit
+/// `data` is regrouped into 5-bit words and checksummed with the original
+/// Bech32 constant. This is the account address format used by Cosmos-SDK
+/// chains (`cosmos1...`) and Avalanche X/P-chain (`avax1...`).
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidEncoding`] if `hrp` is not a valid human-readable
+/// part or the result would exceed the Bech32 length limit.
+pub fn bech32_encode_data(hrp: &str, data: &[u8]) -> Result<String, Error> {
+    let parsed = Hrp::parse(hrp).map_err(|_| Error::InvalidEncoding)?;
+    bech32::encode::<Bech32>(parsed, data).map_err(|_| Error::InvalidEncoding)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32_encode_data_cosmos() {
+        let hash = [
+            0x28, 0xff, 0x5c, 0x6d, 0x57, 0xd8, 0xcf, 0xd4, 0x92, 0xb6, 0xfb, 0x42, 0x61, 0x45,
+            0x36, 0xed, 0x64, 0x8e, 0x01, 0xfd,
+        ];
+        assert_eq!(
+            bech32_encode_data("cosmos", &hash).unwrap(),
+            "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+        );
+        assert!(
+            bech32_encode_data("avax", &hash)
+                .unwrap()
+                .starts_with("avax1")
+        );
+        assert!(bech32_encode_data("", &hash).is_err());
+    }
+}
@@ -10,6 +10,20 @@ pub enum Error {
     Mnemonic(bip39::Error),
     /// Invalid word count for mnemonic.
     InvalidWordCount(usize),
+    /// Malformed payment URI.
+    InvalidUri,
+    /// Malformed numeric or byte encoding.
+    InvalidEncoding,
+    /// Seed does not produce a valid master key.
+    InvalidSeed,
+    /// Encoded public key is not a valid curve point.
+    InvalidPublicKey,
+    /// Declared length or count exceeds the available input.
+    InvalidLength,
+    /// Division by a zero denominator.
+    DivisionByZero,
+    /// Arithmetic result does not fit its type.
+    Overflow,
 }
 
 impl fmt::Display for Error {
@@ -19,6 +33,13 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Self::InvalidWordCount(n) => {
                 write!(f, "invalid word count {n}, must be 12, 15, 18, 21, or 24")
             }
+            Self::InvalidUri => write!(f, "invalid payment URI"),
+            Self::InvalidEncoding => write!(f, "invalid encoding"),
+            Self::InvalidSeed => write!(f, "seed does not produce a valid master key"),
+            Self::InvalidPublicKey => write!(f, "invalid public key"),
+            Self::InvalidLength => write!(f, "declared length exceeds available input"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::Overflow => write!(f, "arithmetic overflow"),
         }
     }
 }
@@ -28,7 +49,14 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Mnemonic(e) => Some(e),
-            Self::InvalidWordCount(_) => None,
+            Self::InvalidWordCount(_)
+            | Self::InvalidUri
+            | Self::InvalidEncoding
+            | Self::InvalidSeed
+            | Self::InvalidPublicKey
+            | Self::InvalidLength
+            | Self::DivisionByZero
+            | Self::Overflow => None,
         }
     }
 }
@@ -0,0 +1,92 @@
+//! Hash functions with a fixed-size output.
+//!
+//! [`FixedHash`] lets checksum code be written once over "a hash with an
+//! `N`-byte output", e.g. SHA-256 versus double-SHA-256. Any [`Digest`]
+//! can be used through the [`Single`] and [`Double`] marker types.
+
+use core::marker::PhantomData;
+
+use digest::typenum::Unsigned;
+use digest::{Digest, Output, OutputSizeUser};
+
+/// A hash function with a fixed output length.
+pub trait FixedHash {
+    /// Output length in bytes.
+    const OUTPUT_LEN: usize;
+
+    /// Hash output, [`Self::OUTPUT_LEN`] bytes long.
+    type Output: AsRef<[u8]>;
+
+    /// Hash `data`.
+    fn hash(data: &[u8]) -> Self::Output;
+}
+
+/// A single application of the digest `D`, e.g. `Single<Sha256>`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Single<D>(PhantomData<D>);
+
+/// The digest `D` applied twice, e.g. `Double<Sha256>` for Bitcoin's
+/// `SHA256d`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Double<D>(PhantomData<D>);
+
+impl<D: Digest> FixedHash for Single<D> {
+    const OUTPUT_LEN: usize = <D as OutputSizeUser>::OutputSize::USIZE;
+
+    type Output = Output<D>;
+
+    #[inline]
+    fn hash(data: &[u8]) -> Self::Output {
+        D::digest(data)
+    }
+}
+
+impl<D: Digest> FixedHash for Double<D> {
+    const OUTPUT_LEN: usize = <D as OutputSizeUser>::OutputSize::USIZE;
+
+    type Output = Output<D>;
+
+    #[inline]
+    fn hash(data: &[u8]) -> Self::Output {
+        D::digest(D::digest(data))
+    }
+}
+
+/// Compute a 4-byte checksum of `data`: the first four bytes of `H(data)`.
+///
+/// With `H = Double<Sha256>` this is the Base58Check checksum.
+#[must_use]
+pub fn checksum<H: FixedHash>(data: &[u8]) -> [u8; 4] {
+    const { assert!(H::OUTPUT_LEN >= 4, "hash output is shorter than a checksum") };
+    let digest = H::hash(data);
+    let mut out = [0u8; 4];
+    out.copy_from_slice(&digest.as_ref()[..4]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    #[test]
+    fn test_output_len() {
+        assert_eq!(<Single<Sha256> as FixedHash>::OUTPUT_LEN, 32);
+        assert_eq!(<Double<Sha256> as FixedHash>::OUTPUT_LEN, 32);
+        assert_eq!(Single::<Sha256>::hash(b"").len(), 32);
+    }
+
+    #[test]
+    fn test_base58check_checksum() {
+        // Version byte 0 and an all-zero hash160: `1111111111111111111114oLvT2`.
+        let payload = [0u8; 21];
+        assert_eq!(
+            checksum::<Double<Sha256>>(&payload),
+            [0x94, 0xa0, 0x09, 0x11]
+        );
+        assert_eq!(
+            checksum::<Single<Sha256>>(&payload),
+            [0xc9, 0x02, 0x32, 0x58]
+        );
+    }
+}
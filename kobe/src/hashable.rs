@@ -0,0 +1,112 @@
+//! Preimage construction for hashing typed fields.
+//!
+//! Signing preimages are usually built by concatenating typed fields.
+//! [`Hashable`] lets such types feed themselves directly into any
+//! [`Digest`], avoiding intermediate buffers.
+
+use digest::{Digest, Output};
+
+/// A value that can be written into a hash function.
+///
+/// Integers are written in big-endian byte order.
+pub trait Hashable {
+    /// Feed this value's canonical byte encoding into `hasher`.
+    fn write_to(&self, hasher: &mut impl Digest);
+
+    /// Hash this value with a fresh instance of `D`.
+    fn hash<D: Digest>(&self) -> Output<D> {
+        let mut hasher = D::new();
+        self.write_to(&mut hasher);
+        hasher.finalize()
+    }
+}
+
+impl<T: Hashable + ?Sized> Hashable for &T {
+    #[inline]
+    fn write_to(&self, hasher: &mut impl Digest) {
+        (**self).write_to(hasher);
+    }
+}
+
+impl Hashable for [u8] {
+    #[inline]
+    fn write_to(&self, hasher: &mut impl Digest) {
+        hasher.update(self);
+    }
+}
+
+impl<const N: usize> Hashable for [u8; N] {
+    #[inline]
+    fn write_to(&self, hasher: &mut impl Digest) {
+        hasher.update(self);
+    }
+}
+
+/// Implement [`Hashable`] for integer types using big-endian encoding.
+macro_rules! impl_hashable_int {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Hashable for $ty {
+                #[inline]
+                fn write_to(&self, hasher: &mut impl Digest) {
+                    hasher.update(self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_hashable_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use sha3::Keccak256;
+
+    /// Example preimage made of typed fields.
+    struct Transfer {
+        /// Transfer nonce.
+        nonce: u64,
+        /// Recipient identifier.
+        to: [u8; 20],
+        /// Transfer amount.
+        amount: u128,
+        /// Arbitrary payload.
+        data: &'static [u8],
+    }
+
+    impl Hashable for Transfer {
+        fn write_to(&self, hasher: &mut impl Digest) {
+            self.nonce.write_to(hasher);
+            self.to.write_to(hasher);
+            self.amount.write_to(hasher);
+            self.data.write_to(hasher);
+        }
+    }
+
+    #[test]
+    fn test_struct_matches_manual_concat() {
+        let tx = Transfer {
+            nonce: 7,
+            to: [0xab; 20],
+            amount: 1_000_000,
+            data: b"hello",
+        };
+
+        let mut buf = [0u8; 8 + 20 + 16 + 5];
+        buf[..8].copy_from_slice(&7u64.to_be_bytes());
+        buf[8..28].copy_from_slice(&[0xab; 20]);
+        buf[28..44].copy_from_slice(&1_000_000u128.to_be_bytes());
+        buf[44..].copy_from_slice(b"hello");
+
+        assert_eq!(tx.hash::<Keccak256>(), Keccak256::digest(buf));
+    }
+
+    #[test]
+    fn test_integers_are_big_endian() {
+        let mut hasher = Keccak256::new();
+        0x0102_0304u32.write_to(&mut hasher);
+        assert_eq!(hasher.finalize(), Keccak256::digest([1, 2, 3, 4]));
+    }
+}
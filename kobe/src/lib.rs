@@ -7,6 +7,11 @@
 //!
 //! - `std` (default): Enable standard library support
 //! - `alloc`: Enable heap allocation without full std (for `no_std` environments)
+//! - `wasm`: Enable the browser RNG backend and the [`rng`] module
+//! - `fingerprint`: Enable [`Wallet::seed_fingerprint`] for confirming passphrases
+//! - `points`: Enable public key point compression in [`secp256k1`]
+//! - `test-util`: Enable [`rng::DeterministicRng`] for reproducible tests and examples
+//! - `test-vectors`: Expose the [`testing`] module with shared conformance vectors
 //!
 //! # Example
 //!
@@ -34,15 +39,35 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod amount;
+pub mod bytes;
+#[cfg(feature = "bech32")]
+pub mod encoding;
 mod error;
+pub mod fixed_hash;
+mod hashable;
+#[cfg(any(feature = "wasm", feature = "test-util"))]
+pub mod rng;
+pub mod secp256k1;
+pub mod slip44;
+mod strength;
+#[cfg(feature = "test-vectors")]
+pub mod testing;
+#[cfg(feature = "alloc")]
+pub mod uri;
 #[cfg(feature = "alloc")]
 mod wallet;
 
+pub use amount::{Amount, Denomination};
 pub use error::Error;
+pub use fixed_hash::FixedHash;
+pub use hashable::Hashable;
+pub use strength::MnemonicStrength;
 #[cfg(feature = "alloc")]
 pub use wallet::Wallet;
 
 pub use bip39::Language;
+pub use digest;
 
 #[cfg(feature = "rand_core")]
 pub use bip39::rand_core;
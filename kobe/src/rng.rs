@@ -0,0 +1,143 @@
+//! Random number generators.
+//!
+//! On `wasm32-unknown-unknown` there is no OS entropy source; the `wasm`
+//! feature enables `getrandom`'s `js` backend, which draws from the
+//! browser's `crypto.getRandomValues` (or Node's `crypto` module).
+//!
+//! The `test-util` feature adds [`DeterministicRng`], a seedable generator
+//! for reproducible tests and examples.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "wasm")] {
+//! use kobe::{Language, Wallet, rng::WasmRng};
+//!
+//! let wallet = Wallet::generate_in_with(&mut WasmRng, Language::English, 12, None).unwrap();
+//! assert_eq!(wallet.word_count(), 12);
+//! # }
+//! ```
+//!
+//! # Note
+//!
+//! [`OsRng`] and [`WasmRng`] require the `wasm` feature. Chain crates expose
+//! the same feature, which also turns on their `rand` feature so that
+//! `StandardWallet::generate` works in the browser.
+
+#[cfg(feature = "test-util")]
+use rand_core::{CryptoRng, Error, RngCore};
+
+#[cfg(feature = "wasm")]
+pub use rand_core::OsRng;
+
+/// Cryptographically secure RNG usable in browsers.
+///
+/// This is [`OsRng`], which is backed by `getrandom` and therefore by
+/// `crypto.getRandomValues` on WebAssembly.
+#[cfg(feature = "wasm")]
+pub use rand_core::OsRng as WasmRng;
+
+/// A seedable RNG producing the same sequence for the same seed.
+///
+/// **NOT cryptographically secure.** The output is fully determined by a
+/// 64-bit seed (SplitMix64), so anything generated with it can be
+/// recovered by brute force. It implements [`CryptoRng`] only so it can be
+/// passed to APIs such as [`Wallet::generate_in_with`](crate::Wallet::generate_in_with)
+/// in tests and demos. Never use it for real keys.
+///
+/// # Example
+///
+/// ```
+/// use kobe::{Language, Wallet, rng::DeterministicRng};
+///
+/// let a = Wallet::generate_in_with(&mut DeterministicRng::new(7), Language::English, 12, None).unwrap();
+/// let b = Wallet::generate_in_with(&mut DeterministicRng::new(7), Language::English, 12, None).unwrap();
+/// assert_eq!(a.mnemonic(), b.mnemonic());
+/// ```
+#[cfg(feature = "test-util")]
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)] // implicit copies would silently replay the stream
+pub struct DeterministicRng {
+    /// SplitMix64 state.
+    state: u64,
+}
+
+#[cfg(feature = "test-util")]
+impl DeterministicRng {
+    /// Create a generator from a seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl RngCore for DeterministicRng {
+    #[allow(clippy::cast_possible_truncation)] // keep the high bits
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl CryptoRng for DeterministicRng {}
+
+#[cfg(all(test, feature = "test-util"))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{Language, Wallet};
+
+    fn generate(seed: u64) -> Wallet {
+        Wallet::generate_in_with(
+            &mut DeterministicRng::new(seed),
+            Language::English,
+            24,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_same_seed_same_keys() {
+        let a = generate(42);
+        let b = generate(42);
+        assert_eq!(a.mnemonic(), b.mnemonic());
+        assert_eq!(a.seed(), b.seed());
+    }
+
+    #[test]
+    fn test_different_seed_different_keys() {
+        assert_ne!(generate(1).seed(), generate(2).seed());
+    }
+
+    #[test]
+    fn test_fill_bytes_matches_next_u64() {
+        let mut rng = DeterministicRng::new(0);
+        let expected = DeterministicRng::new(0).next_u64().to_le_bytes();
+        let mut buf = [0u8; 5];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, expected[..5]);
+    }
+}
@@ -0,0 +1,189 @@
+//! secp256k1 curve constants and public key encodings.
+//!
+//! Key validation and low-`s` signature normalization both compare
+//! big-endian scalars against the group order; these constants keep the
+//! magic numbers in one place. With the `points` feature, [`compress`] and
+//! [`decompress`] convert between SEC1 public key encodings.
+
+#[cfg(feature = "points")]
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+#[cfg(feature = "points")]
+use crate::Error;
+
+/// The secp256k1 group order `n`, big-endian.
+pub const CURVE_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Half the secp256k1 group order, `n / 2` (rounded down), big-endian.
+///
+/// A signature is low-`s` (BIP-62, EIP-2) when `s <= HALF_CURVE_ORDER`.
+pub const HALF_CURVE_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Check whether a big-endian scalar is a valid secret key, i.e. `0 < s < n`.
+#[inline]
+#[must_use]
+pub fn is_valid_scalar(bytes: &[u8; 32]) -> bool {
+    *bytes != [0u8; 32] && *bytes < CURVE_ORDER
+}
+
+/// Check whether a big-endian signature `s` value is in the lower half of
+/// the order, i.e. `0 < s <= n / 2`.
+#[inline]
+#[must_use]
+pub fn is_low_s(bytes: &[u8; 32]) -> bool {
+    *bytes != [0u8; 32] && *bytes <= HALF_CURVE_ORDER
+}
+
+/// Convert a 65-byte uncompressed SEC1 public key (`0x04 || x || y`) to
+/// its 33-byte compressed form (`0x02 | parity(y) || x`).
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidPublicKey`] if the prefix is not `0x04` or
+/// `(x, y)` is not a curve point.
+#[cfg(feature = "points")]
+pub fn compress(uncompressed: &[u8; 65]) -> Result<[u8; 33], Error> {
+    if uncompressed[0] != 0x04 {
+        return Err(Error::InvalidPublicKey);
+    }
+    let key =
+        k256::PublicKey::from_sec1_bytes(uncompressed).map_err(|_| Error::InvalidPublicKey)?;
+    let mut out = [0u8; 33];
+    out.copy_from_slice(key.to_encoded_point(true).as_bytes());
+    Ok(out)
+}
+
+/// Convert a 33-byte compressed SEC1 public key to its 65-byte uncompressed
+/// form by recovering the `y` coordinate.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidPublicKey`] if the prefix is not `0x02`/`0x03`
+/// or `x` is not the coordinate of a curve point.
+#[cfg(feature = "points")]
+pub fn decompress(compressed: &[u8; 33]) -> Result<[u8; 65], Error> {
+    if !matches!(compressed[0], 0x02 | 0x03) {
+        return Err(Error::InvalidPublicKey);
+    }
+    let key = k256::PublicKey::from_sec1_bytes(compressed).map_err(|_| Error::InvalidPublicKey)?;
+    let mut out = [0u8; 65];
+    out.copy_from_slice(key.to_encoded_point(false).as_bytes());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_bounds() {
+        assert!(!is_valid_scalar(&[0u8; 32]));
+        assert!(!is_valid_scalar(&CURVE_ORDER));
+        assert!(!is_valid_scalar(&[0xff; 32]));
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert!(is_valid_scalar(&one));
+
+        let mut n_minus_one = CURVE_ORDER;
+        n_minus_one[31] -= 1;
+        assert!(is_valid_scalar(&n_minus_one));
+    }
+
+    #[test]
+    fn test_half_order() {
+        // 2 * (n / 2) + 1 == n, since n is odd.
+        let mut doubled = [0u8; 32];
+        let mut carry = 1u16;
+        for i in (0..32).rev() {
+            let sum = u16::from(HALF_CURVE_ORDER[i]) * 2 + carry;
+            doubled[i] = sum.to_be_bytes()[1];
+            carry = sum >> 8;
+        }
+        assert_eq!(carry, 0);
+        assert_eq!(doubled, CURVE_ORDER);
+
+        assert!(is_low_s(&HALF_CURVE_ORDER));
+        let mut above_half = HALF_CURVE_ORDER;
+        above_half[31] += 1;
+        assert!(!is_low_s(&above_half));
+    }
+
+    #[cfg(feature = "points")]
+    #[test]
+    fn test_point_compression_round_trip() {
+        // The generator point G.
+        let x = [
+            0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87,
+            0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b,
+            0x16, 0xf8, 0x17, 0x98,
+        ];
+        let y = [
+            0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4, 0xfb, 0xfc, 0x0e, 0x11,
+            0x08, 0xa8, 0xfd, 0x17, 0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19, 0x9c, 0x47, 0xd0, 0x8f,
+            0xfb, 0x10, 0xd4, 0xb8,
+        ];
+        let mut compressed = [0x02; 33];
+        compressed[1..].copy_from_slice(&x);
+        let mut uncompressed = [0x04; 65];
+        uncompressed[1..33].copy_from_slice(&x);
+        uncompressed[33..].copy_from_slice(&y);
+
+        assert_eq!(decompress(&compressed).ok(), Some(uncompressed));
+        assert_eq!(compress(&uncompressed).ok(), Some(compressed));
+
+        // -G has the same x and odd y.
+        let mut negated = compressed;
+        negated[0] = 0x03;
+        let negated_full = decompress(&negated).ok();
+        assert!(negated_full.is_some_and(|p| p[..33] == uncompressed[..33] && p[64] & 1 == 1));
+        assert_eq!(negated_full.and_then(|p| compress(&p).ok()), Some(negated));
+    }
+
+    #[cfg(feature = "points")]
+    #[test]
+    fn test_decompress_rejects_invalid_points() {
+        // x = 5 is not on the curve: 5^3 + 7 is not a square mod p.
+        let mut off_curve = [0u8; 33];
+        off_curve[0] = 0x02;
+        off_curve[32] = 5;
+        assert!(matches!(
+            decompress(&off_curve),
+            Err(Error::InvalidPublicKey)
+        ));
+
+        let mut bad_prefix = off_curve;
+        bad_prefix[0] = 0x04;
+        bad_prefix[32] = 1;
+        assert!(matches!(
+            decompress(&bad_prefix),
+            Err(Error::InvalidPublicKey)
+        ));
+    }
+
+    #[cfg(feature = "points")]
+    #[test]
+    fn test_compress_rejects_invalid_points() {
+        // A compressed key padded to 65 bytes has the wrong prefix.
+        let mut bad_prefix = [0u8; 65];
+        bad_prefix[0] = 0x02;
+        bad_prefix[32] = 1;
+        assert!(matches!(
+            compress(&bad_prefix),
+            Err(Error::InvalidPublicKey)
+        ));
+
+        // (5, 1) is not on the curve.
+        let mut off_curve = [0u8; 65];
+        off_curve[0] = 0x04;
+        off_curve[32] = 5;
+        off_curve[64] = 1;
+        assert!(matches!(compress(&off_curve), Err(Error::InvalidPublicKey)));
+    }
+}
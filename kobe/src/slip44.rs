@@ -0,0 +1,221 @@
+//! SLIP-44 registered coin types.
+//!
+//! The coin type is the second level of a BIP-44 path
+//! (`m/44'/coin_type'/...`). This registry lets multi-chain code build
+//! paths generically instead of hardcoding the numbers per chain.
+
+use core::fmt;
+
+/// A SLIP-44 registered coin type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CoinType {
+    /// Bitcoin (`0`).
+    Bitcoin,
+    /// Testnet for all coins (`1`).
+    Testnet,
+    /// Litecoin (`2`).
+    Litecoin,
+    /// Dogecoin (`3`).
+    Dogecoin,
+    /// Dash (`5`).
+    Dash,
+    /// Ethereum (`60`).
+    Ethereum,
+    /// Ethereum Classic (`61`).
+    EthereumClassic,
+    /// Cosmos Hub (`118`).
+    Cosmos,
+    /// Monero (`128`).
+    Monero,
+    /// Zcash (`133`).
+    Zcash,
+    /// XRP Ledger (`144`).
+    Ripple,
+    /// Bitcoin Cash (`145`).
+    BitcoinCash,
+    /// Stellar (`148`).
+    Stellar,
+    /// Tron (`195`).
+    Tron,
+    /// Polkadot (`354`).
+    Polkadot,
+    /// NEAR (`397`).
+    Near,
+    /// Solana (`501`).
+    Solana,
+    /// BNB Beacon Chain (`714`).
+    Binance,
+    /// Tezos (`1729`).
+    Tezos,
+    /// Cardano (`1815`).
+    Cardano,
+}
+
+impl CoinType {
+    /// All registered coin types known to this crate.
+    pub const ALL: &'static [Self] = &[
+        Self::Bitcoin,
+        Self::Testnet,
+        Self::Litecoin,
+        Self::Dogecoin,
+        Self::Dash,
+        Self::Ethereum,
+        Self::EthereumClassic,
+        Self::Cosmos,
+        Self::Monero,
+        Self::Zcash,
+        Self::Ripple,
+        Self::BitcoinCash,
+        Self::Stellar,
+        Self::Tron,
+        Self::Polkadot,
+        Self::Near,
+        Self::Solana,
+        Self::Binance,
+        Self::Tezos,
+        Self::Cardano,
+    ];
+
+    /// Get the coin type index (unhardened).
+    #[inline]
+    #[must_use]
+    pub const fn index(self) -> u32 {
+        match self {
+            Self::Bitcoin => 0,
+            Self::Testnet => 1,
+            Self::Litecoin => 2,
+            Self::Dogecoin => 3,
+            Self::Dash => 5,
+            Self::Ethereum => 60,
+            Self::EthereumClassic => 61,
+            Self::Cosmos => 118,
+            Self::Monero => 128,
+            Self::Zcash => 133,
+            Self::Ripple => 144,
+            Self::BitcoinCash => 145,
+            Self::Stellar => 148,
+            Self::Tron => 195,
+            Self::Polkadot => 354,
+            Self::Near => 397,
+            Self::Solana => 501,
+            Self::Binance => 714,
+            Self::Tezos => 1729,
+            Self::Cardano => 1815,
+        }
+    }
+
+    /// Get the lowercase coin name.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "bitcoin",
+            Self::Testnet => "testnet",
+            Self::Litecoin => "litecoin",
+            Self::Dogecoin => "dogecoin",
+            Self::Dash => "dash",
+            Self::Ethereum => "ethereum",
+            Self::EthereumClassic => "ethereum-classic",
+            Self::Cosmos => "cosmos",
+            Self::Monero => "monero",
+            Self::Zcash => "zcash",
+            Self::Ripple => "ripple",
+            Self::BitcoinCash => "bitcoin-cash",
+            Self::Stellar => "stellar",
+            Self::Tron => "tron",
+            Self::Polkadot => "polkadot",
+            Self::Near => "near",
+            Self::Solana => "solana",
+            Self::Binance => "binance",
+            Self::Tezos => "tezos",
+            Self::Cardano => "cardano",
+        }
+    }
+
+    /// Get the ticker symbol.
+    #[inline]
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "BTC",
+            Self::Testnet => "TEST",
+            Self::Litecoin => "LTC",
+            Self::Dogecoin => "DOGE",
+            Self::Dash => "DASH",
+            Self::Ethereum => "ETH",
+            Self::EthereumClassic => "ETC",
+            Self::Cosmos => "ATOM",
+            Self::Monero => "XMR",
+            Self::Zcash => "ZEC",
+            Self::Ripple => "XRP",
+            Self::BitcoinCash => "BCH",
+            Self::Stellar => "XLM",
+            Self::Tron => "TRX",
+            Self::Polkadot => "DOT",
+            Self::Near => "NEAR",
+            Self::Solana => "SOL",
+            Self::Binance => "BNB",
+            Self::Tezos => "XTZ",
+            Self::Cardano => "ADA",
+        }
+    }
+
+    /// Look up a coin type by its index.
+    #[must_use]
+    pub fn from_index(index: u32) -> Option<Self> {
+        Self::ALL.iter().copied().find(|coin| coin.index() == index)
+    }
+
+    /// Look up a coin type by name or ticker symbol, ignoring ASCII case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|coin| {
+            coin.name().eq_ignore_ascii_case(name) || coin.symbol().eq_ignore_ascii_case(name)
+        })
+    }
+}
+
+impl fmt::Display for CoinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Look up the SLIP-44 coin type index by coin name or ticker symbol.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(kobe::slip44::coin_type("bitcoin"), Some(0));
+/// assert_eq!(kobe::slip44::coin_type("ETH"), Some(60));
+/// assert_eq!(kobe::slip44::coin_type("unknown"), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn coin_type(name: &str) -> Option<u32> {
+    CoinType::from_name(name).map(CoinType::index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coin_type_lookup() {
+        assert_eq!(coin_type("bitcoin"), Some(0));
+        assert_eq!(coin_type("ethereum"), Some(60));
+        assert_eq!(coin_type("Solana"), Some(501));
+        assert_eq!(coin_type("btc"), Some(0));
+        assert_eq!(coin_type("dogecoin "), None);
+    }
+
+    #[test]
+    fn test_index_roundtrip() {
+        for &coin in CoinType::ALL {
+            assert_eq!(CoinType::from_index(coin.index()), Some(coin));
+            assert_eq!(CoinType::from_name(coin.name()), Some(coin));
+        }
+        assert_eq!(CoinType::from_index(4), None);
+    }
+}
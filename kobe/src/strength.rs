@@ -0,0 +1,120 @@
+//! BIP-39 mnemonic strength levels.
+
+use core::fmt;
+
+use crate::Error;
+
+/// Entropy strength of a BIP-39 mnemonic.
+///
+/// Using this instead of a raw word count makes invalid lengths
+/// unrepresentable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+pub enum MnemonicStrength {
+    /// 128 bits of entropy (12 words).
+    #[default]
+    Bits128,
+    /// 160 bits of entropy (15 words).
+    Bits160,
+    /// 192 bits of entropy (18 words).
+    Bits192,
+    /// 224 bits of entropy (21 words).
+    Bits224,
+    /// 256 bits of entropy (24 words).
+    Bits256,
+}
+
+impl MnemonicStrength {
+    /// Get the strength for a word count, if it is a valid BIP-39 length.
+    #[inline]
+    #[must_use]
+    pub const fn from_word_count(word_count: usize) -> Option<Self> {
+        match word_count {
+            12 => Some(Self::Bits128),
+            15 => Some(Self::Bits160),
+            18 => Some(Self::Bits192),
+            21 => Some(Self::Bits224),
+            24 => Some(Self::Bits256),
+            _ => None,
+        }
+    }
+
+    /// Get the number of mnemonic words.
+    #[inline]
+    #[must_use]
+    pub const fn word_count(self) -> usize {
+        match self {
+            Self::Bits128 => 12,
+            Self::Bits160 => 15,
+            Self::Bits192 => 18,
+            Self::Bits224 => 21,
+            Self::Bits256 => 24,
+        }
+    }
+
+    /// Get the entropy size in bits.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> usize {
+        self.word_count() / 3 * 32
+    }
+
+    /// Get the entropy size in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn entropy_len(self) -> usize {
+        self.bits() / 8
+    }
+}
+
+impl TryFrom<usize> for MnemonicStrength {
+    type Error = Error;
+
+    fn try_from(word_count: usize) -> Result<Self, Self::Error> {
+        Self::from_word_count(word_count).ok_or(Error::InvalidWordCount(word_count))
+    }
+}
+
+impl From<MnemonicStrength> for usize {
+    #[inline]
+    fn from(strength: MnemonicStrength) -> Self {
+        strength.word_count()
+    }
+}
+
+impl fmt::Display for MnemonicStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-bit ({} words)", self.bits(), self.word_count())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_counts() {
+        let expected = [
+            (MnemonicStrength::Bits128, 128, 12),
+            (MnemonicStrength::Bits160, 160, 15),
+            (MnemonicStrength::Bits192, 192, 18),
+            (MnemonicStrength::Bits224, 224, 21),
+            (MnemonicStrength::Bits256, 256, 24),
+        ];
+        for (strength, bits, words) in expected {
+            assert_eq!(strength.bits(), bits);
+            assert_eq!(strength.word_count(), words);
+            assert_eq!(MnemonicStrength::try_from(words).unwrap(), strength);
+        }
+    }
+
+    #[test]
+    fn test_invalid_word_count() {
+        assert!(matches!(
+            MnemonicStrength::try_from(13),
+            Err(Error::InvalidWordCount(13))
+        ));
+        assert_eq!(MnemonicStrength::from_word_count(0), None);
+    }
+}
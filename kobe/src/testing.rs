@@ -0,0 +1,220 @@
+//! Shared conformance vectors and assertion helpers.
+//!
+//! Chain crates built on top of [`Wallet`](crate::Wallet) can reuse these
+//! standard BIP-32, BIP-39 and BIP-44 vectors to check their derivation
+//! logic against known-good values.
+//!
+//! # Note
+//!
+//! This module requires the `test-vectors` feature to be enabled.
+
+use alloc::string::{String, ToString};
+
+use bip32::{DerivationPath, Prefix, XPrv};
+
+use crate::Wallet;
+
+/// A BIP-32 test vector: extended private key at `path` for `seed_hex`.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Bip32Vector {
+    /// Hex-encoded master seed.
+    pub seed_hex: &'static str,
+    /// Derivation path (e.g., `m/0'/1`).
+    pub path: &'static str,
+    /// Expected extended private key (`xprv...`).
+    pub xprv: &'static str,
+}
+
+/// A BIP-39 test vector from the reference Trezor suite.
+///
+/// All vectors use the passphrase [`BIP39_PASSPHRASE`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Bip39Vector {
+    /// Hex-encoded entropy.
+    pub entropy_hex: &'static str,
+    /// Expected English mnemonic.
+    pub mnemonic: &'static str,
+    /// Expected hex-encoded seed.
+    pub seed_hex: &'static str,
+}
+
+/// A BIP-44 style address vector for a specific chain.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Bip44Vector {
+    /// BIP-39 mnemonic (no passphrase).
+    pub mnemonic: &'static str,
+    /// Derivation path.
+    pub path: &'static str,
+    /// Expected address in the chain's canonical format.
+    pub address: &'static str,
+}
+
+/// The 12-word all-zero-entropy mnemonic used throughout wallet test suites.
+pub const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// BIP-32 test vector 1.
+pub const BIP32_VECTOR_1: &[Bip32Vector] = &[
+    Bip32Vector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f",
+        path: "m",
+        xprv: "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+    },
+    Bip32Vector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f",
+        path: "m/0'",
+        xprv: "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+    },
+    Bip32Vector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f",
+        path: "m/0'/1",
+        xprv: "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+    },
+    Bip32Vector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f",
+        path: "m/0'/1/2'",
+        xprv: "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+    },
+    Bip32Vector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f",
+        path: "m/0'/1/2'/2",
+        xprv: "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+    },
+    Bip32Vector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f",
+        path: "m/0'/1/2'/2/1000000000",
+        xprv: "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+    },
+];
+
+/// Passphrase used by all [`BIP39_VECTORS`].
+pub const BIP39_PASSPHRASE: &str = "TREZOR";
+
+/// BIP-39 English test vectors (subset of the reference suite).
+pub const BIP39_VECTORS: &[Bip39Vector] = &[
+    Bip39Vector {
+        entropy_hex: "00000000000000000000000000000000",
+        mnemonic: TEST_MNEMONIC,
+        seed_hex: "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    },
+    Bip39Vector {
+        entropy_hex: "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        mnemonic: "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        seed_hex: "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+    },
+    Bip39Vector {
+        entropy_hex: "80808080808080808080808080808080",
+        mnemonic: "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        seed_hex: "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+    },
+    Bip39Vector {
+        entropy_hex: "ffffffffffffffffffffffffffffffff",
+        mnemonic: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        seed_hex: "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+    },
+];
+
+/// Bitcoin legacy (BIP-44) address for [`TEST_MNEMONIC`].
+pub const BIP44_BTC_VECTOR: Bip44Vector = Bip44Vector {
+    mnemonic: TEST_MNEMONIC,
+    path: "m/44'/0'/0'/0/0",
+    address: "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA",
+};
+
+/// Bitcoin nested SegWit (BIP-49, P2SH-P2WPKH) address for [`TEST_MNEMONIC`].
+pub const BIP49_BTC_VECTOR: Bip44Vector = Bip44Vector {
+    mnemonic: TEST_MNEMONIC,
+    path: "m/49'/0'/0'/0/0",
+    address: "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf",
+};
+
+/// Bitcoin native SegWit (BIP-84) address for [`TEST_MNEMONIC`].
+pub const BIP84_BTC_VECTOR: Bip44Vector = Bip44Vector {
+    mnemonic: TEST_MNEMONIC,
+    path: "m/84'/0'/0'/0/0",
+    address: "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+};
+
+/// Ethereum (BIP-44) address for [`TEST_MNEMONIC`].
+pub const BIP44_ETH_VECTOR: Bip44Vector = Bip44Vector {
+    mnemonic: TEST_MNEMONIC,
+    path: "m/44'/60'/0'/0/0",
+    address: "0x9858EfFD232B4033E47d90003D41EC34EcaEda94",
+};
+
+/// Derive the extended private key at `path` from a hex-encoded seed.
+///
+/// Returns `None` if the seed or path is invalid.
+#[must_use]
+pub fn derive_xprv(seed_hex: &str, path: &str) -> Option<String> {
+    let seed = hex::decode(seed_hex).ok()?;
+    let derivation_path: DerivationPath = path.parse().ok()?;
+    let xprv = XPrv::derive_from_path(seed, &derivation_path).ok()?;
+    Some(xprv.to_string(Prefix::XPRV).to_string())
+}
+
+/// Assert that deriving `path` from `seed_hex` yields `expected_xprv`.
+///
+/// # Panics
+///
+/// Panics if derivation fails or the result does not match.
+pub fn assert_bip32_vector(seed_hex: &str, path: &str, expected_xprv: &str) {
+    assert_eq!(
+        derive_xprv(seed_hex, path).as_deref(),
+        Some(expected_xprv),
+        "BIP-32 vector mismatch at {path}"
+    );
+}
+
+/// Assert that `entropy_hex` produces `expected_mnemonic` and `expected_seed_hex`.
+///
+/// # Panics
+///
+/// Panics if the wallet cannot be created or the result does not match.
+pub fn assert_bip39_vector(
+    entropy_hex: &str,
+    passphrase: &str,
+    expected_mnemonic: &str,
+    expected_seed_hex: &str,
+) {
+    let wallet = hex::decode(entropy_hex)
+        .ok()
+        .and_then(|entropy| Wallet::from_entropy(&entropy, Some(passphrase)).ok());
+    assert_eq!(
+        wallet.as_ref().map(Wallet::mnemonic),
+        Some(expected_mnemonic),
+        "BIP-39 mnemonic mismatch for entropy {entropy_hex}"
+    );
+    assert_eq!(
+        wallet.map(|w| hex::encode(w.seed())).as_deref(),
+        Some(expected_seed_hex),
+        "BIP-39 seed mismatch for entropy {entropy_hex}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bip32_vector_1() {
+        for vector in BIP32_VECTOR_1 {
+            assert_bip32_vector(vector.seed_hex, vector.path, vector.xprv);
+        }
+    }
+
+    #[test]
+    fn test_bip39_vectors() {
+        for vector in BIP39_VECTORS {
+            assert_bip39_vector(
+                vector.entropy_hex,
+                BIP39_PASSPHRASE,
+                vector.mnemonic,
+                vector.seed_hex,
+            );
+        }
+    }
+}
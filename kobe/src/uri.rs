@@ -0,0 +1,303 @@
+//! BIP-21 `bitcoin:` payment URIs.
+//!
+//! These URIs are what scan-to-pay QR codes carry, e.g.
+//! `bitcoin:bc1q...?amount=0.1&label=Shop`. The address is kept as a
+//! string; validating it against a network is left to the chain crate.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::{Amount, Denomination, Error};
+
+/// URI scheme for Bitcoin payment requests.
+const SCHEME: &str = "bitcoin:";
+
+/// Satoshi per bitcoin.
+const SATOSHI_PER_BTC: u64 = 100_000_000;
+
+/// A parsed BIP-21 payment request.
+///
+/// # Example
+///
+/// ```
+/// use kobe::Amount;
+/// use kobe::uri::{BitcoinUri, parse_bitcoin_uri};
+///
+/// let uri = parse_bitcoin_uri("bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=0.1&label=Test")?;
+/// assert_eq!(uri.amount(), Some(Amount::from_satoshi(10_000_000)));
+/// assert_eq!(uri.label(), Some("Test"));
+///
+/// let encoded = BitcoinUri::new("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+///     .with_amount(10_000_000)
+///     .with_label("Test");
+/// assert_eq!(encoded, uri);
+/// # Ok::<(), kobe::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitcoinUri {
+    /// Recipient address.
+    address: String,
+    /// Requested amount, in satoshi.
+    amount: Option<Amount>,
+    /// Recipient label.
+    label: Option<String>,
+    /// Message describing the payment.
+    message: Option<String>,
+}
+
+impl BitcoinUri {
+    /// Create a payment request for `address` with no other parameters.
+    #[inline]
+    #[must_use]
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            amount: None,
+            label: None,
+            message: None,
+        }
+    }
+
+    /// Set the requested amount, in satoshi.
+    #[inline]
+    #[must_use]
+    pub const fn with_amount(mut self, satoshi: u64) -> Self {
+        self.amount = Some(Amount::from_satoshi(satoshi));
+        self
+    }
+
+    /// Set the recipient label.
+    #[inline]
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the payment message.
+    #[inline]
+    #[must_use]
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Get the recipient address.
+    #[inline]
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Get the requested amount, always denominated in satoshi.
+    #[inline]
+    #[must_use]
+    pub const fn amount(&self) -> Option<Amount> {
+        self.amount
+    }
+
+    /// Get the recipient label.
+    #[inline]
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Get the payment message.
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for BitcoinUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{SCHEME}{}", self.address)?;
+        let mut separator = '?';
+        if let Some(satoshi) = self.amount.and_then(|amount| amount.as_satoshi()) {
+            let whole = satoshi / SATOSHI_PER_BTC;
+            let frac = satoshi % SATOSHI_PER_BTC;
+            write!(f, "{separator}amount={whole}")?;
+            if frac != 0 {
+                let digits = alloc::format!("{frac:08}");
+                write!(f, ".{}", digits.trim_end_matches('0'))?;
+            }
+            separator = '&';
+        }
+        for (key, field) in [("label", &self.label), ("message", &self.message)] {
+            if let Some(value) = field {
+                write!(f, "{separator}{key}=")?;
+                percent_encode(f, value)?;
+                separator = '&';
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for BitcoinUri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_bitcoin_uri(s)
+    }
+}
+
+/// Parse a BIP-21 `bitcoin:` URI.
+///
+/// The scheme is matched case-insensitively, as QR encoders often
+/// upper-case it. Unknown parameters are ignored unless prefixed with
+/// `req-`, which BIP-21 requires rejecting.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidUri`] if the scheme, address, amount or
+/// parameter encoding is malformed, or a `req-` parameter is present.
+pub fn parse_bitcoin_uri(s: &str) -> Result<BitcoinUri, Error> {
+    let rest = s
+        .get(..SCHEME.len())
+        .filter(|scheme| scheme.eq_ignore_ascii_case(SCHEME))
+        .and_then(|_| s.get(SCHEME.len()..))
+        .ok_or(Error::InvalidUri)?;
+    let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+    if address.is_empty() || !address.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::InvalidUri);
+    }
+
+    let mut uri = BitcoinUri::new(address);
+    for param in query.split('&').filter(|param| !param.is_empty()) {
+        let (key, value) = param.split_once('=').ok_or(Error::InvalidUri)?;
+        match key {
+            "amount" if uri.amount.is_none() => {
+                let amount = Amount::from_str_in(value, Denomination::Satoshi)
+                    .map_err(|_| Error::InvalidUri)?;
+                uri.amount = Some(amount);
+            }
+            "label" if uri.label.is_none() => uri.label = Some(percent_decode(value)?),
+            "message" if uri.message.is_none() => uri.message = Some(percent_decode(value)?),
+            "amount" | "label" | "message" => return Err(Error::InvalidUri),
+            _ if key.starts_with("req-") => return Err(Error::InvalidUri),
+            _ => {}
+        }
+    }
+    Ok(uri)
+}
+
+/// Write `value` percent-encoding everything but RFC 3986 unreserved characters.
+fn percent_encode(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            f.write_char(char::from(byte))?;
+        } else {
+            write!(f, "%{byte:02X}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Decode `%XX` escapes into a UTF-8 string.
+fn percent_decode(value: &str) -> Result<String, Error> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        if byte == b'%' {
+            let hi = input.next().and_then(hex_digit).ok_or(Error::InvalidUri)?;
+            let lo = input.next().and_then(hex_digit).ok_or(Error::InvalidUri)?;
+            bytes.push(hi << 4 | lo);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| Error::InvalidUri)
+}
+
+/// Decode a single ASCII hex digit.
+const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const ADDRESS: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+    #[test]
+    fn test_parse_amount_and_label() {
+        let uri =
+            parse_bitcoin_uri(&alloc::format!("bitcoin:{ADDRESS}?amount=0.1&label=Test")).unwrap();
+        assert_eq!(uri.address(), ADDRESS);
+        assert_eq!(uri.amount(), Some(Amount::from_satoshi(10_000_000)));
+        assert_eq!(uri.label(), Some("Test"));
+        assert_eq!(uri.message(), None);
+    }
+
+    #[test]
+    fn test_parse_percent_encoded_message() {
+        let uri: BitcoinUri =
+            alloc::format!("BITCOIN:{ADDRESS}?message=Donation%20for%20%E2%82%AC")
+                .parse()
+                .unwrap();
+        assert_eq!(uri.message(), Some("Donation for €"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let uri = BitcoinUri::new(ADDRESS)
+            .with_amount(123_456_789)
+            .with_label("Luke-Jr")
+            .with_message("Donation for project xyz");
+        let encoded = uri.to_string();
+        assert_eq!(
+            encoded,
+            alloc::format!(
+                "bitcoin:{ADDRESS}?amount=1.23456789&label=Luke-Jr&message=Donation%20for%20project%20xyz"
+            )
+        );
+        assert_eq!(parse_bitcoin_uri(&encoded).unwrap(), uri);
+        assert_eq!(
+            BitcoinUri::new(ADDRESS)
+                .with_amount(2 * SATOSHI_PER_BTC)
+                .to_string(),
+            alloc::format!("bitcoin:{ADDRESS}?amount=2")
+        );
+    }
+
+    #[test]
+    fn test_reject_malformed() {
+        for uri in [
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+            "bitcoin:",
+            "bitcoin:bc1q ar0s",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=1,5",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=-1",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=0.000000001",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=184467440737.09551616",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=1&amount=2",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?label=%zz",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?req-somethingyoudontunderstand=50",
+            "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?label",
+        ] {
+            assert!(parse_bitcoin_uri(uri).is_err(), "{uri}");
+        }
+    }
+
+    #[test]
+    fn test_ignore_unknown_param() {
+        let uri = parse_bitcoin_uri(&alloc::format!(
+            "bitcoin:{ADDRESS}?somethingyoudontunderstand=50"
+        ))
+        .unwrap();
+        assert_eq!(uri, BitcoinUri::new(ADDRESS));
+    }
+}
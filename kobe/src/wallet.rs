@@ -1,10 +1,32 @@
 //! Unified wallet type for multi-chain key derivation.
 
 use alloc::string::{String, ToString};
+#[cfg(feature = "rand_core")]
+use bip39::rand_core::{CryptoRng, RngCore};
 use bip39::{Language, Mnemonic};
 use zeroize::Zeroizing;
 
-use crate::Error;
+use crate::{Error, MnemonicStrength};
+
+/// Entropies of widely published mnemonics (BIP-39 reference test vectors)
+/// whose wallets are swept as soon as they are funded.
+///
+/// Single-repeated-byte entropies such as all-zero are caught separately.
+const KNOWN_WEAK_ENTROPY: &[&[u8]] = &[
+    &[
+        0x9e, 0x88, 0x5d, 0x95, 0x2a, 0xd3, 0x62, 0xca, 0xeb, 0x4e, 0xfe, 0x34, 0xa8, 0xe9, 0x1b,
+        0xd2,
+    ],
+    &[
+        0xc0, 0xba, 0x5a, 0x8e, 0x91, 0x41, 0x11, 0x21, 0x0f, 0x2b, 0xd1, 0x31, 0xf3, 0xd5, 0xe0,
+        0x8d,
+    ],
+    &[
+        0xf5, 0x85, 0xc1, 0x1a, 0xec, 0x52, 0x0d, 0xb5, 0x7d, 0xd3, 0x53, 0xc6, 0x95, 0x54, 0xb2,
+        0x1a, 0x89, 0xb2, 0x0f, 0xb0, 0x65, 0x09, 0x66, 0xfa, 0x0a, 0x9d, 0x6f, 0x74, 0xfd, 0x98,
+        0x9d, 0x8f,
+    ],
+];
 
 /// A unified HD wallet that can derive keys for multiple cryptocurrencies.
 ///
@@ -27,6 +49,8 @@ pub struct Wallet {
     has_passphrase: bool,
     /// Language of the mnemonic.
     language: Language,
+    /// Entropy strength of the mnemonic.
+    strength: MnemonicStrength,
 }
 
 impl Wallet {
@@ -49,6 +73,23 @@ pub fn generate(word_count: usize, passphrase: Option<&str>) -> Result<Self, Err
         Self::generate_in(Language::English, word_count, passphrase)
     }
 
+    /// Generate a new wallet with a random mnemonic of the given strength.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mnemonic generation fails.
+    ///
+    /// # Note
+    ///
+    /// This function requires the `rand` feature to be enabled.
+    #[cfg(feature = "rand")]
+    pub fn generate_with_strength(
+        strength: MnemonicStrength,
+        passphrase: Option<&str>,
+    ) -> Result<Self, Error> {
+        Self::generate_in(Language::English, strength.word_count(), passphrase)
+    }
+
     /// Generate a new wallet with a random mnemonic in the specified language.
     ///
     /// # Arguments
@@ -70,11 +111,8 @@ pub fn generate_in(
         word_count: usize,
         passphrase: Option<&str>,
     ) -> Result<Self, Error> {
-        if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
-            return Err(Error::InvalidWordCount(word_count));
-        }
-
-        let mnemonic = Mnemonic::generate_in(language, word_count)?;
+        let strength = MnemonicStrength::try_from(word_count)?;
+        let mnemonic = Mnemonic::generate_in(language, strength.word_count())?;
         Self::from_mnemonic_in(language, mnemonic.to_string().as_str(), passphrase)
     }
 
@@ -105,13 +143,10 @@ pub fn generate_in_with<R>(
         passphrase: Option<&str>,
     ) -> Result<Self, Error>
     where
-        R: bip39::rand_core::RngCore + bip39::rand_core::CryptoRng,
+        R: RngCore + CryptoRng,
     {
-        if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
-            return Err(Error::InvalidWordCount(word_count));
-        }
-
-        let mnemonic = Mnemonic::generate_in_with(rng, language, word_count)?;
+        let strength = MnemonicStrength::try_from(word_count)?;
+        let mnemonic = Mnemonic::generate_in_with(rng, language, strength.word_count())?;
         Self::from_mnemonic_in(language, mnemonic.to_string().as_str(), passphrase)
     }
 
@@ -170,6 +205,7 @@ pub fn from_entropy_in(
     pub fn from_mnemonic(phrase: &str, passphrase: Option<&str>) -> Result<Self, Error> {
         let mnemonic: Mnemonic = phrase.parse()?;
         let language = mnemonic.language();
+        let strength = MnemonicStrength::try_from(mnemonic.word_count())?;
         let passphrase_str = passphrase.unwrap_or("");
         let seed_bytes = mnemonic.to_seed(passphrase_str);
 
@@ -178,6 +214,7 @@ pub fn from_mnemonic(phrase: &str, passphrase: Option<&str>) -> Result<Self, Err
             seed: Zeroizing::new(seed_bytes),
             has_passphrase: passphrase.is_some() && !passphrase_str.is_empty(),
             language,
+            strength,
         })
     }
 
@@ -198,6 +235,7 @@ pub fn from_mnemonic_in(
         passphrase: Option<&str>,
     ) -> Result<Self, Error> {
         let mnemonic = Mnemonic::parse_in(language, phrase)?;
+        let strength = MnemonicStrength::try_from(mnemonic.word_count())?;
         let passphrase_str = passphrase.unwrap_or("");
         let seed_bytes = mnemonic.to_seed(passphrase_str);
 
@@ -206,6 +244,7 @@ pub fn from_mnemonic_in(
             seed: Zeroizing::new(seed_bytes),
             has_passphrase: passphrase.is_some() && !passphrase_str.is_empty(),
             language,
+            strength,
         })
     }
 
@@ -248,6 +287,72 @@ pub const fn language(&self) -> Language {
     pub fn word_count(&self) -> usize {
         self.mnemonic.split_whitespace().count()
     }
+
+    /// Get the entropy strength of the mnemonic.
+    #[inline]
+    #[must_use]
+    pub const fn strength(&self) -> MnemonicStrength {
+        self.strength
+    }
+
+    /// Compute the BIP-32 master key fingerprint of this mnemonic under
+    /// `passphrase`: the first 4 bytes of `hash160` of the master public key.
+    ///
+    /// A passphrase typo silently yields a different wallet, so a UI can
+    /// show this short value and have the user confirm it matches across
+    /// entries. It is the same fingerprint hardware wallets display.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the master key cannot be derived from the seed.
+    #[cfg(feature = "fingerprint")]
+    pub fn seed_fingerprint(&self, passphrase: &str) -> Result<[u8; 4], Error> {
+        let mnemonic = Mnemonic::parse_in_normalized(self.language, &self.mnemonic)?;
+        let seed = Zeroizing::new(mnemonic.to_seed(passphrase));
+        let master = bip32::XPrv::new(seed.as_slice()).map_err(|_| Error::InvalidSeed)?;
+        Ok(master.public_key().fingerprint())
+    }
+
+    /// Compute a stable 16-byte identifier for this wallet: the first 16
+    /// bytes of `sha256` of the compressed BIP-32 master public key.
+    ///
+    /// The seed, including any passphrase, determines the ID, so apps can
+    /// group accounts by it across sessions. Only a hash of a public key is
+    /// exposed, which reveals nothing about the seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the master key cannot be derived from the seed.
+    #[cfg(feature = "fingerprint")]
+    pub fn wallet_id(&self) -> Result<[u8; 16], Error> {
+        use sha2::{Digest, Sha256};
+
+        let master = bip32::XPrv::new(self.seed.as_slice()).map_err(|_| Error::InvalidSeed)?;
+        let digest = Sha256::digest(master.public_key().to_bytes());
+        let mut id = [0u8; 16];
+        id.copy_from_slice(&digest[..16]);
+        Ok(id)
+    }
+
+    /// Check whether the mnemonic is publicly known and therefore unsafe
+    /// for holding funds.
+    ///
+    /// # Note
+    ///
+    /// This is a heuristic safety net, not a randomness test. It flags
+    /// entropy made of a single repeated byte (all-zero `abandon ... about`,
+    /// all-max `zoo ... wrong`, and similar) and a small bundled list of
+    /// famous test-vector phrases. A `false` result does not mean the
+    /// mnemonic was generated securely.
+    #[must_use]
+    pub fn is_weak(&self) -> bool {
+        let Ok(mnemonic) = Mnemonic::parse_in_normalized(self.language, &self.mnemonic) else {
+            return false;
+        };
+        let (bytes, len) = mnemonic.to_entropy_array();
+        let entropy = &bytes[..len];
+        entropy.iter().all(|&b| b == entropy[0]) || KNOWN_WEAK_ENTROPY.contains(&entropy)
+    }
 }
 
 #[cfg(test)]
@@ -257,6 +362,72 @@ mod tests {
 
     const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
 
+    #[cfg(feature = "fingerprint")]
+    #[test]
+    fn test_seed_fingerprint() {
+        let wallet = Wallet::from_mnemonic(TEST_MNEMONIC, None).unwrap();
+        // Master fingerprint of the BIP-84 test mnemonic.
+        assert_eq!(
+            wallet.seed_fingerprint("").unwrap(),
+            [0x73, 0xc5, 0xda, 0x0a]
+        );
+        assert_eq!(
+            wallet.seed_fingerprint("hunter2").unwrap(),
+            wallet.seed_fingerprint("hunter2").unwrap()
+        );
+        assert_ne!(
+            wallet.seed_fingerprint("hunter2").unwrap(),
+            wallet.seed_fingerprint("hunter3").unwrap()
+        );
+        assert_ne!(
+            wallet.seed_fingerprint("").unwrap(),
+            wallet.seed_fingerprint("hunter2").unwrap()
+        );
+    }
+
+    #[cfg(feature = "fingerprint")]
+    #[test]
+    fn test_wallet_id() {
+        let wallet = Wallet::from_mnemonic(TEST_MNEMONIC, None).unwrap();
+        let again = Wallet::from_mnemonic(TEST_MNEMONIC, None).unwrap();
+        assert_eq!(wallet.wallet_id().unwrap(), again.wallet_id().unwrap());
+
+        let other = Wallet::from_mnemonic(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            None,
+        )
+        .unwrap();
+        assert_ne!(wallet.wallet_id().unwrap(), other.wallet_id().unwrap());
+
+        let with_passphrase = Wallet::from_mnemonic(TEST_MNEMONIC, Some("hunter2")).unwrap();
+        assert_ne!(
+            wallet.wallet_id().unwrap(),
+            with_passphrase.wallet_id().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_weak() {
+        let weak = [
+            TEST_MNEMONIC,
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+            "scheme spot photo card baby mountain device kick cradle pact join borrow",
+            "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold",
+        ];
+        for phrase in weak {
+            let wallet = Wallet::from_mnemonic(phrase, None).unwrap();
+            assert!(wallet.is_weak(), "{phrase}");
+        }
+
+        let entropy = [
+            0x3a, 0x91, 0x5c, 0xe2, 0x07, 0xb4, 0x6f, 0xd8, 0x21, 0x8e, 0x45, 0xf0, 0x9b, 0x13,
+            0x7c, 0xa6,
+        ];
+        assert!(!Wallet::from_entropy(&entropy, None).unwrap().is_weak());
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn test_generate_12_words() {
@@ -272,6 +443,14 @@ fn test_generate_24_words() {
         assert_eq!(wallet.word_count(), 24);
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_generate_with_strength() {
+        let wallet = Wallet::generate_with_strength(MnemonicStrength::Bits256, None).unwrap();
+        assert_eq!(wallet.word_count(), 24);
+        assert_eq!(wallet.strength(), MnemonicStrength::Bits256);
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn test_generate_with_passphrase() {
@@ -298,6 +477,7 @@ fn test_from_entropy() {
     fn test_from_mnemonic() {
         let wallet = Wallet::from_mnemonic(TEST_MNEMONIC, None).unwrap();
         assert_eq!(wallet.mnemonic(), TEST_MNEMONIC);
+        assert_eq!(wallet.strength(), MnemonicStrength::Bits128);
     }
 
     #[test]